@@ -0,0 +1,42 @@
+//! Compares iterating over fixed-size chunks via `sub_array_chunks` with
+//! calling `sub_array_ref` for each chunk.
+
+use std::hint::black_box;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use sub_array::SubArray;
+use sub_array::SubArrayRef;
+
+
+const LEN: usize = 64 * 1024;
+const N: usize = 16;
+
+fn sum_iter(buf: &[u8]) -> u64 {
+	buf.sub_array_chunks::<N>()
+		.map(|chunk| chunk.iter().map(|&b| u64::from(b)).sum::<u64>())
+		.sum()
+}
+
+fn sum_naive(buf: &[u8]) -> u64 {
+	(0..(buf.len() / N))
+		.map(|i| {
+			let chunk = buf.sub_array_ref::<N>(i * N);
+			chunk.iter().map(|&b| u64::from(b)).sum::<u64>()
+		})
+		.sum()
+}
+
+fn chunks(c: &mut Criterion) {
+	let buf: Vec<u8> = (0..LEN).map(|i| i as u8).collect();
+	assert_eq!(sum_iter(&buf), sum_naive(&buf));
+
+	let mut group = c.benchmark_group("chunks");
+	group.bench_function("sub_array_chunks", |b| b.iter(|| sum_iter(black_box(&buf))));
+	group.bench_function("sub_array_ref", |b| b.iter(|| sum_naive(black_box(&buf))));
+	group.finish();
+}
+
+criterion_group!(benches, chunks);
+criterion_main!(benches);
@@ -0,0 +1,1688 @@
+//! Helpers specific to byte arrays
+
+use crate::container::out_of_bounds;
+use crate::hex::encode_hex;
+use crate::BigEndian;
+use crate::Endian;
+use crate::EndianInt;
+use crate::FromSubArray;
+use crate::FromSubArrayBytes;
+use crate::LittleEndian;
+use crate::SubArray;
+use crate::SubArrayRef;
+use crate::TagError;
+use crate::Tagged;
+use crate::TextWindow;
+
+
+/// Byte array that offers helpers working on a sub-array of its bytes
+///
+/// This trait is implemented for every [`SubArray`] of `u8`, thus it is
+/// sufficient to import it to use its methods, there is no need to implement
+/// it manually.
+///
+/// Like the methods of [`SubArray`], these helpers come in a panicking and in
+/// a `try_` form, the latter returns `None` instead of panicking if the
+/// sub-array is out of bounds.
+pub trait ByteSubArray: SubArray<Item = u8> {
+	/// Converts the sub-array of length `N` starting at `offset` to its ASCII
+	/// upper case equivalent in-place.
+	///
+	/// Only ASCII letters are changed, all other bytes, including those
+	/// outside of the sub-array, are left untouched.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let mut arr: [u8; 6] = *b"abcdef";
+	///
+	/// arr.make_ascii_uppercase_sub_array::<3>(1);
+	/// assert_eq!(&arr, b"aBCDef");
+	/// ```
+	fn make_ascii_uppercase_sub_array<const N: usize>(&mut self, offset: usize) {
+		self.sub_array_mut::<N>(offset).make_ascii_uppercase();
+	}
+
+	/// Converts the sub-array of length `N` starting at `offset` to its ASCII
+	/// upper case equivalent in-place, if it is in bounds.
+	///
+	/// Returns `None` and leaves this array untouched if `offset + N` exceeds
+	/// the length of this array.
+	fn try_make_ascii_uppercase_sub_array<const N: usize>(&mut self, offset: usize) -> Option<()> {
+		self.try_sub_array_mut::<N>(offset)?.make_ascii_uppercase();
+		Some(())
+	}
+
+	/// Converts the sub-array of length `N` starting at `offset` to its ASCII
+	/// lower case equivalent in-place.
+	///
+	/// Only ASCII letters are changed, all other bytes, including those
+	/// outside of the sub-array, are left untouched.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let mut arr: [u8; 6] = *b"ABCDEF";
+	///
+	/// arr.make_ascii_lowercase_sub_array::<2>(4);
+	/// assert_eq!(&arr, b"ABCDef");
+	/// ```
+	fn make_ascii_lowercase_sub_array<const N: usize>(&mut self, offset: usize) {
+		self.sub_array_mut::<N>(offset).make_ascii_lowercase();
+	}
+
+	/// Converts the sub-array of length `N` starting at `offset` to its ASCII
+	/// lower case equivalent in-place, if it is in bounds.
+	///
+	/// Returns `None` and leaves this array untouched if `offset + N` exceeds
+	/// the length of this array.
+	fn try_make_ascii_lowercase_sub_array<const N: usize>(&mut self, offset: usize) -> Option<()> {
+		self.try_sub_array_mut::<N>(offset)?.make_ascii_lowercase();
+		Some(())
+	}
+
+	/// Checks whether the sub-array starting at `offset` is an ASCII case
+	/// insensitive match of `other`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 8] = *b"GET /foo";
+	///
+	/// assert!(arr.eq_ignore_ascii_case_sub_array(0, b"get"));
+	/// assert!(!arr.eq_ignore_ascii_case_sub_array(4, b"bar"));
+	/// ```
+	fn eq_ignore_ascii_case_sub_array<const N: usize>(
+		&self,
+		offset: usize,
+		other: &[u8; N],
+	) -> bool {
+		self.sub_array_ref::<N>(offset).eq_ignore_ascii_case(other)
+	}
+
+	/// Checks whether the sub-array starting at `offset` is an ASCII case
+	/// insensitive match of `other`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_eq_ignore_ascii_case_sub_array<const N: usize>(
+		&self,
+		offset: usize,
+		other: &[u8; N],
+	) -> Option<bool> {
+		Some(
+			self.try_sub_array_ref::<N>(offset)?
+				.eq_ignore_ascii_case(other),
+		)
+	}
+
+	/// Checks whether all bytes of the sub-array of length `N` starting at
+	/// `offset` are within the ASCII range.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 5] = [b'a', b'b', 0xFF, b'c', b'd'];
+	///
+	/// assert!(arr.is_ascii_sub_array::<2>(0));
+	/// assert!(!arr.is_ascii_sub_array::<2>(1));
+	/// ```
+	fn is_ascii_sub_array<const N: usize>(&self, offset: usize) -> bool {
+		self.sub_array_ref::<N>(offset).is_ascii()
+	}
+
+	/// Checks whether all bytes of the sub-array of length `N` starting at
+	/// `offset` are within the ASCII range, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_is_ascii_sub_array<const N: usize>(&self, offset: usize) -> Option<bool> {
+		Some(self.try_sub_array_ref::<N>(offset)?.is_ascii())
+	}
+
+	/// Reads the signed Q8.8 fixed-point number starting at `offset`, in
+	/// big-endian byte order.
+	///
+	/// A Q8.8 number is a 16-bit two's complement integer, whose lower 8 bits
+	/// are the fractional part, i.e. its value is the integer divided by
+	/// `2^8`. The conversion to `f64` is exact.
+	///
+	/// # Panics
+	/// Panics if `offset + 2` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 3] = [0x00, 0x01, 0x80];
+	///
+	/// assert_eq!(arr.read_q8_8_be(1), 1.5);
+	/// ```
+	fn read_q8_8_be(&self, offset: usize) -> f64 {
+		q8_8(i16::from_be_bytes(self.copy_sub_array(offset)))
+	}
+
+	/// Reads the signed Q8.8 fixed-point number starting at `offset`, in
+	/// big-endian byte order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + 2` exceeds the length of this array.
+	/// Also see [`read_q8_8_be`](Self::read_q8_8_be).
+	fn try_read_q8_8_be(&self, offset: usize) -> Option<f64> {
+		Some(q8_8(i16::from_be_bytes(self.try_copy_sub_array(offset)?)))
+	}
+
+	/// Reads the signed Q8.8 fixed-point number starting at `offset`, in
+	/// little-endian byte order.
+	///
+	/// See [`read_q8_8_be`](Self::read_q8_8_be) for the format.
+	///
+	/// # Panics
+	/// Panics if `offset + 2` exceeds the length of this array.
+	fn read_q8_8_le(&self, offset: usize) -> f64 {
+		q8_8(i16::from_le_bytes(self.copy_sub_array(offset)))
+	}
+
+	/// Reads the signed Q8.8 fixed-point number starting at `offset`, in
+	/// little-endian byte order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + 2` exceeds the length of this array.
+	fn try_read_q8_8_le(&self, offset: usize) -> Option<f64> {
+		Some(q8_8(i16::from_le_bytes(self.try_copy_sub_array(offset)?)))
+	}
+
+	/// Reads the signed Q16.16 fixed-point number starting at `offset`, in
+	/// big-endian byte order.
+	///
+	/// A Q16.16 number is a 32-bit two's complement integer, whose lower 16
+	/// bits are the fractional part, i.e. its value is the integer divided by
+	/// `2^16`. The conversion to `f64` is exact.
+	///
+	/// # Panics
+	/// Panics if `offset + 4` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 4] = [0xFF, 0xFE, 0xC0, 0x00];
+	///
+	/// assert_eq!(arr.read_q16_16_be(0), -1.25);
+	/// ```
+	fn read_q16_16_be(&self, offset: usize) -> f64 {
+		q16_16(i32::from_be_bytes(self.copy_sub_array(offset)))
+	}
+
+	/// Reads the signed Q16.16 fixed-point number starting at `offset`, in
+	/// big-endian byte order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + 4` exceeds the length of this array.
+	/// Also see [`read_q16_16_be`](Self::read_q16_16_be).
+	fn try_read_q16_16_be(&self, offset: usize) -> Option<f64> {
+		Some(q16_16(i32::from_be_bytes(self.try_copy_sub_array(offset)?)))
+	}
+
+	/// Reads the signed Q16.16 fixed-point number starting at `offset`, in
+	/// little-endian byte order.
+	///
+	/// See [`read_q16_16_be`](Self::read_q16_16_be) for the format.
+	///
+	/// # Panics
+	/// Panics if `offset + 4` exceeds the length of this array.
+	fn read_q16_16_le(&self, offset: usize) -> f64 {
+		q16_16(i32::from_le_bytes(self.copy_sub_array(offset)))
+	}
+
+	/// Reads the signed Q16.16 fixed-point number starting at `offset`, in
+	/// little-endian byte order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + 4` exceeds the length of this array.
+	fn try_read_q16_16_le(&self, offset: usize) -> Option<f64> {
+		Some(q16_16(i32::from_le_bytes(self.try_copy_sub_array(offset)?)))
+	}
+
+	/// Reads the integer starting at `offset`, in the byte order `E`.
+	///
+	/// This allows to write parsers once, generic over the [`Endian`] byte
+	/// order, and to instantiate them per byte order.
+	///
+	/// # Panics
+	/// Panics if `offset + size_of::<I>()` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::BigEndian;
+	/// use sub_array::ByteSubArray;
+	/// use sub_array::LittleEndian;
+	///
+	/// let arr: [u8; 3] = [0xFF, 0x12, 0x34];
+	///
+	/// assert_eq!(arr.read_int::<u16, BigEndian>(1), 0x1234);
+	/// assert_eq!(arr.read_int::<u16, LittleEndian>(1), 0x3412);
+	/// ```
+	#[track_caller]
+	fn read_int<I: EndianInt, E: Endian>(&self, offset: usize) -> I
+	where
+		Self: AsRef<[u8]>,
+	{
+		match self.try_read_int::<I, E>(offset) {
+			Some(value) => value,
+			None => out_of_bounds(offset, core::mem::size_of::<I>(), self.as_ref().len()),
+		}
+	}
+
+	/// Reads the integer starting at `offset`, in the byte order `E`, if it is
+	/// in bounds.
+	///
+	/// Returns `None` if `offset + size_of::<I>()` exceeds the length of this
+	/// array.
+	/// Also see [`read_int`](Self::read_int).
+	fn try_read_int<I: EndianInt, E: Endian>(&self, offset: usize) -> Option<I>
+	where
+		Self: AsRef<[u8]>,
+	{
+		let mut bytes = I::Bytes::default();
+		let len = bytes.as_ref().len();
+		bytes
+			.as_mut()
+			.copy_from_slice(self.as_ref().get(offset..)?.get(..len)?);
+		Some(E::convert(I::from_ne_bytes(bytes)))
+	}
+
+	/// Writes the integer `value` starting at `offset`, in the byte order `E`.
+	///
+	/// Also see [`read_int`](Self::read_int).
+	///
+	/// # Panics
+	/// Panics if `offset + size_of::<I>()` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::BigEndian;
+	/// use sub_array::ByteSubArray;
+	///
+	/// let mut arr: [u8; 3] = [0xFF; 3];
+	///
+	/// arr.write_int::<u16, BigEndian>(1, 0x1234);
+	/// assert_eq!(arr, [0xFF, 0x12, 0x34]);
+	/// ```
+	#[track_caller]
+	fn write_int<I: EndianInt, E: Endian>(&mut self, offset: usize, value: I)
+	where
+		Self: AsMut<[u8]>,
+	{
+		let len = self.as_mut().len();
+		if self.try_write_int::<I, E>(offset, value).is_none() {
+			out_of_bounds(offset, core::mem::size_of::<I>(), len);
+		}
+	}
+
+	/// Writes the integer `value` starting at `offset`, in the byte order `E`,
+	/// if it is in bounds.
+	///
+	/// Returns `None` if `offset + size_of::<I>()` exceeds the length of this
+	/// array, in which case nothing is written.
+	/// Also see [`write_int`](Self::write_int).
+	fn try_write_int<I: EndianInt, E: Endian>(&mut self, offset: usize, value: I) -> Option<()>
+	where
+		Self: AsMut<[u8]>,
+	{
+		let bytes = E::convert(value).to_ne_bytes();
+		let bytes = bytes.as_ref();
+		self.as_mut()
+			.get_mut(offset..)?
+			.get_mut(..bytes.len())?
+			.copy_from_slice(bytes);
+		Some(())
+	}
+
+	/// Reads the value of type `T` starting at `offset`, in big-endian byte
+	/// order.
+	///
+	/// The number of bytes to read is inferred from `T`, thus unlike with
+	/// e.g. `u32::from_be_bytes(*arr.sub_array_ref::<4>(offset))`, annotating
+	/// the type of the result is sufficient.
+	///
+	/// # Panics
+	/// Panics if `offset + size_of::<T>()` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 7] = [0xFF, 0x12, 0x34, 0x3F, 0x80, 0x00, 0x00];
+	///
+	/// let x: u16 = arr.read_be(1);
+	/// let y: f32 = arr.read_be(3);
+	/// assert_eq!((x, y), (0x1234, 1.0));
+	/// ```
+	///
+	/// Types that can not be decoded from bytes are rejected:
+	/// ```compile_fail,E0277
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 4] = [0; 4];
+	/// let c: char = arr.read_be(0);
+	/// ```
+	#[track_caller]
+	fn read_be<T: FromSubArrayBytes>(&self, offset: usize) -> T
+	where
+		Self: AsRef<[u8]>,
+	{
+		match self.try_read_be(offset) {
+			Some(value) => value,
+			None => {
+				out_of_bounds(
+					offset,
+					core::mem::size_of::<T::Bytes>(),
+					self.as_ref().len(),
+				)
+			},
+		}
+	}
+
+	/// Reads the value of type `T` starting at `offset`, in big-endian byte
+	/// order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + size_of::<T>()` exceeds the length of this
+	/// array.
+	/// Also see [`read_be`](Self::read_be).
+	fn try_read_be<T: FromSubArrayBytes>(&self, offset: usize) -> Option<T>
+	where
+		Self: AsRef<[u8]>,
+	{
+		read_bytes::<T>(self.as_ref(), offset).map(T::from_be_bytes)
+	}
+
+	/// Reads the value of type `T` starting at `offset`, in little-endian
+	/// byte order.
+	///
+	/// See [`read_be`](Self::read_be) for details.
+	///
+	/// # Panics
+	/// Panics if `offset + size_of::<T>()` exceeds the length of this array.
+	#[track_caller]
+	fn read_le<T: FromSubArrayBytes>(&self, offset: usize) -> T
+	where
+		Self: AsRef<[u8]>,
+	{
+		match self.try_read_le(offset) {
+			Some(value) => value,
+			None => {
+				out_of_bounds(
+					offset,
+					core::mem::size_of::<T::Bytes>(),
+					self.as_ref().len(),
+				)
+			},
+		}
+	}
+
+	/// Reads the value of type `T` starting at `offset`, in little-endian
+	/// byte order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + size_of::<T>()` exceeds the length of this
+	/// array.
+	fn try_read_le<T: FromSubArrayBytes>(&self, offset: usize) -> Option<T>
+	where
+		Self: AsRef<[u8]>,
+	{
+		read_bytes::<T>(self.as_ref(), offset).map(T::from_le_bytes)
+	}
+
+	/// Reads the value of type `T` starting at `offset`, in native byte
+	/// order.
+	///
+	/// See [`read_be`](Self::read_be) for details.
+	///
+	/// # Panics
+	/// Panics if `offset + size_of::<T>()` exceeds the length of this array.
+	#[track_caller]
+	fn read_ne<T: FromSubArrayBytes>(&self, offset: usize) -> T
+	where
+		Self: AsRef<[u8]>,
+	{
+		match self.try_read_ne(offset) {
+			Some(value) => value,
+			None => {
+				out_of_bounds(
+					offset,
+					core::mem::size_of::<T::Bytes>(),
+					self.as_ref().len(),
+				)
+			},
+		}
+	}
+
+	/// Reads the value of type `T` starting at `offset`, in native byte
+	/// order, if it is in bounds.
+	///
+	/// Returns `None` if `offset + size_of::<T>()` exceeds the length of this
+	/// array.
+	fn try_read_ne<T: FromSubArrayBytes>(&self, offset: usize) -> Option<T>
+	where
+		Self: AsRef<[u8]>,
+	{
+		read_bytes::<T>(self.as_ref(), offset).map(T::from_ne_bytes)
+	}
+
+	/// Decodes a value of type `T` from the `N` bytes starting at `offset`.
+	///
+	/// This calls [`FromSubArray::from_sub_array`] with the sub-array, thus
+	/// `N` is usually inferred from `T`, see [`FromSubArray`] for an example.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	#[track_caller]
+	fn read<T: FromSubArray<N>, const N: usize>(&self, offset: usize) -> T {
+		T::from_sub_array(self.sub_array_ref(offset))
+	}
+
+	/// Decodes a value of type `T` from the `N` bytes starting at `offset`,
+	/// if they are in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`read`](Self::read).
+	fn try_read<T: FromSubArray<N>, const N: usize>(&self, offset: usize) -> Option<T> {
+		self.try_sub_array_ref(offset).map(T::from_sub_array)
+	}
+
+	/// Reads `K` consecutive integers starting at `offset`, each in the byte
+	/// order `E`.
+	///
+	/// Also see [`read_int`](Self::read_int).
+	///
+	/// # Panics
+	/// Panics if `offset + K * size_of::<I>()` exceeds the length of this
+	/// array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	/// use sub_array::LittleEndian;
+	///
+	/// let arr: [u8; 5] = [0xFF, 1, 0, 2, 0];
+	///
+	/// assert_eq!(arr.read_int_array::<u16, LittleEndian, 2>(1), [1, 2]);
+	/// ```
+	#[track_caller]
+	fn read_int_array<I: EndianInt, E: Endian, const K: usize>(&self, offset: usize) -> [I; K]
+	where
+		Self: AsRef<[u8]>,
+	{
+		match self.try_read_int_array::<I, E, K>(offset) {
+			Some(values) => values,
+			None => {
+				let size = K.saturating_mul(core::mem::size_of::<I>());
+				out_of_bounds(offset, size, self.as_ref().len())
+			},
+		}
+	}
+
+	/// Reads `K` consecutive integers starting at `offset`, each in the byte
+	/// order `E`, if they are all in bounds.
+	///
+	/// Returns `None` if `offset + K * size_of::<I>()` exceeds the length of
+	/// this array.
+	/// Also see [`read_int_array`](Self::read_int_array).
+	fn try_read_int_array<I: EndianInt, E: Endian, const K: usize>(
+		&self,
+		offset: usize,
+	) -> Option<[I; K]>
+	where
+		Self: AsRef<[u8]>,
+	{
+		let size = core::mem::size_of::<I::Bytes>();
+		let region = self.as_ref().get(offset..)?.get(..K.checked_mul(size)?)?;
+		Some(core::array::from_fn(|i| region.read_int::<I, E>(i * size)))
+	}
+
+	/// Writes the `K` integers of `values` consecutively starting at `offset`,
+	/// each in the byte order `E`.
+	///
+	/// Also see [`write_int`](Self::write_int).
+	///
+	/// # Panics
+	/// Panics if `offset + K * size_of::<I>()` exceeds the length of this
+	/// array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::BigEndian;
+	/// use sub_array::ByteSubArray;
+	///
+	/// let mut arr: [u8; 64] = [0; 64];
+	///
+	/// arr.write_int_array::<u32, BigEndian, 4>(8, &[1, 2, 3, 4]);
+	/// assert_eq!(arr[8..16], [0, 0, 0, 1, 0, 0, 0, 2]);
+	/// ```
+	#[track_caller]
+	fn write_int_array<I: EndianInt, E: Endian, const K: usize>(
+		&mut self,
+		offset: usize,
+		values: &[I; K],
+	) where
+		Self: AsMut<[u8]>,
+	{
+		let len = self.as_mut().len();
+		let size = K.saturating_mul(core::mem::size_of::<I>());
+		match self.try_write_int_array::<I, E, K>(offset, values) {
+			Some(()) => {},
+			None => out_of_bounds(offset, size, len),
+		}
+	}
+
+	/// Writes the `K` integers of `values` consecutively starting at `offset`,
+	/// each in the byte order `E`, if they are all in bounds.
+	///
+	/// Returns `None` if `offset + K * size_of::<I>()` exceeds the length of
+	/// this array, in which case nothing is written.
+	/// Also see [`write_int_array`](Self::write_int_array).
+	fn try_write_int_array<I: EndianInt, E: Endian, const K: usize>(
+		&mut self,
+		offset: usize,
+		values: &[I; K],
+	) -> Option<()>
+	where
+		Self: AsMut<[u8]>,
+	{
+		let size = core::mem::size_of::<I::Bytes>();
+		let region = self
+			.as_mut()
+			.get_mut(offset..)?
+			.get_mut(..K.checked_mul(size)?)?;
+		for (i, &value) in values.iter().enumerate() {
+			region.write_int::<I, E>(i * size, value);
+		}
+		Some(())
+	}
+
+	/// Reads `K` consecutive integers starting at `offset`, each in
+	/// big-endian byte order.
+	///
+	/// This is a shorthand for
+	/// [`read_int_array::<I, BigEndian, K>`](Self::read_int_array).
+	///
+	/// # Panics
+	/// Panics if `offset + K * size_of::<I>()` exceeds the length of this
+	/// array.
+	#[track_caller]
+	fn read_array_be<I: EndianInt, const K: usize>(&self, offset: usize) -> [I; K]
+	where
+		Self: AsRef<[u8]>,
+	{
+		self.read_int_array::<I, BigEndian, K>(offset)
+	}
+
+	/// Reads `K` consecutive integers starting at `offset`, each in
+	/// little-endian byte order.
+	///
+	/// This is a shorthand for
+	/// [`read_int_array::<I, LittleEndian, K>`](Self::read_int_array).
+	///
+	/// # Panics
+	/// Panics if `offset + K * size_of::<I>()` exceeds the length of this
+	/// array.
+	#[track_caller]
+	fn read_array_le<I: EndianInt, const K: usize>(&self, offset: usize) -> [I; K]
+	where
+		Self: AsRef<[u8]>,
+	{
+		self.read_int_array::<I, LittleEndian, K>(offset)
+	}
+
+	/// Writes the `K` integers of `values` consecutively starting at `offset`,
+	/// each in big-endian byte order.
+	///
+	/// This is a shorthand for
+	/// [`write_int_array::<I, BigEndian, K>`](Self::write_int_array), where
+	/// both the integer type and `K` are inferred from `values`.
+	///
+	/// # Panics
+	/// Panics if `offset + K * size_of::<I>()` exceeds the length of this
+	/// array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let mut arr: [u8; 6] = [0; 6];
+	///
+	/// arr.write_array_be(1, &[0x1234_u16, 0x5678]);
+	/// assert_eq!(arr, [0, 0x12, 0x34, 0x56, 0x78, 0]);
+	/// assert_eq!(arr.read_array_be::<u16, 2>(1), [0x1234, 0x5678]);
+	/// ```
+	#[track_caller]
+	fn write_array_be<I: EndianInt, const K: usize>(&mut self, offset: usize, values: &[I; K])
+	where
+		Self: AsMut<[u8]>,
+	{
+		self.write_int_array::<I, BigEndian, K>(offset, values)
+	}
+
+	/// Writes the `K` integers of `values` consecutively starting at `offset`,
+	/// each in little-endian byte order.
+	///
+	/// This is a shorthand for
+	/// [`write_int_array::<I, LittleEndian, K>`](Self::write_int_array),
+	/// where both the integer type and `K` are inferred from `values`.
+	///
+	/// # Panics
+	/// Panics if `offset + K * size_of::<I>()` exceeds the length of this
+	/// array.
+	#[track_caller]
+	fn write_array_le<I: EndianInt, const K: usize>(&mut self, offset: usize, values: &[I; K])
+	where
+		Self: AsMut<[u8]>,
+	{
+		self.write_int_array::<I, LittleEndian, K>(offset, values)
+	}
+
+	/// Reads `N` consecutive `u16` starting at `offset`, swapping the bytes of
+	/// each.
+	///
+	/// Each integer is read in native byte order and then byte-swapped via
+	/// [`u16::swap_bytes`], which is the same as reading them in the opposite
+	/// of the native byte order. This is useful if the source has a different
+	/// endianness than the reader assumed.
+	///
+	/// # Panics
+	/// Panics if `offset + 2 * N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 5] = [0xFF, 0x12, 0x34, 0x56, 0x78];
+	///
+	/// let native: u16 = u16::from_ne_bytes([0x12, 0x34]);
+	/// assert_eq!(arr.sub_array_swap_bytes_u16::<2>(1)[0], native.swap_bytes());
+	/// ```
+	#[track_caller]
+	fn sub_array_swap_bytes_u16<const N: usize>(&self, offset: usize) -> [u16; N]
+	where
+		Self: AsRef<[u8]>,
+	{
+		let (chunks, _) = byte_region(self.as_ref(), offset, N, 2).as_chunks();
+		core::array::from_fn(|i| u16::from_ne_bytes(chunks[i]).swap_bytes())
+	}
+
+	/// Reads `N` consecutive `u16` starting at `offset`, swapping the bytes of
+	/// each, if they are in bounds.
+	///
+	/// Returns `None` if `offset + 2 * N` exceeds the length of this array.
+	fn try_sub_array_swap_bytes_u16<const N: usize>(&self, offset: usize) -> Option<[u16; N]>
+	where
+		Self: AsRef<[u8]>,
+	{
+		let (chunks, _) = try_byte_region(self.as_ref(), offset, N, 2)?.as_chunks();
+		Some(core::array::from_fn(|i| {
+			u16::from_ne_bytes(chunks[i]).swap_bytes()
+		}))
+	}
+
+	/// Reads `N` consecutive `u32` starting at `offset`, swapping the bytes of
+	/// each.
+	///
+	/// See [`sub_array_swap_bytes_u16`](Self::sub_array_swap_bytes_u16) for
+	/// details.
+	///
+	/// # Panics
+	/// Panics if `offset + 4 * N` exceeds the length of this array.
+	#[track_caller]
+	fn sub_array_swap_bytes_u32<const N: usize>(&self, offset: usize) -> [u32; N]
+	where
+		Self: AsRef<[u8]>,
+	{
+		let (chunks, _) = byte_region(self.as_ref(), offset, N, 4).as_chunks();
+		core::array::from_fn(|i| u32::from_ne_bytes(chunks[i]).swap_bytes())
+	}
+
+	/// Reads `N` consecutive `u32` starting at `offset`, swapping the bytes of
+	/// each, if they are in bounds.
+	///
+	/// Returns `None` if `offset + 4 * N` exceeds the length of this array.
+	fn try_sub_array_swap_bytes_u32<const N: usize>(&self, offset: usize) -> Option<[u32; N]>
+	where
+		Self: AsRef<[u8]>,
+	{
+		let (chunks, _) = try_byte_region(self.as_ref(), offset, N, 4)?.as_chunks();
+		Some(core::array::from_fn(|i| {
+			u32::from_ne_bytes(chunks[i]).swap_bytes()
+		}))
+	}
+
+	/// Counts the occurrences of each byte value in the sub-array of length
+	/// `N` starting at `offset`.
+	///
+	/// The count of the byte value `b` is at index `b` of the returned
+	/// histogram, thus all counts sum up to `N`.
+	///
+	/// Fails to compile if `N` exceeds [`u32::MAX`], since the counts could
+	/// overflow.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 5] = *b"hello";
+	///
+	/// let histogram = arr.sub_array_histogram::<4>(1);
+	/// assert_eq!(histogram[usize::from(b'l')], 2);
+	/// assert_eq!(histogram[usize::from(b'h')], 0);
+	/// ```
+	fn sub_array_histogram<const N: usize>(&self, offset: usize) -> [u32; 256] {
+		const { assert!(N as u64 <= u32::MAX as u64, "histogram counts may overflow") };
+		histogram(self.sub_array_ref::<N>(offset))
+	}
+
+	/// Counts the occurrences of each byte value in the sub-array of length
+	/// `N` starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_sub_array_histogram<const N: usize>(&self, offset: usize) -> Option<[u32; 256]> {
+		const { assert!(N as u64 <= u32::MAX as u64, "histogram counts may overflow") };
+		Some(histogram(self.try_sub_array_ref::<N>(offset)?))
+	}
+
+	/// Computes the Shannon entropy, in bits per byte, of the sub-array of
+	/// length `N` starting at `offset`.
+	///
+	/// The entropy is `-sum(p * log2(p))` over the relative frequencies `p` of
+	/// all byte values occurring in the sub-array, see
+	/// [`sub_array_histogram`](Self::sub_array_histogram). It ranges from
+	/// `0.0`, if all bytes are equal (or `N == 0`), to `8.0`, if all byte
+	/// values are equally frequent.
+	///
+	/// Requires either the `std` or the `libm` feature.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 6] = [7, 7, 0, 1, 2, 3];
+	///
+	/// assert_eq!(arr.sub_array_entropy::<2>(0), 0.0);
+	/// assert_eq!(arr.sub_array_entropy::<4>(2), 2.0);
+	/// ```
+	#[cfg(any(feature = "std", feature = "libm"))]
+	fn sub_array_entropy<const N: usize>(&self, offset: usize) -> f64 {
+		entropy(&self.sub_array_histogram::<N>(offset), N)
+	}
+
+	/// Computes the Shannon entropy, in bits per byte, of the sub-array of
+	/// length `N` starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`sub_array_entropy`](Self::sub_array_entropy).
+	///
+	/// Requires either the `std` or the `libm` feature.
+	#[cfg(any(feature = "std", feature = "libm"))]
+	fn try_sub_array_entropy<const N: usize>(&self, offset: usize) -> Option<f64> {
+		Some(entropy(&self.try_sub_array_histogram::<N>(offset)?, N))
+	}
+
+	/// Counts the bits that differ between the sub-array of length `N`
+	/// starting at `offset` and `other`, i.e. their Hamming distance.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let arr: [u8; 4] = [0xFF, 0b1010, 0b0000, 0xFF];
+	///
+	/// assert_eq!(arr.hamming_distance_sub_array(1, &[0b1010, 0b0000]), 0);
+	/// assert_eq!(arr.hamming_distance_sub_array(1, &[0b0011, 0b0001]), 3);
+	/// ```
+	fn hamming_distance_sub_array<const N: usize>(&self, offset: usize, other: &[u8; N]) -> u32 {
+		hamming_distance(self.sub_array_ref(offset), other)
+	}
+
+	/// Counts the bits that differ between the sub-array of length `N`
+	/// starting at `offset` and `other`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_hamming_distance_sub_array<const N: usize>(
+		&self,
+		offset: usize,
+		other: &[u8; N],
+	) -> Option<u32> {
+		Some(hamming_distance(self.try_sub_array_ref(offset)?, other))
+	}
+
+	/// Encodes the sub-array of length `N` starting at `offset` as `M` hex
+	/// digits into `hex`.
+	///
+	/// Each byte is encoded as two lowercase hex digits, thus this fails to
+	/// compile unless `M == 2 * N`. For the inverse, see
+	/// [`HexSubArray::sub_array_from_hex`](crate::HexSubArray::sub_array_from_hex).
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	///
+	/// let hash: [u8; 6] = [0xFF, 0xDE, 0xAD, 0xBE, 0xEF, 0xFF];
+	///
+	/// let mut hex = [0; 8];
+	/// hash.sub_array_to_hex::<4, 8>(1, &mut hex);
+	/// assert_eq!(&hex, b"deadbeef");
+	/// ```
+	fn sub_array_to_hex<const N: usize, const M: usize>(&self, offset: usize, hex: &mut [u8; M]) {
+		const { assert!(M.is_multiple_of(2) && M / 2 == N, "M must be 2 * N") };
+		encode_hex(self.sub_array_ref::<N>(offset), hex);
+	}
+
+	/// Encodes the sub-array of length `N` starting at `offset` as `M` hex
+	/// digits into `hex`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array, in
+	/// which case nothing is written.
+	/// Also see [`sub_array_to_hex`](Self::sub_array_to_hex).
+	fn try_sub_array_to_hex<const N: usize, const M: usize>(
+		&self,
+		offset: usize,
+		hex: &mut [u8; M],
+	) -> Option<()> {
+		const { assert!(M.is_multiple_of(2) && M / 2 == N, "M must be 2 * N") };
+		encode_hex(self.try_sub_array_ref::<N>(offset)?, hex);
+		Some(())
+	}
+
+	/// Get a [`TextWindow`] over the sub-array of length `N` starting at
+	/// `offset`, to [`write!`] formatted text into it.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use core::fmt::Write;
+	///
+	/// use sub_array::ByteSubArray;
+	///
+	/// let mut buf: [u8; 12] = *b"name=       ";
+	///
+	/// write!(buf.text_window::<7>(5), "{}", 42).unwrap();
+	/// assert_eq!(&buf, b"name=42     ");
+	/// ```
+	fn text_window<const N: usize>(&mut self, offset: usize) -> TextWindow<'_> {
+		TextWindow::new(self.sub_array_mut::<N>(offset))
+	}
+
+	/// Get a [`TextWindow`] over the sub-array of length `N` starting at
+	/// `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`text_window`](Self::text_window).
+	fn try_text_window<const N: usize>(&mut self, offset: usize) -> Option<TextWindow<'_>> {
+		Some(TextWindow::new(self.try_sub_array_mut::<N>(offset)?))
+	}
+
+	/// Decodes the tag byte at `offset` followed by a payload, whose length
+	/// depends on the tag.
+	///
+	/// If the tag is `tag_a`, the payload is the `A` bytes following it, if
+	/// it is `tag_b`, it is the `B` bytes following it. Only the payload
+	/// selected by the tag has to be in bounds. If both tags are equal, the
+	/// first one wins.
+	///
+	/// # Errors
+	/// Returns [`TagError::OutOfBounds`] if the tag byte or the selected
+	/// payload exceeds the end of this array, and [`TagError::UnknownTag`] if
+	/// the tag is neither `tag_a` nor `tag_b`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::ByteSubArray;
+	/// use sub_array::TagError;
+	/// use sub_array::Tagged;
+	///
+	/// let msg: [u8; 4] = [0x01, 0x00, 0x2A, 0x00];
+	///
+	/// assert_eq!(msg.read_tagged::<2, 8>(0, 0x01, 0x02), Ok(Tagged::A(&[0x00, 0x2A])));
+	/// assert_eq!(msg.read_tagged::<2, 8>(1, 0x01, 0x02), Err(TagError::UnknownTag { tag: 0x00 }));
+	/// ```
+	fn read_tagged<const A: usize, const B: usize>(
+		&self,
+		offset: usize,
+		tag_a: u8,
+		tag_b: u8,
+	) -> Result<Tagged<'_, A, B>, TagError> {
+		let &[tag] = self
+			.try_sub_array_ref::<1>(offset)
+			.ok_or(TagError::OutOfBounds)?;
+
+		let payload = offset + 1;
+		let tagged = if tag == tag_a {
+			self.try_sub_array_ref(payload).map(Tagged::A)
+		} else if tag == tag_b {
+			self.try_sub_array_ref(payload).map(Tagged::B)
+		} else {
+			return Err(TagError::UnknownTag {
+				tag,
+			});
+		};
+		tagged.ok_or(TagError::OutOfBounds)
+	}
+}
+
+impl<A> ByteSubArray for A where A: SubArray<Item = u8> + ?Sized {}
+
+/// Get the `count` elements of `size` bytes each, starting at `offset`
+///
+/// # Panics
+/// Panics via [`out_of_bounds`] if they are not in bounds.
+#[track_caller]
+fn byte_region(bytes: &[u8], offset: usize, count: usize, size: usize) -> &[u8] {
+	match try_byte_region(bytes, offset, count, size) {
+		Some(region) => region,
+		None => out_of_bounds(offset, count.saturating_mul(size), bytes.len()),
+	}
+}
+
+/// Get the `count` elements of `size` bytes each, starting at `offset`, if
+/// they are in bounds
+fn try_byte_region(bytes: &[u8], offset: usize, count: usize, size: usize) -> Option<&[u8]> {
+	bytes.get(offset..)?.get(..count.checked_mul(size)?)
+}
+
+/// Copies the raw bytes of a `T` starting at `offset`
+fn read_bytes<T: FromSubArrayBytes>(bytes: &[u8], offset: usize) -> Option<T::Bytes> {
+	let mut raw = T::Bytes::default();
+	let len = raw.as_ref().len();
+	raw.as_mut()
+		.copy_from_slice(bytes.get(offset..)?.get(..len)?);
+	Some(raw)
+}
+
+/// Converts the raw value of a Q8.8 fixed-point number
+fn q8_8(raw: i16) -> f64 {
+	f64::from(raw) / f64::from(1_u32 << 8)
+}
+
+/// Converts the raw value of a Q16.16 fixed-point number
+fn q16_16(raw: i32) -> f64 {
+	f64::from(raw) / f64::from(1_u32 << 16)
+}
+
+/// Computes the Shannon entropy of `len` bytes with the given `histogram`
+#[cfg(any(feature = "std", feature = "libm"))]
+fn entropy(histogram: &[u32; 256], len: usize) -> f64 {
+	#[cfg(feature = "std")]
+	let log2 = f64::log2;
+	#[cfg(not(feature = "std"))]
+	let log2 = libm::log2;
+
+	let len = len as f64;
+	histogram
+		.iter()
+		.filter(|&&count| count > 0)
+		.map(|&count| {
+			let p = f64::from(count) / len;
+			-p * log2(p)
+		})
+		.sum()
+}
+
+/// Counts the bits that differ between `a` and `b`
+fn hamming_distance<const N: usize>(a: &[u8; N], b: &[u8; N]) -> u32 {
+	a.iter().zip(b).map(|(a, b)| (a ^ b).count_ones()).sum()
+}
+
+/// Counts the occurrences of each byte value
+fn histogram(bytes: &[u8]) -> [u32; 256] {
+	let mut counts = [0; 256];
+	for &b in bytes {
+		counts[usize::from(b)] += 1;
+	}
+	counts
+}
+
+
+/// Get a reference to a sub-array of `bytes` of length `N` starting at
+/// `offset`, in const context.
+///
+/// Trait methods can not be called in const context, thus this is a
+/// standalone function, which is handy to build compile-time tables, e.g. of
+/// magic numbers, from byte string literals.
+///
+/// # Panics
+/// Panics if `offset + N` exceeds the length of `bytes`, which in const
+/// context turns into a compile-time error.
+///
+/// # Example
+/// ```
+/// use sub_array::sub_bytes_const;
+///
+/// const MAGIC: &[u8; 4] = b"PK\x03\x04";
+/// const MAGIC_HEAD: &[u8; 2] = sub_bytes_const(MAGIC, 0);
+/// const MAGIC_TAIL: &[u8; 2] = sub_bytes_const(b"PK\x03\x04", 2);
+///
+/// assert_eq!(MAGIC_HEAD, b"PK");
+/// assert_eq!(MAGIC_TAIL, &[3, 4]);
+///
+/// // Use them as patterns
+/// let header: &[u8] = b"PK\x03\x04";
+/// match sub_bytes_const::<2>(header, 2) {
+///     MAGIC_TAIL => {},
+///     _ => panic!(),
+/// }
+/// ```
+///
+/// Exceeding the bounds fails to compile:
+/// ```compile_fail
+/// use sub_array::sub_bytes_const;
+///
+/// const TOO_LONG: &[u8; 3] = sub_bytes_const(b"PK\x03\x04", 2);
+/// ```
+pub const fn sub_bytes_const<const N: usize>(bytes: &[u8], offset: usize) -> &[u8; N] {
+	if offset > bytes.len() {
+		panic!("sub-array offset out of bounds");
+	}
+	let (_, tail) = bytes.split_at(offset);
+	match tail.first_chunk() {
+		Some(sub) => sub,
+		None => panic!("sub-array end out of bounds"),
+	}
+}
+
+/// Asserts at compile time that `N` bytes are exactly the size of a `T`.
+///
+/// This crate never transmutes, but when a byte sub-array is transmuted (or
+/// otherwise reinterpreted) into a `T` downstream, placing this guard next to
+/// the extraction turns a mismatch of the lengths into a compile error, even
+/// on stable Rust.
+///
+/// # Example
+/// ```
+/// use sub_array::assert_byte_len;
+/// use sub_array::SubArray;
+///
+/// let arr: [u8; 6] = [0, 1, 2, 3, 4, 5];
+///
+/// const LEN: usize = size_of::<u32>();
+/// assert_byte_len::<u32, LEN>();
+/// let bytes: &[u8; LEN] = arr.sub_array_ref(1);
+///
+/// assert_eq!(u32::from_be_bytes(*bytes), 0x01020304);
+/// ```
+///
+/// A mismatch fails to compile:
+/// ```compile_fail
+/// use sub_array::assert_byte_len;
+///
+/// assert_byte_len::<u32, 2>();
+/// ```
+pub const fn assert_byte_len<T, const N: usize>() {
+	const {
+		assert!(
+			N == size_of::<T>(),
+			"byte length does not match the size of the type"
+		)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeEndian;
+
+
+	#[test]
+	fn uppercase_keeps_neighbors() {
+		let mut arr = *b"\x00abc\xFF";
+		arr.make_ascii_uppercase_sub_array::<3>(1);
+		assert_eq!(&arr, b"\x00ABC\xFF");
+
+		let mut arr = *b"abcd";
+		arr.make_ascii_uppercase_sub_array::<2>(1);
+		assert_eq!(&arr, b"aBCd");
+	}
+
+	#[test]
+	fn lowercase_keeps_neighbors() {
+		let mut arr = *b"ABCD";
+		arr.make_ascii_lowercase_sub_array::<2>(1);
+		assert_eq!(&arr, b"AbcD");
+	}
+
+	#[test]
+	fn case_folding_non_ascii() {
+		let mut arr = *b"a\xC3\xA4Z\x80";
+		arr.make_ascii_uppercase_sub_array::<5>(0);
+		assert_eq!(&arr, b"A\xC3\xA4Z\x80");
+		arr.make_ascii_lowercase_sub_array::<5>(0);
+		assert_eq!(&arr, b"a\xC3\xA4z\x80");
+	}
+
+	#[test]
+	fn eq_ignore_case_mixed() {
+		let arr = *b"xHeLlOx";
+		assert!(arr.eq_ignore_ascii_case_sub_array(1, b"hello"));
+		assert!(arr.eq_ignore_ascii_case_sub_array(1, b"HELLO"));
+		assert!(!arr.eq_ignore_ascii_case_sub_array(1, b"hellx"));
+		assert!(!arr.eq_ignore_ascii_case_sub_array(2, b"hello"));
+	}
+
+	#[test]
+	fn is_ascii_high_byte() {
+		let arr = [b'a', b'b', b'c', 0x80, b'd'];
+		assert!(arr.is_ascii_sub_array::<3>(0));
+		assert!(!arr.is_ascii_sub_array::<3>(1));
+		assert!(arr.is_ascii_sub_array::<1>(4));
+	}
+
+	#[test]
+	fn try_forms() {
+		let mut arr = *b"abc";
+		assert_eq!(arr.try_make_ascii_uppercase_sub_array::<4>(0), None);
+		assert_eq!(arr.try_make_ascii_lowercase_sub_array::<1>(3), None);
+		assert_eq!(&arr, b"abc");
+		assert_eq!(arr.try_make_ascii_uppercase_sub_array::<1>(2), Some(()));
+		assert_eq!(&arr, b"abC");
+
+		assert_eq!(arr.try_eq_ignore_ascii_case_sub_array(1, b"BC"), Some(true));
+		assert_eq!(arr.try_eq_ignore_ascii_case_sub_array(2, b"BC"), None);
+		assert_eq!(arr.try_is_ascii_sub_array::<3>(0), Some(true));
+		assert_eq!(arr.try_is_ascii_sub_array::<3>(1), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let mut arr = *b"abc";
+		arr.make_ascii_uppercase_sub_array::<2>(2);
+	}
+
+	fn assert_close(value: f64, expected: f64, tolerance: f64) {
+		assert!(
+			(value - expected).abs() <= tolerance,
+			"{value} is not close to {expected}"
+		);
+	}
+
+	#[test]
+	fn fixed_point_q8_8() {
+		let arr = [0xAA, 0x12, 0x40, 0xFF, 0x80, 0xAA];
+		assert_close(arr.read_q8_8_be(1), 18.25, 0.0);
+		assert_close(arr.read_q8_8_le(1), 64.0 + 18.0 / 256.0, 0.0);
+		assert_close(arr.read_q8_8_be(3), -0.5, 0.0);
+		assert_close(arr.read_q8_8_le(4), -85.5, 0.0);
+		assert_eq!(arr.try_read_q8_8_be(2), Some(arr.read_q8_8_be(2)));
+		assert_eq!(arr.try_read_q8_8_be(5), None);
+		assert_eq!(arr.try_read_q8_8_le(5), None);
+	}
+
+	#[test]
+	fn fixed_point_q16_16() {
+		// 1.2345 rounded to Q16.16 is 0x0001_3C08
+		let arr = [0x00, 0x01, 0x3C, 0x08, 0x00];
+		assert_close(arr.read_q16_16_be(0), 1.2345, 1.0 / 65536.0);
+		let arr_le = [0xFF, 0x08, 0x3C, 0x01, 0x00];
+		assert_close(arr_le.read_q16_16_le(1), 1.2345, 1.0 / 65536.0);
+
+		let min = [0x00, 0x00, 0x00, 0x80];
+		assert_close(min.read_q16_16_le(0), -32768.0, 0.0);
+		assert_close(min.read_q16_16_be(0), 128.0 / 65536.0, 0.0);
+
+		assert_eq!(arr.try_read_q16_16_be(2), None);
+		assert_eq!(arr.try_read_q16_16_le(2), None);
+		assert_eq!(arr.try_read_q16_16_le(1), Some(arr.read_q16_16_le(1)));
+	}
+
+	#[test]
+	#[should_panic]
+	fn fixed_point_out_of_bounds() {
+		let arr = [0_u8; 3];
+		arr.read_q16_16_be(0);
+	}
+
+	#[test]
+	fn swap_bytes() {
+		let arr = [0xFF, 0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+
+		assert_eq!(
+			arr.sub_array_swap_bytes_u16::<3>(1),
+			[
+				u16::from_ne_bytes([0x34, 0x12]),
+				u16::from_ne_bytes([0x78, 0x56]),
+				u16::from_ne_bytes([0xBC, 0x9A]),
+			]
+		);
+		assert_eq!(
+			arr.sub_array_swap_bytes_u32::<2>(1),
+			[
+				u32::from_ne_bytes([0x78, 0x56, 0x34, 0x12]),
+				u32::from_ne_bytes([0xF0, 0xDE, 0xBC, 0x9A]),
+			]
+		);
+		assert_eq!(arr.sub_array_swap_bytes_u32::<0>(9), []);
+
+		// Reading little-endian data as big-endian on a little-endian host
+		#[cfg(target_endian = "little")]
+		assert_eq!(arr.sub_array_swap_bytes_u16::<1>(1), [0x1234]);
+		#[cfg(target_endian = "big")]
+		assert_eq!(arr.sub_array_swap_bytes_u16::<1>(1), [0x3412]);
+	}
+
+	#[test]
+	fn try_swap_bytes() {
+		let arr = [0x12, 0x34, 0x56, 0x78, 0x9A_u8];
+
+		assert_eq!(
+			arr.try_sub_array_swap_bytes_u16::<2>(1),
+			Some([
+				u16::from_ne_bytes([0x56, 0x34]),
+				u16::from_ne_bytes([0x9A, 0x78]),
+			])
+		);
+		assert_eq!(arr.try_sub_array_swap_bytes_u16::<2>(2), None);
+		assert_eq!(arr.try_sub_array_swap_bytes_u16::<0>(6), None);
+		assert_eq!(arr.try_sub_array_swap_bytes_u16::<1>(usize::MAX), None);
+		assert_eq!(
+			arr.try_sub_array_swap_bytes_u32::<1>(0),
+			Some([u32::from_ne_bytes([0x78, 0x56, 0x34, 0x12])])
+		);
+		assert_eq!(arr.try_sub_array_swap_bytes_u32::<1>(2), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 8 at offset 0 exceeds the array of length 7")]
+	fn swap_bytes_out_of_bounds() {
+		let arr = [0_u8; 7];
+		arr.sub_array_swap_bytes_u32::<2>(0);
+	}
+
+	#[test]
+	fn const_bytes() {
+		const BYTES: &[u8] = b"\x7FELF";
+		const TAIL: &[u8; 3] = sub_bytes_const(BYTES, 1);
+		const EMPTY: &[u8; 0] = sub_bytes_const(BYTES, 4);
+		assert_eq!(TAIL, b"ELF");
+		assert_eq!(EMPTY, b"");
+	}
+
+	#[test]
+	#[should_panic]
+	fn const_bytes_out_of_bounds() {
+		sub_bytes_const::<1>(b"abc", 3);
+	}
+
+	#[test]
+	fn on_slices() {
+		let mut arr = *b"abcd";
+		let slice: &mut [u8] = &mut arr;
+		slice.make_ascii_uppercase_sub_array::<2>(0);
+		assert!(slice.eq_ignore_ascii_case_sub_array(0, b"abcd"));
+		assert_eq!(&arr, b"ABcd");
+	}
+
+	#[test]
+	fn histogram_flat() {
+		let flat: [u8; 256] = core::array::from_fn(|i| i as u8);
+		assert_eq!(flat.sub_array_histogram::<256>(0), [1; 256]);
+
+		let histogram = flat.sub_array_histogram::<16>(100);
+		assert_eq!(
+			histogram[99..117],
+			[0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0]
+		);
+	}
+
+	#[test]
+	fn histogram_sum() {
+		let arr = *b"mississippi river";
+
+		let histogram = arr.sub_array_histogram::<11>(0);
+		assert_eq!(histogram.iter().sum::<u32>(), 11);
+		assert_eq!(histogram[usize::from(b's')], 4);
+		assert_eq!(histogram[usize::from(b'p')], 2);
+		assert_eq!(histogram[usize::from(b'r')], 0);
+
+		assert_eq!(arr.sub_array_histogram::<0>(17), [0; 256]);
+		let histogram = arr.try_sub_array_histogram::<6>(11).unwrap();
+		assert_eq!(histogram.iter().sum::<u32>(), 6);
+		assert_eq!(arr.try_sub_array_histogram::<7>(11), None);
+	}
+
+	#[cfg(any(feature = "std", feature = "libm"))]
+	#[test]
+	fn entropy() {
+		assert_eq!([0_u8; 64].sub_array_entropy::<64>(0), 0.0);
+		assert_eq!([0_u8; 64].sub_array_entropy::<0>(64), 0.0);
+
+		let flat: [u8; 256] = core::array::from_fn(|i| i as u8);
+		assert_eq!(flat.sub_array_entropy::<256>(0), 8.0);
+		assert_eq!(flat.sub_array_entropy::<16>(32), 4.0);
+
+		let arr = [42_u8; 300];
+		assert_eq!(arr.sub_array_entropy::<1>(0), 0.0);
+		assert_eq!(arr.sub_array_entropy::<7>(3), 0.0);
+		assert_eq!(arr.sub_array_entropy::<300>(0), 0.0);
+
+		let bits = [0_u8, 1, 0, 1];
+		assert_eq!(bits.try_sub_array_entropy::<4>(0), Some(1.0));
+		assert_eq!(bits.try_sub_array_entropy::<4>(1), None);
+	}
+
+	#[test]
+	fn hamming_distance() {
+		let fingerprint = [0xDE, 0xAD, 0xBE, 0xEF_u8];
+		let slice: &[u8] = &[0, 0xDE, 0xAD, 0xBE, 0xEF];
+
+		assert_eq!(slice.hamming_distance_sub_array(1, &fingerprint), 0);
+		assert_eq!(
+			slice.hamming_distance_sub_array(1, &[0xDE, 0xAD, 0xBE, 0xEE]),
+			1
+		);
+		assert_eq!(
+			slice.hamming_distance_sub_array(1, &[0x5E, 0xAD, 0xBE, 0xEF]),
+			1
+		);
+		assert_eq!(slice.hamming_distance_sub_array(0, &[0xFF]), 8);
+		assert_eq!(slice.hamming_distance_sub_array::<0>(5, &[]), 0);
+
+		assert_eq!(
+			slice.try_hamming_distance_sub_array(1, &fingerprint),
+			Some(0)
+		);
+		assert_eq!(slice.try_hamming_distance_sub_array(2, &fingerprint), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn hamming_distance_out_of_bounds() {
+		let arr = [0_u8; 3];
+		arr.hamming_distance_sub_array(2, &[0, 0]);
+	}
+
+	#[test]
+	fn byte_len() {
+		const _: () = assert_byte_len::<u64, 8>();
+		assert_byte_len::<[u16; 3], 6>();
+		assert_byte_len::<(), 0>();
+	}
+
+	#[test]
+	fn tagged() {
+		let buf = [0xAB, 1, 10, 2, 20, 21, 22, 23];
+
+		assert_eq!(buf.read_tagged::<1, 4>(1, 1, 2), Ok(Tagged::A(&[10])));
+		assert_eq!(
+			buf.read_tagged::<1, 4>(3, 1, 2),
+			Ok(Tagged::B(&[20, 21, 22, 23]))
+		);
+		assert_eq!(buf.read_tagged::<1, 1>(1, 1, 1), Ok(Tagged::A(&[10])));
+		assert_eq!(
+			buf.read_tagged::<1, 4>(0, 1, 2),
+			Err(TagError::UnknownTag {
+				tag: 0xAB
+			})
+		);
+	}
+
+	#[test]
+	fn tagged_out_of_bounds() {
+		let buf: &[u8] = &[2, 20, 21];
+
+		// Long enough for the small payload, but not for the large one
+		assert_eq!(buf.read_tagged::<2, 4>(0, 2, 9), Ok(Tagged::A(&[20, 21])));
+		assert_eq!(buf.read_tagged::<2, 4>(0, 9, 2), Err(TagError::OutOfBounds));
+		assert_eq!(buf.read_tagged::<0, 0>(3, 2, 9), Err(TagError::OutOfBounds));
+		assert_eq!(buf.read_tagged::<0, 1>(2, 21, 9), Ok(Tagged::A(&[])));
+	}
+
+	#[derive(Debug, PartialEq)]
+	struct Header {
+		version: u8,
+		flags: u16,
+		len: u32,
+		id: i64,
+	}
+
+	fn parse<E: Endian>(buf: &[u8; 16]) -> Header {
+		Header {
+			version: buf.read_int::<u8, E>(0),
+			flags: buf.read_int::<u16, E>(1),
+			len: buf.read_int::<u32, E>(3),
+			id: buf.read_int::<i64, E>(7),
+		}
+	}
+
+	fn serialize<E: Endian>(header: &Header) -> [u8; 16] {
+		let mut buf = [0xFF; 16];
+		buf.write_int::<u8, E>(0, header.version);
+		buf.write_int::<u16, E>(1, header.flags);
+		buf.write_int::<u32, E>(3, header.len);
+		buf.write_int::<i64, E>(7, header.id);
+		buf
+	}
+
+	#[test]
+	fn endian_generic_parser() {
+		#[rustfmt::skip]
+		let be = [
+			1,
+			0x12, 0x34,
+			0x00, 0x00, 0x01, 0x00,
+			0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+			0xFF,
+		];
+		// The same fields, with the bytes of each mirrored
+		#[rustfmt::skip]
+		let le = [
+			1,
+			0x34, 0x12,
+			0x00, 0x01, 0x00, 0x00,
+			0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+			0xFF,
+		];
+		let expected = Header {
+			version: 1,
+			flags: 0x1234,
+			len: 256,
+			id: -2,
+		};
+
+		assert_eq!(parse::<BigEndian>(&be), expected);
+		assert_eq!(parse::<LittleEndian>(&le), expected);
+		assert_eq!(serialize::<BigEndian>(&expected), be);
+		assert_eq!(serialize::<LittleEndian>(&expected), le);
+
+		let native = if cfg!(target_endian = "big") { be } else { le };
+		assert_eq!(parse::<NativeEndian>(&native), expected);
+	}
+
+	#[test]
+	fn int_out_of_bounds() {
+		let mut buf = [1, 2, 3_u8];
+		assert_eq!(buf.try_read_int::<u16, BigEndian>(1), Some(0x0203));
+		assert_eq!(buf.try_read_int::<u32, BigEndian>(0), None);
+		assert_eq!(buf.try_read_int::<u8, LittleEndian>(3), None);
+		assert_eq!(buf.try_write_int::<u16, LittleEndian>(2, 0), None);
+		assert_eq!(buf, [1, 2, 3]);
+		assert_eq!(buf.try_write_int::<u16, LittleEndian>(1, 0x0405), Some(()));
+		assert_eq!(buf, [1, 5, 4]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 4 at offset 0 exceeds the array of length 3")]
+	fn read_int_out_of_bounds() {
+		let buf: &[u8] = &[1, 2, 3];
+		buf.read_int::<u32, NativeEndian>(0);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 2 exceeds the array of length 3")]
+	fn write_int_out_of_bounds() {
+		let mut buf = [1, 2, 3_u8];
+		buf.write_int::<u16, NativeEndian>(2, 0);
+	}
+
+	#[test]
+	fn int_array_round_trip() {
+		let values = [0x0102_0304_u32, 0x0506_0708, 0xDEAD_BEEF, 0];
+		let mut buf = [0xFF_u8; 64];
+
+		buf.write_array_be(8, &values);
+		assert_eq!(buf[7], 0xFF);
+		assert_eq!(buf[8..16], [1, 2, 3, 4, 5, 6, 7, 8]);
+		assert_eq!(buf[16..20], [0xDE, 0xAD, 0xBE, 0xEF]);
+		assert_eq!(buf[24], 0xFF);
+		assert_eq!(buf.read_array_be::<u32, 4>(8), values);
+		assert_eq!(buf.read_int_array::<u32, BigEndian, 4>(8), values);
+
+		buf.write_array_le(8, &values);
+		assert_eq!(buf[8..16], [4, 3, 2, 1, 8, 7, 6, 5]);
+		assert_eq!(buf.read_array_le::<u32, 4>(8), values);
+		assert_ne!(buf.read_array_be::<u32, 4>(8), values);
+	}
+
+	#[test]
+	fn int_array_widths() {
+		let mut buf = [0_u8; 16];
+
+		buf.write_array_be(0, &[0x0102_u16, 0x0304]);
+		assert_eq!(buf[..4], [1, 2, 3, 4]);
+		assert_eq!(buf.read_array_le::<u16, 2>(0), [0x0201, 0x0403]);
+
+		buf.write_array_le(0, &[0x0102_0304_0506_0708_u64, u64::MAX]);
+		assert_eq!(buf[..8], [8, 7, 6, 5, 4, 3, 2, 1]);
+		assert_eq!(buf.read_array_le::<u64, 2>(0)[1], u64::MAX);
+
+		buf.write_array_be::<u64, 0>(16, &[]);
+		assert_eq!(buf.read_array_be::<u64, 0>(16), []);
+	}
+
+	#[test]
+	fn int_array_out_of_bounds() {
+		let mut buf = [0_u8; 7];
+		assert_eq!(buf.try_read_int_array::<u16, BigEndian, 4>(0), None);
+		assert_eq!(buf.try_read_int_array::<u16, BigEndian, 3>(1), Some([0; 3]));
+		assert_eq!(
+			buf.try_write_int_array::<u32, LittleEndian, 2>(0, &[1, 2]),
+			None
+		);
+		assert_eq!(buf, [0; 7]);
+		assert_eq!(
+			buf.try_read_int_array::<u8, LittleEndian, 0>(usize::MAX),
+			None
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 4 at offset 4 exceeds the array of length 7")]
+	fn write_int_array_out_of_bounds() {
+		let mut buf = [0_u8; 7];
+		buf.write_array_be(4, &[1_u32]);
+	}
+
+	#[test]
+	fn read_inferred() {
+		let buf = [
+			0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0, // integers
+			0x40, 0x49, 0x0F, 0xDB, // pi as f32
+		];
+
+		let a: u16 = buf.read_be(0);
+		let b: u32 = buf.read_be(0);
+		let c: u64 = buf.read_be(0);
+		let d: f32 = buf.read_be(8);
+		assert_eq!(a, 0x1234);
+		assert_eq!(b, 0x1234_5678);
+		assert_eq!(c, 0x1234_5678_9ABC_DEF0);
+		assert_eq!(d, core::f32::consts::PI);
+
+		let a: u16 = buf.read_le(0);
+		let d: f32 = buf.read_le(8);
+		assert_eq!(a, 0x3412);
+		assert_eq!(d, f32::from_bits(0xDB0F_4940));
+
+		let n: u32 = buf.read_ne(4);
+		assert_eq!(n, u32::from_ne_bytes([0x9A, 0xBC, 0xDE, 0xF0]));
+	}
+
+	#[test]
+	fn read_inferred_out_of_bounds() {
+		let buf: &[u8] = &[1, 2, 3];
+		assert_eq!(buf.try_read_be::<u16>(1), Some(0x0203));
+		assert_eq!(buf.try_read_le::<u32>(0), None);
+		assert_eq!(buf.try_read_ne::<u8>(3), None);
+		assert_eq!(buf.try_read_be::<f64>(usize::MAX), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 4 at offset 0 exceeds the array of length 3")]
+	fn read_inferred_panic() {
+		let buf = [1, 2, 3_u8];
+		let _: u32 = buf.read_be(0);
+	}
+
+	#[derive(Debug, PartialEq)]
+	struct PacketHeader {
+		kind: u8,
+		flags: u8,
+		len: u16,
+	}
+
+	impl FromSubArray<4> for PacketHeader {
+		fn from_sub_array(arr: &[u8; 4]) -> Self {
+			Self {
+				kind: arr[0],
+				flags: arr[1],
+				len: u16::from_be_bytes(*arr.sub_array_ref(2)),
+			}
+		}
+	}
+
+	#[test]
+	fn read_user_type() {
+		let buf = [0xAA, 7, 0b101, 0x01, 0x02, 0xBB];
+
+		let header: PacketHeader = buf.read(1);
+		assert_eq!(
+			header,
+			PacketHeader {
+				kind: 7,
+				flags: 0b101,
+				len: 0x0102,
+			}
+		);
+		assert_eq!(buf.try_read::<PacketHeader, 4>(3), None);
+		assert_eq!(buf[..].try_read(2), Some([0b101, 0x01, 0x02, 0xBB]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn read_user_type_out_of_bounds() {
+		let buf = [0_u8; 4];
+		let _: PacketHeader = buf.read(1);
+	}
+}
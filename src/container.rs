@@ -0,0 +1,436 @@
+//! Containers of contiguous elements, implementing `SubArray` uniformly
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::SubArray;
+
+
+mod sealed {
+	pub trait Sealed {}
+}
+
+/// Container that can be viewed as a slice of its elements
+///
+/// This is implemented for arrays, slices, and, with the `alloc` feature,
+/// for `Vec` and `Box<[T]>`. All of them implement [`SubArray`] uniformly on
+/// top of this view, which makes supporting another container a matter of
+/// implementing this trait for it.
+///
+/// This trait is sealed, i.e. it can not be implemented outside of this
+/// crate, but it can be used as a bound.
+///
+/// # Example
+/// ```
+/// use sub_array::AsSubSlice;
+///
+/// fn total<A: AsSubSlice<Item = u8> + ?Sized>(container: &A) -> u32 {
+///     container.as_sub_slice().iter().copied().map(u32::from).sum()
+/// }
+///
+/// assert_eq!(total(&[1, 2, 3]), 6);
+/// assert_eq!(total(&[1, 2, 3][1..]), 5);
+/// ```
+pub trait AsSubSlice: sealed::Sealed {
+	/// The value type of this container.
+	type Item;
+
+	/// Views this container as a slice of its elements.
+	fn as_sub_slice(&self) -> &[Self::Item];
+
+	/// Views this container as a mutable slice of its elements.
+	fn as_sub_slice_mut(&mut self) -> &mut [Self::Item];
+}
+
+/// Get the sub-array of length `N` starting at `offset` of `slice`, if it is
+/// in bounds
+///
+/// This is the one bounds check, which all containers share.
+pub(crate) fn try_sub_slice_ref<T, const N: usize>(slice: &[T], offset: usize) -> Option<&[T; N]> {
+	slice.get(offset..)?.first_chunk()
+}
+
+/// Get the mutable sub-array of length `N` starting at `offset` of `slice`,
+/// if it is in bounds
+pub(crate) fn try_sub_slice_mut<T, const N: usize>(
+	slice: &mut [T],
+	offset: usize,
+) -> Option<&mut [T; N]> {
+	slice.get_mut(offset..)?.first_chunk_mut()
+}
+
+/// Get the sub-array of length `N` starting at `offset` of `slice`
+///
+/// # Panics
+/// Panics via [`out_of_bounds`] if it is not in bounds.
+#[track_caller]
+pub(crate) fn sub_slice_ref<T, const N: usize>(slice: &[T], offset: usize) -> &[T; N] {
+	match try_sub_slice_ref(slice, offset) {
+		Some(sub) => sub,
+		None => out_of_bounds(offset, N, slice.len()),
+	}
+}
+
+/// Get the mutable sub-array of length `N` starting at `offset` of `slice`
+///
+/// # Panics
+/// Panics via [`out_of_bounds`] if it is not in bounds.
+#[track_caller]
+pub(crate) fn sub_slice_mut<T, const N: usize>(slice: &mut [T], offset: usize) -> &mut [T; N] {
+	let len = slice.len();
+	match try_sub_slice_mut(slice, offset) {
+		Some(sub) => sub,
+		None => out_of_bounds(offset, N, len),
+	}
+}
+
+/// Panics because the sub-array of length `n` at `offset` exceeds an array of
+/// length `len`
+///
+/// This is the one panic, which all containers share, thus its message is
+/// the same everywhere, see [`SubArray`] for its format.
+#[cold]
+#[track_caller]
+pub(crate) fn out_of_bounds(offset: usize, n: usize, len: usize) -> ! {
+	panic!(
+		"{}",
+		SubArrayError {
+			offset,
+			len: n,
+			array_len: len,
+		}
+	)
+}
+
+/// Error of a sub-array, which exceeds the array it is taken from
+///
+/// Its message is the one of the out of bounds panic, see [`SubArray`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubArrayError {
+	/// The offset at which the sub-array starts
+	pub offset: usize,
+	/// The length of the sub-array
+	pub len: usize,
+	/// The length of the array, which is less than `offset + len`
+	pub array_len: usize,
+}
+
+impl fmt::Display for SubArrayError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"sub-array of length {} at offset {} exceeds the array of length {}",
+			self.len, self.offset, self.array_len
+		)
+	}
+}
+
+impl core::error::Error for SubArrayError {}
+
+/// Implements `Sealed` and `AsSubSlice` for the given container types via
+/// the given conversions to slices, and `SubArray` on top of it
+macro_rules! impl_as_sub_slice {
+	($(
+		$(#[$attr:meta])*
+		impl [$($generics:tt)*] for $container:ty {
+			|$this:ident| $as_slice:expr,
+			|$this_mut:ident| $as_slice_mut:expr $(,)?
+		}
+	)*) => {
+		$(
+			$(#[$attr])*
+			impl<$($generics)*> sealed::Sealed for $container {}
+
+			$(#[$attr])*
+			impl<$($generics)*> AsSubSlice for $container {
+				type Item = T;
+
+				fn as_sub_slice(&self) -> &[Self::Item] {
+					let $this = self;
+					$as_slice
+				}
+
+				fn as_sub_slice_mut(&mut self) -> &mut [Self::Item] {
+					let $this_mut = self;
+					$as_slice_mut
+				}
+			}
+
+			$(#[$attr])*
+			impl<$($generics)*> SubArray for $container {
+				type Item = T;
+
+				fn len(&self) -> usize {
+					self.as_sub_slice().len()
+				}
+
+				#[track_caller]
+				fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+					sub_slice_ref(self.as_sub_slice(), offset)
+				}
+
+				#[track_caller]
+				fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
+					sub_slice_mut(self.as_sub_slice_mut(), offset)
+				}
+
+				fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+					try_sub_slice_ref(self.as_sub_slice(), offset)
+				}
+
+				fn try_sub_array_mut<const N: usize>(
+					&mut self,
+					offset: usize,
+				) -> Option<&mut [Self::Item; N]> {
+					try_sub_slice_mut(self.as_sub_slice_mut(), offset)
+				}
+			}
+		)*
+	};
+}
+
+impl_as_sub_slice! {
+	/// Implementation on regular arrays
+	impl [T, const M: usize] for [T; M] {
+		|this| this,
+		|this| this,
+	}
+
+	/// Implementation on slices
+	impl [T] for [T] {
+		|this| this,
+		|this| this,
+	}
+
+	/// Implementation on vecs
+	///
+	/// Requires the `alloc` feature.
+	#[cfg(feature = "alloc")]
+	impl [T] for Vec<T> {
+		|this| this.as_slice(),
+		|this| this.as_mut_slice(),
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> sealed::Sealed for Box<[T]> {}
+
+/// Implementation on boxed slices
+///
+/// Unlike the other containers, `SubArray` is implemented on top of the
+/// forwarding implementation on all boxes.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<T> AsSubSlice for Box<[T]> {
+	type Item = T;
+
+	fn as_sub_slice(&self) -> &[Self::Item] {
+		self
+	}
+
+	fn as_sub_slice_mut(&mut self) -> &mut [Self::Item] {
+		self
+	}
+}
+
+/// Implementation on boxes, e.g. of arrays or slices
+///
+/// This forwards to the boxed `A`, such that a box can be passed by value to
+/// generic code bound on `SubArray`.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+impl<A> SubArray for Box<A>
+where
+	A: SubArray + ?Sized,
+{
+	type Item = A::Item;
+
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+
+	#[track_caller]
+	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+		(**self).sub_array_ref(offset)
+	}
+
+	#[track_caller]
+	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
+		(**self).sub_array_mut(offset)
+	}
+
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		(**self).try_sub_array_ref(offset)
+	}
+
+	fn try_sub_array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut [Self::Item; N]> {
+		(**self).try_sub_array_mut(offset)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	extern crate std;
+
+	use std::string::String;
+
+	use super::*;
+
+
+	fn round_trip<A: SubArray<Item = u8> + AsSubSlice<Item = u8> + ?Sized>(container: &mut A) {
+		assert_eq!(container.as_sub_slice(), [1, 2, 3, 4]);
+		assert_eq!(container.len(), 4);
+		assert!(!container.is_empty());
+
+		assert_eq!(container.sub_array_ref::<2>(1), &[2, 3]);
+		*container.sub_array_mut::<2>(2) = [5, 6];
+		assert_eq!(container.try_sub_array_ref::<2>(3), None);
+		assert_eq!(container.try_sub_array_mut::<1>(3), Some(&mut [6]));
+		assert_eq!(container.try_sub_array_ref::<0>(4), Some(&[]));
+		assert_eq!(container.try_sub_array_mut::<0>(5), None);
+
+		container.as_sub_slice_mut()[0] = 7;
+		assert_eq!(container.as_sub_slice(), [7, 2, 5, 6]);
+	}
+
+	#[test]
+	fn arrays_and_slices() {
+		let mut arr = [1, 2, 3, 4_u8];
+		round_trip(&mut arr);
+
+		let mut arr = [0, 1, 2, 3, 4_u8];
+		round_trip(&mut arr[1..]);
+		assert_eq!(arr, [0, 7, 2, 5, 6]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn vecs_and_boxes() {
+		let mut vec = Vec::from([1, 2, 3, 4_u8]);
+		round_trip(&mut vec);
+		assert_eq!(vec, [7, 2, 5, 6]);
+
+		let mut boxed: Box<[u8]> = Box::new([1, 2, 3, 4]);
+		round_trip(&mut boxed);
+		assert_eq!(*boxed, [7, 2, 5, 6]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn vecs_directly() {
+		let mut vec = Vec::from([1, 2, 3, 4_u8]);
+
+		assert_eq!(vec.sub_array_ref::<2>(1), &[2, 3]);
+		*vec.sub_array_mut::<2>(2) = [5, 6];
+		assert_eq!(vec.try_sub_array_ref::<2>(3), None);
+		assert_eq!(vec.try_sub_array_mut::<1>(3), Some(&mut [6]));
+
+		assert_eq!(vec, [1, 2, 5, 6]);
+	}
+
+	#[cfg(feature = "alloc")]
+	fn bump_window<A: SubArray<Item = u8>>(mut buf: A) -> A {
+		for byte in buf.sub_array_mut::<4>(8) {
+			*byte += 1;
+		}
+		buf
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn boxes_by_value() {
+		let boxed: Box<[u8; 64]> = Box::new([0; 64]);
+		let boxed = bump_window(boxed);
+		assert_eq!(boxed.sub_array_ref::<6>(7), &[0, 1, 1, 1, 1, 0]);
+		assert_eq!(boxed.try_sub_array_ref::<1>(64), None);
+		assert_eq!(boxed.len(), 64);
+
+		let nested: Box<Box<[u8]>> = Box::new(Box::new([0; 12]));
+		let mut nested = bump_window(nested);
+		assert_eq!(nested.try_sub_array_mut::<4>(8), Some(&mut [1; 4]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_ref::<2>(2);
+	}
+
+	/// Get the message of the panic of `f`
+	fn panic_message(f: impl FnOnce()) -> String {
+		let payload = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)).unwrap_err();
+		*payload.downcast().unwrap()
+	}
+
+	#[test]
+	fn panic_parity() {
+		use crate::DynSubArray;
+		use crate::TypedBuf;
+
+		let expected = "sub-array of length 4 at offset 2 exceeds the array of length 5";
+		let mut arr = [0_u8; 5];
+
+		let messages = [
+			panic_message(|| {
+				arr.sub_array_ref::<4>(2);
+			}),
+			panic_message(|| {
+				arr.clone().sub_array_mut::<4>(2);
+			}),
+			panic_message(|| {
+				arr[..].sub_array_ref::<4>(2);
+			}),
+			panic_message(|| {
+				TypedBuf::<(), _>::new(arr).sub_array_ref::<4>(2);
+			}),
+			panic_message(|| {
+				let dyn_arr: &dyn DynSubArray<Item = u8> = &arr;
+				dyn_arr.sub_array_ref::<4>(2);
+			}),
+			panic_message(|| {
+				<&mut [u8; 5]>::sub_array_mut::<4>(&mut &mut arr, 2);
+			}),
+		];
+		for message in &messages {
+			assert_eq!(message, expected);
+		}
+
+		#[cfg(feature = "alloc")]
+		{
+			let owned = [
+				panic_message(|| {
+					arr.to_vec().sub_array_mut::<4>(2);
+				}),
+				panic_message(|| {
+					Box::new(arr).sub_array_ref::<4>(2);
+				}),
+				panic_message(|| {
+					let boxed: Box<[u8]> = Box::new(arr);
+					boxed.sub_array_ref::<4>(2);
+				}),
+			];
+			for message in &owned {
+				assert_eq!(message, expected);
+			}
+		}
+
+		let overflow = panic_message(|| {
+			arr.sub_array_ref::<1>(usize::MAX);
+		});
+		assert_eq!(
+			overflow,
+			std::format!(
+				"sub-array of length 1 at offset {} exceeds the array of length 5",
+				usize::MAX
+			)
+		);
+	}
+}
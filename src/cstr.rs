@@ -0,0 +1,48 @@
+//! Sub-arrays of the bytes of C strings
+
+use core::ffi::CStr;
+
+use crate::SubArray;
+
+
+/// Get a reference to the sub-array of length `N` starting at `offset` of
+/// the bytes of `s`, if it is in bounds.
+///
+/// The offsets are over [`CStr::to_bytes`], i.e. the bytes up to, but
+/// excluding, the terminating nul, thus the nul is never part of the
+/// sub-array. Returns `None` if `offset + N` exceeds their length.
+///
+/// # Example
+/// ```
+/// use sub_array::sub_array_ref_cstr;
+///
+/// let s = c"ID:1234";
+///
+/// assert_eq!(sub_array_ref_cstr(s, 3), Some(b"1234"));
+/// assert_eq!(sub_array_ref_cstr::<5>(s, 3), None);
+/// ```
+pub fn sub_array_ref_cstr<const N: usize>(s: &CStr, offset: usize) -> Option<&[u8; N]> {
+	s.to_bytes().try_sub_array_ref(offset)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn excludes_nul() {
+		// A C string in a fixed-size buffer, followed by garbage
+		let buf: &[u8; 10] = b"GET /a\0xyz";
+		let s = CStr::from_bytes_until_nul(buf).unwrap();
+
+		assert_eq!(sub_array_ref_cstr(s, 0), Some(b"GET"));
+		assert_eq!(sub_array_ref_cstr(s, 4), Some(b"/a"));
+		assert_eq!(sub_array_ref_cstr::<0>(s, 6), Some(&[]));
+		assert_eq!(sub_array_ref_cstr::<3>(s, 4), None);
+		assert_eq!(sub_array_ref_cstr::<1>(s, usize::MAX), None);
+		assert_eq!(sub_array_ref_cstr::<1>(c"", 0), None);
+	}
+}
@@ -0,0 +1,46 @@
+//! Decoding user types from fixed-size byte arrays
+
+
+/// Type that can be decoded from an array of `N` bytes
+///
+/// This is the extension point for fixed-size deserialization: implement it
+/// for a type, and [`ByteSubArray::read`](crate::ByteSubArray::read) decodes
+/// it from any byte array. `N` is usually inferred from the type of the
+/// result, since a type has usually a single implementation.
+///
+/// # Example
+/// ```
+/// use sub_array::ByteSubArray;
+/// use sub_array::FromSubArray;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Version {
+///     major: u8,
+///     minor: u8,
+/// }
+///
+/// impl FromSubArray<2> for Version {
+///     fn from_sub_array(arr: &[u8; 2]) -> Self {
+///         Self {
+///             major: arr[0],
+///             minor: arr[1],
+///         }
+///     }
+/// }
+///
+/// let buf: [u8; 3] = [0xFF, 1, 2];
+///
+/// let version: Version = buf.read(1);
+/// assert_eq!(version, Version { major: 1, minor: 2 });
+/// ```
+pub trait FromSubArray<const N: usize>: Sized {
+	/// Decodes a value from the bytes of `arr`.
+	fn from_sub_array(arr: &[u8; N]) -> Self;
+}
+
+/// Implementation on the raw bytes
+impl<const N: usize> FromSubArray<N> for [u8; N] {
+	fn from_sub_array(arr: &[u8; N]) -> Self {
+		*arr
+	}
+}
@@ -0,0 +1,239 @@
+//! Dyn-compatible access to sub-arrays
+
+use crate::container;
+use crate::container::out_of_bounds;
+use crate::SubArray;
+
+
+/// Dyn-compatible companion of [`SubArray`]
+///
+/// [`SubArray`] has generic methods, thus it can not be used as a trait
+/// object. This trait offers the same kind of access but with a runtime
+/// length, such that heterogeneous containers can be stored in e.g. a
+/// `Vec<Box<dyn DynSubArray<Item = u8>>>`.
+///
+/// It is implemented for every [`SubArray`] that can be viewed as a slice.
+/// Further, `dyn DynSubArray` itself implements [`SubArray`], thus fixed-size
+/// sub-arrays can be extracted from a trait object directly.
+///
+/// # Example
+/// ```
+/// use sub_array::DynSubArray;
+/// use sub_array::SubArray;
+///
+/// let buffers: [Box<dyn DynSubArray<Item = u8>>; 2] = [
+///     Box::new([1, 2, 3, 4]),
+///     Box::new([5, 6, 7]),
+/// ];
+///
+/// for buf in &buffers {
+///     assert!(buf.len() >= 3);
+///     let head: &[u8; 2] = buf.sub_array_ref(1);
+///     assert_eq!(head[1], head[0] + 1);
+/// }
+/// ```
+pub trait DynSubArray {
+	/// The value type of this array.
+	type Item;
+
+	/// View this entire array as a slice.
+	fn as_dyn_slice(&self) -> &[Self::Item];
+
+	/// View this entire array as a mutable slice.
+	fn as_dyn_slice_mut(&mut self) -> &mut [Self::Item];
+
+	/// Get a reference to the sub-slice of length `len` starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + len` exceeds the length of this array.
+	#[track_caller]
+	fn sub_slice(&self, offset: usize, len: usize) -> &[Self::Item] {
+		match self.try_sub_slice(offset, len) {
+			Some(sub) => sub,
+			None => out_of_bounds(offset, len, self.as_dyn_slice().len()),
+		}
+	}
+
+	/// Get a mutable reference to the sub-slice of length `len` starting at
+	/// `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + len` exceeds the length of this array.
+	#[track_caller]
+	fn sub_slice_mut(&mut self, offset: usize, len: usize) -> &mut [Self::Item] {
+		let array_len = self.as_dyn_slice().len();
+		match self.try_sub_slice_mut(offset, len) {
+			Some(sub) => sub,
+			None => out_of_bounds(offset, len, array_len),
+		}
+	}
+
+	/// Get a reference to the sub-slice of length `len` starting at `offset`,
+	/// if it is in bounds.
+	fn try_sub_slice(&self, offset: usize, len: usize) -> Option<&[Self::Item]> {
+		self.as_dyn_slice().get(offset..offset.checked_add(len)?)
+	}
+
+	/// Get a mutable reference to the sub-slice of length `len` starting at
+	/// `offset`, if it is in bounds.
+	fn try_sub_slice_mut(&mut self, offset: usize, len: usize) -> Option<&mut [Self::Item]> {
+		self.as_dyn_slice_mut()
+			.get_mut(offset..offset.checked_add(len)?)
+	}
+}
+
+impl<A> DynSubArray for A
+where
+	A: SubArray + AsRef<[A::Item]> + AsMut<[A::Item]> + ?Sized,
+{
+	type Item = A::Item;
+
+	fn as_dyn_slice(&self) -> &[Self::Item] {
+		self.as_ref()
+	}
+
+	fn as_dyn_slice_mut(&mut self) -> &mut [Self::Item] {
+		self.as_mut()
+	}
+}
+
+/// Implementation on trait objects
+impl<T> SubArray for dyn DynSubArray<Item = T> + '_ {
+	type Item = T;
+
+	fn len(&self) -> usize {
+		self.as_dyn_slice().len()
+	}
+
+	#[track_caller]
+	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+		container::sub_slice_ref(self.as_dyn_slice(), offset)
+	}
+
+	#[track_caller]
+	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
+		container::sub_slice_mut(self.as_dyn_slice_mut(), offset)
+	}
+
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		container::try_sub_slice_ref(self.as_dyn_slice(), offset)
+	}
+
+	fn try_sub_array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut [Self::Item; N]> {
+		container::try_sub_slice_mut(self.as_dyn_slice_mut(), offset)
+	}
+}
+
+
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	extern crate alloc;
+
+	use alloc::boxed::Box;
+	use alloc::vec;
+	use alloc::vec::Vec;
+
+	use super::*;
+
+
+	/// A container that is neither an array, nor a slice, nor a vec
+	struct Registers {
+		regs: [u8; 8],
+	}
+
+	impl AsRef<[u8]> for Registers {
+		fn as_ref(&self) -> &[u8] {
+			&self.regs
+		}
+	}
+
+	impl AsMut<[u8]> for Registers {
+		fn as_mut(&mut self) -> &mut [u8] {
+			&mut self.regs
+		}
+	}
+
+	impl SubArray for Registers {
+		type Item = u8;
+
+		fn len(&self) -> usize {
+			SubArray::len(&self.regs)
+		}
+
+		fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[u8; N] {
+			self.regs.sub_array_ref(offset)
+		}
+
+		fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [u8; N] {
+			self.regs.sub_array_mut(offset)
+		}
+
+		fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[u8; N]> {
+			self.regs.try_sub_array_ref(offset)
+		}
+
+		fn try_sub_array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut [u8; N]> {
+			self.regs.try_sub_array_mut(offset)
+		}
+	}
+
+	#[test]
+	fn heterogeneous() {
+		let mut buffers: Vec<Box<dyn DynSubArray<Item = u8>>> = vec![
+			Box::new([0_u8; 16]),
+			Box::new(vec![0_u8; 4]),
+			Box::new(Registers {
+				regs: [0; 8],
+			}),
+		];
+
+		for (i, buf) in buffers.iter_mut().enumerate() {
+			*buf.sub_array_mut::<2>(2) = [i as u8, 42];
+		}
+
+		let lens: Vec<_> = buffers.iter().map(|buf| buf.len()).collect();
+		assert_eq!(lens, [16, 4, 8]);
+		for (i, buf) in buffers.iter().enumerate() {
+			assert_eq!(buf.sub_array_ref::<3>(1), &[0, i as u8, 42]);
+			assert_eq!(buf.sub_slice(2, 2), &[i as u8, 42]);
+			assert_eq!(buf.try_sub_array_ref::<1>(buf.len()), None);
+			assert!(!buf.is_empty());
+		}
+	}
+
+	#[test]
+	fn try_sub_slice() {
+		let mut arr = [1, 2, 3_u8];
+		let dyn_arr: &mut dyn DynSubArray<Item = u8> = &mut arr;
+
+		assert_eq!(dyn_arr.try_sub_slice(1, 2), Some(&[2, 3][..]));
+		assert_eq!(dyn_arr.try_sub_slice(2, 2), None);
+		assert_eq!(dyn_arr.try_sub_slice(1, usize::MAX), None);
+		assert_eq!(dyn_arr.try_sub_slice_mut(3, 0), Some(&mut [][..]));
+		assert_eq!(dyn_arr.try_sub_array_mut::<4>(0), None);
+	}
+
+	#[test]
+	fn len_with_both_traits() {
+		let buf = crate::TypedBuf::<(), [u8; 4]>::new([1, 2, 3, 4]);
+		assert_eq!(buf.len(), 4);
+		assert_eq!(DynSubArray::as_dyn_slice(&buf), &[1, 2, 3, 4]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 2 exceeds the array of length 3")]
+	fn sub_slice_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		let dyn_arr: &dyn DynSubArray<Item = u8> = &arr;
+		dyn_arr.sub_slice(2, 2);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		let dyn_arr: &dyn DynSubArray<Item = u8> = &arr;
+		dyn_arr.sub_array_ref::<2>(2);
+	}
+}
@@ -0,0 +1,201 @@
+//! Placing small arrays into larger ones
+
+use crate::SubArray;
+
+
+/// Array that can be embedded into a larger array
+///
+/// This is the dual of [`SubArray`]: instead of extracting a small array out
+/// of a larger one, it places a small array into a new larger one.
+/// Also see [`embed_const`] for use in const context.
+pub trait Embed<T, const N: usize>: Sized {
+	/// Creates a new array of length `M` containing this array at `offset`,
+	/// and default values everywhere else.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds `M`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::Embed;
+	///
+	/// let small = [String::from("foo"), String::from("bar")];
+	///
+	/// let large: [String; 4] = small.embed(1);
+	/// assert_eq!(large, ["", "foo", "bar", ""]);
+	/// ```
+	fn embed<const M: usize>(self, offset: usize) -> [T; M]
+	where
+		T: Default;
+
+	/// Creates a new array of length `M` containing this array at `offset`,
+	/// and default values everywhere else, if it fits.
+	///
+	/// Returns `None`, dropping this array, if `offset + N` exceeds `M`.
+	fn try_embed<const M: usize>(self, offset: usize) -> Option<[T; M]>
+	where
+		T: Default;
+
+	/// Places this array into `base` at `offset`, returning the result.
+	///
+	/// The elements of `base` that are overwritten are dropped.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds `M`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::Embed;
+	///
+	/// let frame: [u8; 6] = [0xAA, 0xBB].embed_into([0xFF; 6], 3);
+	/// assert_eq!(frame, [0xFF, 0xFF, 0xFF, 0xAA, 0xBB, 0xFF]);
+	/// ```
+	fn embed_into<const M: usize>(self, base: [T; M], offset: usize) -> [T; M];
+
+	/// Places this array into `base` at `offset`, returning the result, if it
+	/// fits.
+	///
+	/// Returns `None`, dropping both arrays, if `offset + N` exceeds `M`.
+	fn try_embed_into<const M: usize>(self, base: [T; M], offset: usize) -> Option<[T; M]>;
+}
+
+/// Implementation on regular arrays
+impl<T, const N: usize> Embed<T, N> for [T; N] {
+	fn embed<const M: usize>(self, offset: usize) -> [T; M]
+	where
+		T: Default,
+	{
+		self.embed_into(core::array::from_fn(|_| T::default()), offset)
+	}
+
+	fn try_embed<const M: usize>(self, offset: usize) -> Option<[T; M]>
+	where
+		T: Default,
+	{
+		// Check first, to avoid creating the default values in vain
+		if offset.checked_add(N)? > M {
+			return None;
+		}
+		self.try_embed_into(core::array::from_fn(|_| T::default()), offset)
+	}
+
+	fn embed_into<const M: usize>(self, mut base: [T; M], offset: usize) -> [T; M] {
+		*base.sub_array_mut(offset) = self;
+		base
+	}
+
+	fn try_embed_into<const M: usize>(self, mut base: [T; M], offset: usize) -> Option<[T; M]> {
+		*base.try_sub_array_mut(offset)? = self;
+		Some(base)
+	}
+}
+
+
+/// Places `small` into a copy of `base` at `offset`, in const context.
+///
+/// This is the const version of [`Embed::embed_into`], which is limited to
+/// `Copy` elements, because trait methods can not be called in const context.
+///
+/// # Panics
+/// Panics if `offset + N` exceeds `M`, which in const context turns into a
+/// compile-time error.
+///
+/// # Example
+/// ```
+/// use sub_array::embed_const;
+///
+/// const HEADER: [u8; 2] = [0xCA, 0xFE];
+/// const FRAME: [u8; 8] = embed_const(&HEADER, &[0; 8], 0);
+/// const PADDED: [u8; 8] = embed_const(&HEADER, &FRAME, 6);
+///
+/// assert_eq!(FRAME, [0xCA, 0xFE, 0, 0, 0, 0, 0, 0]);
+/// assert_eq!(PADDED, [0xCA, 0xFE, 0, 0, 0, 0, 0xCA, 0xFE]);
+/// ```
+///
+/// Exceeding the bounds fails to compile:
+/// ```compile_fail
+/// use sub_array::embed_const;
+///
+/// const FRAME: [u8; 8] = embed_const(&[0xCA, 0xFE], &[0; 8], 7);
+/// ```
+pub const fn embed_const<T, const N: usize, const M: usize>(
+	small: &[T; N],
+	base: &[T; M],
+	offset: usize,
+) -> [T; M]
+where
+	T: Copy,
+{
+	if offset > M || N > M - offset {
+		panic!("embedded array out of bounds");
+	}
+	let mut result = *base;
+	let mut i = 0;
+	while i < N {
+		result[offset + i] = small[i];
+		i += 1;
+	}
+	result
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::string::String;
+
+	use super::*;
+
+
+	const SMALL: [u16; 3] = [1, 2, 3];
+	const AT_START: [u16; 5] = embed_const(&SMALL, &[9; 5], 0);
+	const AT_END: [u16; 5] = embed_const(&SMALL, &AT_START, 2);
+
+	#[test]
+	fn embed_in_const() {
+		assert_eq!(AT_START, [1, 2, 3, 9, 9]);
+		assert_eq!(AT_END, [1, 2, 1, 2, 3]);
+		assert_eq!(embed_const(&[], &[1, 2], 2), [1, 2]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn embed_const_out_of_bounds() {
+		embed_const(&SMALL, &[0; 5], 3);
+	}
+
+	#[test]
+	fn embed_non_copy() {
+		let small = [String::from("a"), String::from("b")];
+		let large: [String; 3] = small.embed(1);
+		assert_eq!(large, ["", "a", "b"]);
+
+		let base = [String::from("x"), String::from("y"), String::from("z")];
+		let large = [String::from("c")].embed_into(base, 2);
+		assert_eq!(large, ["x", "y", "c"]);
+	}
+
+	#[test]
+	fn embed_at_end() {
+		assert_eq!(SMALL.embed::<3>(0), SMALL);
+		assert_eq!(SMALL.embed::<4>(1), [0, 1, 2, 3]);
+		assert_eq!([7_u8; 0].embed::<2>(2), [0, 0]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn embed_out_of_bounds() {
+		SMALL.embed::<4>(2);
+	}
+
+	#[test]
+	fn try_embed() {
+		assert_eq!(SMALL.try_embed::<4>(1), Some([0, 1, 2, 3]));
+		assert_eq!(SMALL.try_embed::<4>(2), None);
+		assert_eq!(SMALL.try_embed::<4>(usize::MAX), None);
+		assert_eq!(SMALL.try_embed_into([5; 4], 1), Some([5, 1, 2, 3]));
+		assert_eq!(SMALL.try_embed_into([5; 2], 0), None);
+	}
+}
@@ -0,0 +1,227 @@
+//! Byte order conversion of integer sub-arrays
+
+/// Integers whose byte order can be reversed
+///
+/// Used by [`SubArray::sub_array_swap_bytes`](crate::SubArray::sub_array_swap_bytes),
+/// this is implemented for all primitive integer types.
+pub trait SwapBytes: Copy {
+	/// Reverses the byte order of this integer, see e.g. [`u32::swap_bytes`].
+	fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_swap_bytes {
+	($($int:ty),*) => {
+		$(
+			impl SwapBytes for $int {
+				fn swap_bytes(self) -> Self {
+					<$int>::swap_bytes(self)
+				}
+			}
+		)*
+	};
+}
+
+impl_swap_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Integers that can be converted from and to their raw bytes
+///
+/// Used by [`ByteSubArray::read_int`](crate::ByteSubArray::read_int) and
+/// [`ByteSubArray::write_int`](crate::ByteSubArray::write_int), this is
+/// implemented for all primitive integer types.
+pub trait EndianInt: SwapBytes {
+	/// The raw bytes of this integer, i.e. `[u8; size_of::<Self>()]`.
+	type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+	/// Converts the raw bytes in native byte order, see e.g.
+	/// [`u32::from_ne_bytes`].
+	fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+
+	/// Converts this integer to its raw bytes in native byte order, see e.g.
+	/// [`u32::to_ne_bytes`].
+	fn to_ne_bytes(self) -> Self::Bytes;
+}
+
+macro_rules! impl_endian_int {
+	($($int:ty),*) => {
+		$(
+			impl EndianInt for $int {
+				type Bytes = [u8; core::mem::size_of::<$int>()];
+
+				fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+					<$int>::from_ne_bytes(bytes)
+				}
+
+				fn to_ne_bytes(self) -> Self::Bytes {
+					<$int>::to_ne_bytes(self)
+				}
+			}
+		)*
+	};
+}
+
+impl_endian_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+
+/// Byte order of integers
+///
+/// This is a marker trait implemented by the zero-sized [`BigEndian`],
+/// [`LittleEndian`] and [`NativeEndian`], which allows to write code that is
+/// generic over the byte order and is instantiated per byte order, e.g.:
+///
+/// ```
+/// use sub_array::BigEndian;
+/// use sub_array::ByteSubArray;
+/// use sub_array::Endian;
+/// use sub_array::LittleEndian;
+///
+/// fn parse<E: Endian>(buf: &[u8; 6]) -> (u16, u32) {
+///     (buf.read_int::<u16, E>(0), buf.read_int::<u32, E>(2))
+/// }
+///
+/// assert_eq!(parse::<BigEndian>(&[0, 1, 0, 0, 0, 2]), (1, 2));
+/// assert_eq!(parse::<LittleEndian>(&[1, 0, 2, 0, 0, 0]), (1, 2));
+/// ```
+pub trait Endian {
+	/// Whether this is the byte order of the target platform.
+	const IS_NATIVE: bool;
+
+	/// Converts an integer between native and this byte order.
+	///
+	/// This is its own inverse, i.e. it swaps the bytes if this is not the
+	/// native byte order, and is a no-op otherwise.
+	fn convert<I: SwapBytes>(int: I) -> I {
+		if Self::IS_NATIVE {
+			int
+		} else {
+			int.swap_bytes()
+		}
+	}
+}
+
+/// Big-endian byte order, also known as network byte order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BigEndian;
+
+impl Endian for BigEndian {
+	const IS_NATIVE: bool = cfg!(target_endian = "big");
+}
+
+/// Little-endian byte order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LittleEndian;
+
+impl Endian for LittleEndian {
+	const IS_NATIVE: bool = cfg!(target_endian = "little");
+}
+
+/// The byte order of the target platform
+///
+/// This is the same as either [`BigEndian`] or [`LittleEndian`], depending on
+/// the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NativeEndian;
+
+impl Endian for NativeEndian {
+	const IS_NATIVE: bool = true;
+}
+
+
+
+/// Types that can be decoded from their raw bytes
+///
+/// Used by [`ByteSubArray::read_be`](crate::ByteSubArray::read_be) and its
+/// siblings, which infer the number of bytes to read from the decoded type,
+/// thus the type of the result is enough to call them. This is implemented
+/// for all primitive integer and floating-point types.
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` can not be decoded from a fixed number of bytes",
+	label = "not a primitive integer or floating-point type",
+	note = "only the primitive integer and floating-point types implement `FromSubArrayBytes`"
+)]
+pub trait FromSubArrayBytes: Sized {
+	/// The raw bytes of this type, i.e. `[u8; size_of::<Self>()]`.
+	type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+
+	/// Decodes the raw bytes in big-endian byte order, see e.g.
+	/// [`u32::from_be_bytes`].
+	fn from_be_bytes(bytes: Self::Bytes) -> Self;
+
+	/// Decodes the raw bytes in little-endian byte order, see e.g.
+	/// [`u32::from_le_bytes`].
+	fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+	/// Decodes the raw bytes in native byte order, see e.g.
+	/// [`u32::from_ne_bytes`].
+	fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! impl_from_sub_array_bytes {
+	($($ty:ty),*) => {
+		$(
+			impl FromSubArrayBytes for $ty {
+				type Bytes = [u8; core::mem::size_of::<$ty>()];
+
+				fn from_be_bytes(bytes: Self::Bytes) -> Self {
+					<$ty>::from_be_bytes(bytes)
+				}
+
+				fn from_le_bytes(bytes: Self::Bytes) -> Self {
+					<$ty>::from_le_bytes(bytes)
+				}
+
+				fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+					<$ty>::from_ne_bytes(bytes)
+				}
+			}
+		)*
+	};
+}
+
+impl_from_sub_array_bytes!(
+	u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+
+#[cfg(test)]
+mod tests {
+	use crate::SubArray;
+
+
+	#[test]
+	fn swap_twice() {
+		let original = [0x0102_u16, 0x0304, 0x0506, 0x0708];
+		let mut arr = original;
+
+		arr.sub_array_swap_bytes::<2>(1);
+		assert_eq!(arr, [0x0102, 0x0403, 0x0605, 0x0708]);
+		arr.sub_array_swap_bytes::<2>(1);
+		assert_eq!(arr, original);
+	}
+
+	#[test]
+	fn matches_to_be() {
+		let mut arr = [0x1234_5678_u32, 0x9ABC_DEF0];
+		arr.sub_array_swap_bytes::<2>(0);
+		assert_eq!(arr, [0x7856_3412, 0xF0DE_BC9A]);
+
+		#[cfg(target_endian = "little")]
+		assert_eq!(arr, [0x1234_5678_u32.to_be(), 0x9ABC_DEF0_u32.to_be()]);
+	}
+
+	#[test]
+	fn on_slices() {
+		let mut arr = [1_u64, 2, 3];
+		let slice: &mut [u64] = &mut arr;
+
+		slice.sub_array_swap_bytes::<1>(2);
+		slice.sub_array_swap_bytes::<0>(3);
+		assert_eq!(arr, [1, 2, 3 << 56]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let mut arr = [0_u32; 3];
+		arr.sub_array_swap_bytes::<2>(2);
+	}
+}
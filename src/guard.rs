@@ -0,0 +1,180 @@
+//! Sub-arrays that notify their container when they are done
+
+use core::fmt;
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+use crate::SubArray;
+
+
+/// A mutable sub-array that runs a callback on its container once dropped
+///
+/// This dereferences to the plain `[T; N]` sub-array, and once it is dropped
+/// (or explicitly [finished](Self::finish)), it calls the callback with the
+/// entire container. This allows to e.g. refresh a checksum over the
+/// container whenever a part of it was modified.
+///
+/// The guard holds the exclusive borrow of the entire container, which is
+/// only released to the callback, thus nothing can observe the container in
+/// between the modification and the callback.
+///
+/// Returned by [`SubArray::sub_array_mut_guarded`](crate::SubArray::sub_array_mut_guarded).
+pub struct SubArrayGuard<'a, A, F, const N: usize>
+where
+	A: SubArray + ?Sized,
+	F: FnOnce(&mut A),
+{
+	container: &'a mut A,
+	offset: usize,
+	on_drop: Option<F>,
+}
+
+impl<'a, A, F, const N: usize> SubArrayGuard<'a, A, F, N>
+where
+	A: SubArray + ?Sized,
+	F: FnOnce(&mut A),
+{
+	pub(crate) fn new(container: &'a mut A, offset: usize, on_drop: F) -> Self {
+		// Check the bounds once upfront, before handing out the guard
+		container.sub_array_mut::<N>(offset);
+		Self {
+			container,
+			offset,
+			on_drop: Some(on_drop),
+		}
+	}
+
+	/// Runs the callback right now, consuming this guard.
+	///
+	/// This is the same as dropping it, but more explicit.
+	pub fn finish(self) {
+		drop(self);
+	}
+}
+
+impl<A, F, const N: usize> Deref for SubArrayGuard<'_, A, F, N>
+where
+	A: SubArray + ?Sized,
+	F: FnOnce(&mut A),
+{
+	type Target = [A::Item; N];
+
+	fn deref(&self) -> &Self::Target {
+		self.container.sub_array_ref(self.offset)
+	}
+}
+
+impl<A, F, const N: usize> DerefMut for SubArrayGuard<'_, A, F, N>
+where
+	A: SubArray + ?Sized,
+	F: FnOnce(&mut A),
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.container.sub_array_mut(self.offset)
+	}
+}
+
+impl<A, F, const N: usize> Drop for SubArrayGuard<'_, A, F, N>
+where
+	A: SubArray + ?Sized,
+	F: FnOnce(&mut A),
+{
+	fn drop(&mut self) {
+		if let Some(on_drop) = self.on_drop.take() {
+			on_drop(self.container);
+		}
+	}
+}
+
+impl<A, F, const N: usize> fmt::Debug for SubArrayGuard<'_, A, F, N>
+where
+	A: SubArray + ?Sized,
+	A::Item: fmt::Debug,
+	F: FnOnce(&mut A),
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SubArrayGuard")
+			.field("sub_array", &**self)
+			.field("offset", &self.offset)
+			.finish_non_exhaustive()
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use core::cell::Cell;
+
+	use crate::SubArray;
+
+
+	/// Layout: 4 bytes payload, followed by a 1 byte checksum
+	fn update_checksum(calls: &Cell<usize>) -> impl FnOnce(&mut [u8; 5]) + '_ {
+		move |packet| {
+			calls.set(calls.get() + 1);
+			packet[4] = packet[..4].iter().fold(0, |sum, b| sum ^ b);
+		}
+	}
+
+	#[test]
+	fn on_drop() {
+		let calls = Cell::new(0);
+		let mut packet = [0_u8; 5];
+
+		{
+			let mut payload = packet.sub_array_mut_guarded::<2, _>(1, update_checksum(&calls));
+			payload[0] = 0x0F;
+			payload[1] = 0xF1;
+			assert_eq!(*payload, [0x0F, 0xF1]);
+			assert_eq!(calls.get(), 0);
+		}
+
+		assert_eq!(calls.get(), 1);
+		assert_eq!(packet, [0, 0x0F, 0xF1, 0, 0xFE]);
+	}
+
+	#[test]
+	fn finish() {
+		let calls = Cell::new(0);
+		let mut packet = [1, 2, 3, 4, 0_u8];
+
+		let mut payload = packet.sub_array_mut_guarded::<4, _>(0, update_checksum(&calls));
+		*payload = [1, 1, 1, 0];
+		payload.finish();
+
+		assert_eq!(calls.get(), 1);
+		assert_eq!(packet, [1, 1, 1, 0, 1]);
+	}
+
+	fn fallible_update(packet: &mut [u8; 5], calls: &Cell<usize>, fail: bool) -> Option<()> {
+		let mut payload = packet.sub_array_mut_guarded::<1, _>(3, update_checksum(calls));
+		payload[0] = 0x80;
+		if fail {
+			return None;
+		}
+		payload[0] = 0x81;
+		Some(())
+	}
+
+	#[test]
+	fn early_return() {
+		let calls = Cell::new(0);
+		let mut packet = [0_u8; 5];
+
+		assert_eq!(fallible_update(&mut packet, &calls, true), None);
+		assert_eq!(calls.get(), 1);
+		assert_eq!(packet, [0, 0, 0, 0x80, 0x80]);
+
+		assert_eq!(fallible_update(&mut packet, &calls, false), Some(()));
+		assert_eq!(calls.get(), 2);
+		assert_eq!(packet, [0, 0, 0, 0x81, 0x81]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let mut packet = [0_u8; 5];
+		packet.sub_array_mut_guarded::<2, _>(4, |_| unreachable!());
+	}
+}
@@ -0,0 +1,94 @@
+//! Sub-arrays that expose their length as an associated const
+
+use core::ops::Deref;
+
+
+/// A shared sub-array that exposes its length as [`LEN`](Self::LEN)
+///
+/// This dereferences to the plain `[T; N]` sub-array, thus it can be used just
+/// like the `&[T; N]` returned by [`sub_array_ref`](crate::SubArray::sub_array_ref),
+/// but generic code, e.g. generated by macros, can get the length from the
+/// handle type alone, without naming `N`.
+///
+/// Returned by [`SubArrayRef::sub_array_handle`](crate::SubArrayRef::sub_array_handle).
+#[derive(Debug)]
+pub struct SubArrayHandle<'a, T, const N: usize> {
+	array: &'a [T; N],
+}
+
+impl<'a, T, const N: usize> SubArrayHandle<'a, T, N> {
+	/// The length of this sub-array.
+	pub const LEN: usize = N;
+
+	pub(crate) fn new(array: &'a [T; N]) -> Self {
+		Self {
+			array,
+		}
+	}
+
+	/// Unwraps the plain sub-array reference.
+	pub fn into_inner(self) -> &'a [T; N] {
+		self.array
+	}
+}
+
+impl<T, const N: usize> Clone for SubArrayHandle<'_, T, N> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, const N: usize> Copy for SubArrayHandle<'_, T, N> {}
+
+impl<T, const N: usize> Deref for SubArrayHandle<'_, T, N> {
+	type Target = [T; N];
+
+	fn deref(&self) -> &Self::Target {
+		self.array
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::SubArray;
+	use crate::SubArrayRef;
+
+
+	/// Generic over the handle type only, not its length
+	fn total_len<H>(_: &H) -> usize
+	where
+		H: HasLen,
+	{
+		H::LEN
+	}
+
+	trait HasLen {
+		const LEN: usize;
+	}
+
+	impl<T, const N: usize> HasLen for SubArrayHandle<'_, T, N> {
+		const LEN: usize = Self::LEN;
+	}
+
+	#[test]
+	fn len_and_deref() {
+		let arr = [1, 2, 3, 4, 5_u8];
+
+		let handle = arr.sub_array_handle::<3>(1);
+		assert_eq!(SubArrayHandle::<u8, 3>::LEN, 3);
+		assert_eq!(total_len(&handle), 3);
+		assert_eq!(*handle, [2, 3, 4]);
+		assert_eq!(handle.len(), 3);
+		assert_eq!(handle.into_inner(), &[2, 3, 4]);
+	}
+
+	#[test]
+	fn try_handle() {
+		let slice: &[u8] = &[1, 2, 3];
+		assert_eq!(slice.try_sub_array_handle::<2>(1).as_deref(), Some(&[2, 3]));
+		assert!(slice.try_sub_array_handle::<2>(2).is_none());
+	}
+}
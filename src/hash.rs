@@ -0,0 +1,133 @@
+//! Rolling hashes over sliding windows
+
+/// Hasher that can be updated incrementally while sliding over an array
+///
+/// Used by [`SubArrayRef::sub_array_rolling_hash`](crate::SubArrayRef::sub_array_rolling_hash)
+/// to compute the hash of each window from that of the preceding one, e.g. for
+/// the Rabin-Karp string search. See [`PolynomialHash`] for an implementation.
+pub trait RollingHasher<T> {
+	/// Adds `incoming` at the end of the window, growing it by one element.
+	fn push(&mut self, incoming: &T);
+
+	/// Removes `outgoing` from the start of the window and adds `incoming` at
+	/// its end, keeping the length of the window unchanged.
+	fn roll(&mut self, outgoing: &T, incoming: &T);
+
+	/// Returns the hash of the current window.
+	fn finish(&self) -> u64;
+}
+
+
+/// Polynomial rolling hash
+///
+/// The hash of a window `[c_0, ..., c_(n-1)]` is
+/// `c_0 * base^(n-1) + ... + c_(n-1) * base^0` modulo `modulus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PolynomialHash {
+	base: u64,
+	modulus: u64,
+	hash: u64,
+	/// `base^(n-1) mod modulus`, the factor of the first element of the window
+	lead: u64,
+	len: usize,
+}
+
+impl PolynomialHash {
+	/// Creates a hasher of an empty window.
+	///
+	/// # Panics
+	/// Panics if `modulus` is zero.
+	pub const fn new(base: u64, modulus: u64) -> Self {
+		assert!(modulus != 0, "modulus must not be zero");
+		Self {
+			base: base % modulus,
+			modulus,
+			hash: 0,
+			lead: 0,
+			len: 0,
+		}
+	}
+
+	/// Computes `a * b mod modulus` without overflowing
+	fn mul(&self, a: u64, b: u64) -> u64 {
+		(u128::from(a) * u128::from(b) % u128::from(self.modulus)) as u64
+	}
+
+	/// Computes `a + b mod modulus` without overflowing
+	fn add(&self, a: u64, b: u64) -> u64 {
+		((u128::from(a) + u128::from(b)) % u128::from(self.modulus)) as u64
+	}
+}
+
+impl<T> RollingHasher<T> for PolynomialHash
+where
+	T: Copy + Into<u64>,
+{
+	fn push(&mut self, incoming: &T) {
+		self.lead = if self.len == 0 {
+			1 % self.modulus
+		} else {
+			self.mul(self.lead, self.base)
+		};
+		self.len += 1;
+		self.hash = self.add(self.mul(self.hash, self.base), (*incoming).into());
+	}
+
+	fn roll(&mut self, outgoing: &T, incoming: &T) {
+		let outgoing = self.mul((*outgoing).into() % self.modulus, self.lead);
+		let hash = self.add(self.hash, self.modulus - outgoing);
+		self.hash = self.add(self.mul(hash, self.base), (*incoming).into());
+	}
+
+	fn finish(&self) -> u64 {
+		self.hash
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	fn hash_of(base: u64, modulus: u64, window: &[u8]) -> u64 {
+		let mut hasher = PolynomialHash::new(base, modulus);
+		for elem in window {
+			hasher.push(elem);
+		}
+		RollingHasher::<u8>::finish(&hasher)
+	}
+
+	#[test]
+	fn polynomial() {
+		assert_eq!(hash_of(10, 1_000_000, &[]), 0);
+		assert_eq!(hash_of(10, 1_000_000, &[1, 2, 3]), 123);
+		assert_eq!(hash_of(10, 100, &[1, 2, 3]), 23);
+		assert_eq!(hash_of(256, 1, b"abc"), 0);
+	}
+
+	#[test]
+	fn polynomial_roll() {
+		let data = b"the quick brown fox";
+		let (base, modulus) = (257, u64::MAX - 58);
+
+		let mut hasher = PolynomialHash::new(base, modulus);
+		for elem in &data[..5] {
+			hasher.push(elem);
+		}
+		for offset in 1..=(data.len() - 5) {
+			hasher.roll(&data[offset - 1], &data[offset + 4]);
+			assert_eq!(
+				RollingHasher::<u8>::finish(&hasher),
+				hash_of(base, modulus, &data[offset..(offset + 5)])
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "modulus must not be zero")]
+	fn zero_modulus() {
+		PolynomialHash::new(2, 0);
+	}
+}
@@ -0,0 +1,223 @@
+//! Decoding of fixed-size hex fields
+
+use core::fmt;
+
+
+/// Error decoding a hex encoded sub-array
+///
+/// Returned by [`HexSubArray::sub_array_from_hex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+	/// The input is too short to contain the requested number of hex digits
+	OutOfBounds,
+	/// The input contains a character that is not a hex digit
+	InvalidDigit {
+		/// The offset of the offending character in the input
+		offset: usize,
+	},
+}
+
+impl fmt::Display for HexError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::OutOfBounds => write!(f, "hex sub-array out of bounds"),
+			Self::InvalidDigit {
+				offset,
+			} => write!(f, "invalid hex digit at offset {offset}"),
+		}
+	}
+}
+
+impl core::error::Error for HexError {}
+
+
+/// Text that contains hex encoded fixed-size byte arrays
+///
+/// This trait is implemented for everything that can be viewed as bytes,
+/// including `str`, byte arrays and slices, thus it is sufficient to import it
+/// to use its methods, there is no need to implement it manually.
+///
+/// Unlike the [`SubArray`](crate::SubArray) methods, the `offset`s and lengths
+/// here refer to the encoded text, where each decoded byte is made of two hex
+/// digits.
+pub trait HexSubArray: AsRef<[u8]> {
+	/// Decodes the `2 * N` hex digits starting at `offset` into `N` bytes.
+	///
+	/// Both upper and lower case hex digits are accepted. For the inverse, see
+	/// [`ByteSubArray::sub_array_to_hex`](crate::ByteSubArray::sub_array_to_hex).
+	///
+	/// # Errors
+	/// Returns [`HexError::OutOfBounds`] if `offset + 2 * N` exceeds the length
+	/// of this text, and [`HexError::InvalidDigit`] if any of the characters
+	/// is not a hex digit.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::HexError;
+	/// use sub_array::HexSubArray;
+	///
+	/// let text = "id=41424344;";
+	///
+	/// assert_eq!(text.sub_array_from_hex::<4>(3), Ok(*b"ABCD"));
+	/// assert_eq!(text.sub_array_from_hex::<2>(0), Err(HexError::InvalidDigit { offset: 0 }));
+	/// assert_eq!(text.sub_array_from_hex::<5>(3), Err(HexError::OutOfBounds));
+	/// ```
+	fn sub_array_from_hex<const N: usize>(&self, offset: usize) -> Result<[u8; N], HexError> {
+		let hex = N
+			.checked_mul(2)
+			.and_then(|len| self.as_ref().get(offset..)?.get(..len))
+			.ok_or(HexError::OutOfBounds)?;
+
+		let mut bytes = [0_u8; N];
+		for (i, (byte, digits)) in bytes.iter_mut().zip(hex.chunks_exact(2)).enumerate() {
+			let digit = |j: usize| {
+				hex_digit(digits[j]).ok_or(HexError::InvalidDigit {
+					offset: offset + 2 * i + j,
+				})
+			};
+			*byte = (digit(0)? << 4) | digit(1)?;
+		}
+		Ok(bytes)
+	}
+
+	/// Decodes the `2 * N` hex digits starting at `offset` into `N` bytes, if
+	/// they are in bounds and valid.
+	///
+	/// This is the same as [`sub_array_from_hex`](Self::sub_array_from_hex),
+	/// but discards the reason of a failure.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::HexSubArray;
+	///
+	/// let text = b"hash: deadbeef";
+	///
+	/// assert_eq!(text.decode_hex_sub_array::<4>(6), Some([0xDE, 0xAD, 0xBE, 0xEF]));
+	/// assert_eq!(text.decode_hex_sub_array::<4>(5), None);
+	/// ```
+	fn decode_hex_sub_array<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+		self.sub_array_from_hex(offset).ok()
+	}
+}
+
+impl<A> HexSubArray for A where A: AsRef<[u8]> + ?Sized {}
+
+/// Encodes each of the `bytes` as two lowercase hex digits into `hex`
+///
+/// The length of `hex` must be twice the length of `bytes`.
+pub(crate) fn encode_hex(bytes: &[u8], hex: &mut [u8]) {
+	const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+	debug_assert_eq!(bytes.len() * 2, hex.len());
+	for (byte, digits) in bytes.iter().zip(hex.chunks_exact_mut(2)) {
+		digits[0] = DIGITS[usize::from(byte >> 4)];
+		digits[1] = DIGITS[usize::from(byte & 0x0F)];
+	}
+}
+
+/// Decodes a single hex digit
+fn hex_digit(c: u8) -> Option<u8> {
+	match c {
+		b'0'..=b'9' => Some(c - b'0'),
+		b'a'..=b'f' => Some(c - b'a' + 10),
+		b'A'..=b'F' => Some(c - b'A' + 10),
+		_ => None,
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::ByteSubArray;
+
+
+	#[test]
+	fn decode() {
+		assert_eq!(
+			"41424344".sub_array_from_hex::<4>(0),
+			Ok([0x41, 0x42, 0x43, 0x44])
+		);
+		assert_eq!(
+			b"xxdeADbeEF".sub_array_from_hex::<4>(2),
+			Ok([0xDE, 0xAD, 0xBE, 0xEF])
+		);
+		assert_eq!(
+			"0123456789abcdef".sub_array_from_hex::<8>(0),
+			Ok([0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF])
+		);
+		assert_eq!("".sub_array_from_hex::<0>(0), Ok([]));
+	}
+
+	#[test]
+	fn decode_odd_offset() {
+		let slice: &[u8] = b"x4142";
+		assert_eq!(slice.sub_array_from_hex::<2>(1), Ok(*b"AB"));
+		assert_eq!(
+			slice.sub_array_from_hex::<2>(0),
+			Err(HexError::InvalidDigit {
+				offset: 0
+			})
+		);
+	}
+
+	#[test]
+	fn decode_invalid() {
+		assert_eq!(
+			"414g4344".sub_array_from_hex::<4>(0),
+			Err(HexError::InvalidDigit {
+				offset: 3
+			})
+		);
+		assert_eq!(
+			"4142 ".sub_array_from_hex::<2>(1),
+			Err(HexError::InvalidDigit {
+				offset: 4
+			})
+		);
+	}
+
+	#[test]
+	fn decode_too_short() {
+		assert_eq!(
+			"4142434".sub_array_from_hex::<4>(0),
+			Err(HexError::OutOfBounds)
+		);
+		assert_eq!(
+			"41424344".sub_array_from_hex::<1>(9),
+			Err(HexError::OutOfBounds)
+		);
+		assert_eq!("41".sub_array_from_hex::<0>(3), Err(HexError::OutOfBounds));
+	}
+
+	#[test]
+	fn decode_option() {
+		assert_eq!(
+			b"deadbeef".decode_hex_sub_array::<4>(0),
+			Some([0xDE, 0xAD, 0xBE, 0xEF])
+		);
+		assert_eq!(b"deadbeef".decode_hex_sub_array::<2>(4), Some([0xBE, 0xEF]));
+		assert_eq!(b"deadbeeg".decode_hex_sub_array::<4>(0), None);
+		assert_eq!(b"de ad".decode_hex_sub_array::<2>(0), None);
+		assert_eq!(b"deadbeef".decode_hex_sub_array::<4>(1), None);
+	}
+
+	#[test]
+	fn encode_round_trip() {
+		let bytes = [0x00, 0xDE, 0xAD, 0xBE, 0xEF, 0x0F_u8];
+
+		let mut hex = [0; 8];
+		bytes.sub_array_to_hex::<4, 8>(1, &mut hex);
+		assert_eq!(&hex, b"deadbeef");
+		assert_eq!(hex.sub_array_from_hex::<4>(0), Ok([0xDE, 0xAD, 0xBE, 0xEF]));
+		assert_eq!(b"deadbeeg".sub_array_from_hex::<4>(0).ok(), None);
+
+		let mut hex = [0; 4];
+		bytes.sub_array_to_hex::<2, 4>(4, &mut hex);
+		assert_eq!(&hex, b"ef0f");
+		assert_eq!(bytes.try_sub_array_to_hex::<2, 4>(5, &mut hex), None);
+		assert_eq!(&hex, b"ef0f");
+		assert_eq!(bytes.try_sub_array_to_hex::<0, 0>(6, &mut []), Some(()));
+	}
+}
@@ -0,0 +1,154 @@
+//! Interning of repeated fixed-size blocks
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::SubArray;
+
+
+/// Maps each distinct block of length `N` to a stable `u32` id
+///
+/// The ids are assigned in the order in which the blocks are first interned,
+/// starting at `0`, thus interning the same block again returns the same id.
+/// This is e.g. useful for compressors, which replace repeated fixed-size
+/// blocks by references to their first occurrence.
+///
+/// The blocks are kept in a `BTreeMap`, since there is no `HashMap` without
+/// `std`, thus `T` must be [`Ord`].
+///
+/// Requires the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use sub_array::SubArrayInterner;
+///
+/// let data: [u8; 8] = *b"abababcd";
+/// let mut interner = SubArrayInterner::<u8, 2>::new();
+///
+/// let ids = [0, 2, 4, 6].map(|offset| interner.intern_sub_array(&data, offset));
+/// assert_eq!(ids, [0, 0, 0, 1]);
+/// assert_eq!(interner.resolve(1), Some(b"cd"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SubArrayInterner<T, const N: usize> {
+	ids: BTreeMap<[T; N], u32>,
+	/// The interned blocks, indexed by their id
+	blocks: Vec<[T; N]>,
+}
+
+impl<T, const N: usize> SubArrayInterner<T, N> {
+	/// Creates an interner without any blocks.
+	pub const fn new() -> Self {
+		Self {
+			ids: BTreeMap::new(),
+			blocks: Vec::new(),
+		}
+	}
+
+	/// Returns the number of distinct blocks interned so far.
+	pub fn len(&self) -> usize {
+		self.blocks.len()
+	}
+
+	/// Returns `true` if no block has been interned yet.
+	pub fn is_empty(&self) -> bool {
+		self.blocks.is_empty()
+	}
+
+	/// Get the block with the given `id`, if it has been assigned.
+	pub fn resolve(&self, id: u32) -> Option<&[T; N]> {
+		self.blocks.get(usize::try_from(id).ok()?)
+	}
+}
+
+impl<T: Ord + Copy, const N: usize> SubArrayInterner<T, N> {
+	/// Returns the id of `block`, assigning the next free one if it has not
+	/// been interned before.
+	///
+	/// # Panics
+	/// Panics if there would be more than `u32::MAX + 1` distinct blocks.
+	pub fn intern(&mut self, block: &[T; N]) -> u32 {
+		if let Some(&id) = self.ids.get(block) {
+			return id;
+		}
+		let id = u32::try_from(self.blocks.len()).expect("too many distinct blocks for `u32` ids");
+		self.ids.insert(*block, id);
+		self.blocks.push(*block);
+		id
+	}
+
+	/// Returns the id of `block`, if it has been interned before.
+	pub fn get(&self, block: &[T; N]) -> Option<u32> {
+		self.ids.get(block).copied()
+	}
+
+	/// Extracts the sub-array of length `N` starting at `offset` of `src`
+	/// and interns it, see [`intern`](Self::intern).
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of `src`.
+	#[track_caller]
+	pub fn intern_sub_array<A>(&mut self, src: &A, offset: usize) -> u32
+	where
+		A: SubArray<Item = T> + ?Sized,
+	{
+		self.intern(src.sub_array_ref(offset))
+	}
+
+	/// Extracts the sub-array of length `N` starting at `offset` of `src`
+	/// and interns it, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of `src`.
+	pub fn try_intern_sub_array<A>(&mut self, src: &A, offset: usize) -> Option<u32>
+	where
+		A: SubArray<Item = T> + ?Sized,
+	{
+		Some(self.intern(src.try_sub_array_ref(offset)?))
+	}
+}
+
+impl<T, const N: usize> Default for SubArrayInterner<T, N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn repeated_blocks() {
+		let data = [1, 2, 3, 4, 1, 2, 1, 2, 3, 4, 5, 6_u8];
+		let mut interner = SubArrayInterner::<u8, 2>::new();
+
+		let ids: Vec<u32> = (0..6)
+			.map(|i| interner.intern_sub_array(&data, i * 2))
+			.collect();
+		assert_eq!(ids, [0, 1, 0, 0, 1, 2]);
+		assert_eq!(interner.len(), 3);
+
+		// Identical blocks share an id, wherever they come from
+		let slice: &[u8] = &data[1..];
+		assert_eq!(interner.intern_sub_array(slice, 7), ids[1]);
+		assert_eq!(interner.try_intern_sub_array(slice, 10), None);
+		assert_eq!(interner.get(&[5, 6]), Some(2));
+		assert_eq!(interner.get(&[6, 5]), None);
+		assert_eq!(interner.len(), 3);
+
+		for (i, &id) in ids.iter().enumerate() {
+			assert_eq!(interner.resolve(id), Some(data.sub_array_ref(i * 2)));
+		}
+		assert_eq!(interner.resolve(3), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let mut interner = SubArrayInterner::<u8, 4>::default();
+		interner.intern_sub_array(&[0_u8; 6], 3);
+	}
+}
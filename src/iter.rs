@@ -0,0 +1,927 @@
+//! Iterators over sub-arrays
+
+use core::iter::FusedIterator;
+use core::mem;
+
+use crate::RollingHasher;
+
+
+/// Iterator over the offsets of all occurrences of a fixed-size pattern
+///
+/// Matches may overlap, e.g. the pattern `[1, 1]` occurs twice in `[1, 1, 1]`,
+/// at the offsets `0` and `1`.
+///
+/// Returned by [`SubArrayRef::match_indices_sub_array`](crate::SubArrayRef::match_indices_sub_array).
+#[derive(Debug, Clone)]
+pub struct MatchIndices<'a, T, const N: usize> {
+	haystack: &'a [T],
+	pattern: &'a [T; N],
+	/// The next offset to check from the front
+	front: usize,
+	/// One past the next offset to check from the back
+	back: usize,
+}
+
+impl<'a, T, const N: usize> MatchIndices<'a, T, N> {
+	pub(crate) fn new(haystack: &'a [T], pattern: &'a [T; N]) -> Self {
+		Self {
+			haystack,
+			pattern,
+			front: 0,
+			back: (haystack.len() + 1).saturating_sub(N),
+		}
+	}
+
+	fn is_match(&self, offset: usize) -> bool
+	where
+		T: PartialEq,
+	{
+		self.haystack[offset..(offset + N)] == self.pattern[..]
+	}
+}
+
+impl<T, const N: usize> Iterator for MatchIndices<'_, T, N>
+where
+	T: PartialEq,
+{
+	type Item = usize;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.front < self.back {
+			let offset = self.front;
+			self.front += 1;
+			if self.is_match(offset) {
+				return Some(offset);
+			}
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.back - self.front))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for MatchIndices<'_, T, N>
+where
+	T: PartialEq,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		while self.front < self.back {
+			self.back -= 1;
+			if self.is_match(self.back) {
+				return Some(self.back);
+			}
+		}
+		None
+	}
+}
+
+impl<T, const N: usize> FusedIterator for MatchIndices<'_, T, N> where T: PartialEq {}
+
+
+/// Iterator over the elements that differ between a sub-array and a snapshot
+///
+/// Yields `(index, old, new)` triples in ascending order of `index`, which is
+/// relative to the start of the sub-array, `old` is the element of the
+/// snapshot and `new` that of the sub-array.
+///
+/// Returned by [`SubArrayRef::diff_sub_array`](crate::SubArrayRef::diff_sub_array).
+#[derive(Debug, Clone)]
+pub struct SubArrayDiff<'a, T, const N: usize> {
+	old: &'a [T; N],
+	new: &'a [T; N],
+	/// The next index to check from the front
+	front: usize,
+	/// One past the next index to check from the back
+	back: usize,
+}
+
+impl<'a, T, const N: usize> SubArrayDiff<'a, T, N> {
+	pub(crate) fn new(old: &'a [T; N], new: &'a [T; N]) -> Self {
+		Self {
+			old,
+			new,
+			front: 0,
+			back: N,
+		}
+	}
+
+	fn get(&self, index: usize) -> Option<(usize, &'a T, &'a T)>
+	where
+		T: PartialEq,
+	{
+		let old = &self.old[index];
+		let new = &self.new[index];
+		(old != new).then_some((index, old, new))
+	}
+
+	/// Counts the remaining elements that differ.
+	pub fn changed_count(self) -> usize
+	where
+		T: PartialEq,
+	{
+		self.count()
+	}
+
+	/// Get the first remaining element that differs, if any.
+	pub fn first_mismatch(mut self) -> Option<(usize, &'a T, &'a T)>
+	where
+		T: PartialEq,
+	{
+		self.next()
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for SubArrayDiff<'a, T, N>
+where
+	T: PartialEq,
+{
+	type Item = (usize, &'a T, &'a T);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.front < self.back {
+			let index = self.front;
+			self.front += 1;
+			if let Some(diff) = self.get(index) {
+				return Some(diff);
+			}
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.back - self.front))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for SubArrayDiff<'_, T, N>
+where
+	T: PartialEq,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		while self.front < self.back {
+			self.back -= 1;
+			if let Some(diff) = self.get(self.back) {
+				return Some(diff);
+			}
+		}
+		None
+	}
+}
+
+impl<T, const N: usize> FusedIterator for SubArrayDiff<'_, T, N> where T: PartialEq {}
+
+
+/// Iterator over the hashes of all windows of a fixed length
+///
+/// Yields one hash for each window, i.e. for each offset from `0` to
+/// `len - N`, in ascending order. Each hash is computed from that of the
+/// preceding window via [`RollingHasher::roll`].
+///
+/// Returned by [`SubArrayRef::sub_array_rolling_hash`](crate::SubArrayRef::sub_array_rolling_hash).
+#[derive(Debug, Clone)]
+pub struct RollingHashes<'a, T, H, const N: usize> {
+	slice: &'a [T],
+	hasher: H,
+	/// The offset of the next window, the `hasher` contains the window before
+	next: usize,
+}
+
+impl<'a, T, H, const N: usize> RollingHashes<'a, T, H, N> {
+	pub(crate) fn new(slice: &'a [T], hasher: H) -> Self {
+		Self {
+			slice,
+			hasher,
+			next: 0,
+		}
+	}
+}
+
+impl<T, H, const N: usize> Iterator for RollingHashes<'_, T, H, N>
+where
+	H: RollingHasher<T>,
+{
+	type Item = u64;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let offset = self.next;
+		if offset + N > self.slice.len() {
+			return None;
+		}
+
+		if offset == 0 {
+			for elem in &self.slice[..N] {
+				self.hasher.push(elem);
+			}
+		} else if N > 0 {
+			self.hasher
+				.roll(&self.slice[offset - 1], &self.slice[offset - 1 + N]);
+		}
+		self.next += 1;
+		Some(self.hasher.finish())
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = (self.slice.len() + 1)
+			.saturating_sub(N)
+			.saturating_sub(self.next);
+		(len, Some(len))
+	}
+}
+
+impl<T, H, const N: usize> ExactSizeIterator for RollingHashes<'_, T, H, N> where H: RollingHasher<T>
+{}
+
+impl<T, H, const N: usize> FusedIterator for RollingHashes<'_, T, H, N> where H: RollingHasher<T> {}
+
+/// Iterator over the segments of a slice separated by a fixed-size delimiter
+///
+/// Like [`slice::split`], but the delimiter is a sequence of `N` elements.
+/// The occurrences of the delimiter are searched for from the front, thus they
+/// do not overlap. A leading or trailing delimiter yields an empty segment,
+/// and an empty slice yields a single empty segment.
+///
+/// Returned by [`SubArrayRef::split_by_sub_array`](crate::SubArrayRef::split_by_sub_array).
+#[derive(Debug, Clone)]
+pub struct SplitBySubArray<'a, T, const N: usize> {
+	/// The part that was not yet yielded, `None` once the last segment was
+	/// yielded
+	rest: Option<&'a [T]>,
+	delimiter: &'a [T; N],
+}
+
+impl<'a, T, const N: usize> SplitBySubArray<'a, T, N> {
+	pub(crate) fn new(slice: &'a [T], delimiter: &'a [T; N]) -> Self {
+		const { assert!(N > 0, "the delimiter must not be empty") };
+		Self {
+			rest: Some(slice),
+			delimiter,
+		}
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for SplitBySubArray<'a, T, N>
+where
+	T: PartialEq,
+{
+	type Item = &'a [T];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = self.rest?;
+		match rest.windows(N).position(|w| w == self.delimiter) {
+			Some(end) => {
+				self.rest = Some(&rest[(end + N)..]);
+				Some(&rest[..end])
+			},
+			None => {
+				self.rest = None;
+				Some(rest)
+			},
+		}
+	}
+}
+
+impl<T, const N: usize> FusedIterator for SplitBySubArray<'_, T, N> where T: PartialEq {}
+
+
+/// Iterator over the consecutive, non-overlapping sub-arrays of length `N`
+///
+/// This is like [`slice::chunks_exact`], but yields fixed-size arrays. The
+/// slice is split into the whole sub-arrays and the remainder once, upfront,
+/// via [`slice::as_chunks`], thus unlike calling
+/// [`sub_array_ref`](crate::SubArray::sub_array_ref) for each offset, no
+/// bounds are checked per sub-array. The trailing elements that do not fill a
+/// whole sub-array are not yielded, see [`remainder`](Self::remainder).
+///
+/// Returned by [`SubArrayRef::sub_array_chunks`](crate::SubArrayRef::sub_array_chunks).
+#[derive(Debug, Clone)]
+pub struct SubArrayChunks<'a, T, const N: usize> {
+	chunks: core::slice::Iter<'a, [T; N]>,
+	remainder: &'a [T],
+}
+
+impl<'a, T, const N: usize> SubArrayChunks<'a, T, N> {
+	pub(crate) fn new(slice: &'a [T]) -> Self {
+		const { assert!(N > 0, "the sub-arrays must not be empty") };
+		let (chunks, remainder) = slice.as_chunks();
+		Self {
+			chunks: chunks.iter(),
+			remainder,
+		}
+	}
+
+	/// Get the trailing elements that do not fill a whole sub-array.
+	pub fn remainder(&self) -> &'a [T] {
+		self.remainder
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for SubArrayChunks<'a, T, N> {
+	type Item = &'a [T; N];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.chunks.next()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chunks.size_hint()
+	}
+
+	fn fold<B, F>(self, init: B, f: F) -> B
+	where
+		F: FnMut(B, Self::Item) -> B,
+	{
+		self.chunks.fold(init, f)
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for SubArrayChunks<'_, T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.chunks.next_back()
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for SubArrayChunks<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for SubArrayChunks<'_, T, N> {}
+
+
+/// Iterator over the consecutive, non-overlapping mutable sub-arrays of
+/// length `N`
+///
+/// This is the mutable version of [`SubArrayChunks`]. The yielded sub-arrays
+/// never overlap, thus all of them can be kept and used at the same time.
+/// This is guaranteed by splitting each of them off the not yet yielded part
+/// of the slice via [`split_first_chunk_mut`](slice::split_first_chunk_mut),
+/// i.e. [`split_at_mut`](slice::split_at_mut), without any `unsafe` code, and
+/// by this iterator not implementing [`Clone`], which would allow to yield the
+/// same sub-array twice:
+/// ```compile_fail
+/// use sub_array::SubArray;
+///
+/// let mut arr: [u8; 4] = [1, 2, 3, 4];
+///
+/// let chunks = arr.sub_array_chunks_mut::<2>();
+/// let copy = chunks.clone();
+/// ```
+///
+/// Returned by [`SubArray::sub_array_chunks_mut`](crate::SubArray::sub_array_chunks_mut).
+#[derive(Debug)]
+pub struct SubArrayChunksMut<'a, T, const N: usize> {
+	/// The not yet yielded sub-arrays, its length is a multiple of `N`
+	rest: &'a mut [T],
+	remainder: &'a mut [T],
+}
+
+impl<'a, T, const N: usize> SubArrayChunksMut<'a, T, N> {
+	pub(crate) fn new(slice: &'a mut [T]) -> Self {
+		const { assert!(N > 0, "the sub-arrays must not be empty") };
+		let len = slice.len();
+		let (rest, remainder) = slice.split_at_mut(len - len % N);
+		Self {
+			rest,
+			remainder,
+		}
+	}
+
+	/// Unwraps the trailing elements that do not fill a whole sub-array.
+	///
+	/// These are disjoint from all sub-arrays, thus this consumes the iterator
+	/// only to return them for the whole lifetime `'a`.
+	pub fn into_remainder(self) -> &'a mut [T] {
+		self.remainder
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for SubArrayChunksMut<'a, T, N> {
+	type Item = &'a mut [T; N];
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (first, rest) = mem::take(&mut self.rest).split_first_chunk_mut()?;
+		self.rest = rest;
+		Some(first)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.rest.len() / N;
+		(len, Some(len))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for SubArrayChunksMut<'_, T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let (rest, last) = mem::take(&mut self.rest).split_last_chunk_mut()?;
+		self.rest = rest;
+		Some(last)
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for SubArrayChunksMut<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for SubArrayChunksMut<'_, T, N> {}
+
+
+/// Iterator over the consecutive, non-overlapping sub-arrays of length `N`,
+/// along with their offsets
+///
+/// This is like [`SubArrayChunks`], but yields each sub-array along with its
+/// offset, which is a multiple of `N`. Only offsets where `offset + N` is in
+/// bounds are yielded.
+///
+/// Returned by [`SubArrayRef::sub_array_iter`](crate::SubArrayRef::sub_array_iter).
+#[derive(Debug, Clone)]
+pub struct SubArrayIter<'a, T, const N: usize> {
+	chunks: SubArrayChunks<'a, T, N>,
+	/// The number of sub-arrays yielded from the front
+	front: usize,
+}
+
+impl<'a, T, const N: usize> SubArrayIter<'a, T, N> {
+	pub(crate) fn new(slice: &'a [T]) -> Self {
+		Self {
+			chunks: SubArrayChunks::new(slice),
+			front: 0,
+		}
+	}
+
+	/// Get the trailing elements that do not fill a whole sub-array.
+	pub fn remainder(&self) -> &'a [T] {
+		self.chunks.remainder()
+	}
+}
+
+impl<'a, T, const N: usize> Iterator for SubArrayIter<'a, T, N> {
+	type Item = (usize, &'a [T; N]);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let chunk = self.chunks.next()?;
+		let offset = self.front * N;
+		self.front += 1;
+		Some((offset, chunk))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chunks.size_hint()
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for SubArrayIter<'_, T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let chunk = self.chunks.next_back()?;
+		Some(((self.front + self.chunks.len()) * N, chunk))
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for SubArrayIter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for SubArrayIter<'_, T, N> {}
+
+
+/// Iterator applying a function to the corresponding sub-arrays of length `N`
+/// of two arrays of the same length
+///
+/// Yields `f(a, b)` for each pair of consecutive, non-overlapping sub-arrays
+/// `a` and `b` at the same offset of either array, like [`SubArrayChunks`].
+/// The trailing elements that do not fill a whole sub-array are not compared.
+///
+/// Returned by
+/// [`SubArrayRef::sub_array_windows_compare`](crate::SubArrayRef::sub_array_windows_compare).
+#[derive(Debug, Clone)]
+pub struct WindowsCompare<'a, T, F, const N: usize> {
+	a: SubArrayChunks<'a, T, N>,
+	b: SubArrayChunks<'a, T, N>,
+	f: F,
+}
+
+impl<'a, T, F, const N: usize> WindowsCompare<'a, T, F, N> {
+	/// Panics if `a` and `b` have different lengths
+	#[track_caller]
+	pub(crate) fn new(a: &'a [T], b: &'a [T], f: F) -> Self {
+		assert_eq!(a.len(), b.len(), "the arrays must have the same length");
+		Self {
+			a: SubArrayChunks::new(a),
+			b: SubArrayChunks::new(b),
+			f,
+		}
+	}
+}
+
+impl<'a, T, U, F, const N: usize> Iterator for WindowsCompare<'a, T, F, N>
+where
+	F: FnMut(&'a [T; N], &'a [T; N]) -> U,
+{
+	type Item = U;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let a = self.a.next()?;
+		let b = self.b.next()?;
+		Some((self.f)(a, b))
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.a.size_hint()
+	}
+}
+
+impl<'a, T, U, F, const N: usize> DoubleEndedIterator for WindowsCompare<'a, T, F, N>
+where
+	F: FnMut(&'a [T; N], &'a [T; N]) -> U,
+{
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let a = self.a.next_back()?;
+		let b = self.b.next_back()?;
+		Some((self.f)(a, b))
+	}
+}
+
+impl<'a, T, U, F, const N: usize> ExactSizeIterator for WindowsCompare<'a, T, F, N> where
+	F: FnMut(&'a [T; N], &'a [T; N]) -> U
+{
+}
+
+impl<'a, T, U, F, const N: usize> FusedIterator for WindowsCompare<'a, T, F, N> where
+	F: FnMut(&'a [T; N], &'a [T; N]) -> U
+{
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+	use core::cell::Cell;
+
+	use crate::SubArray;
+	use crate::SubArrayRef;
+
+
+	#[test]
+	fn match_back_to_back() {
+		let arr = [1, 2, 1, 2, 3, 1, 2_u8];
+		let found: Vec<_> = arr.match_indices_sub_array(&[1, 2]).collect();
+		assert_eq!(found, [0, 2, 5]);
+	}
+
+	#[test]
+	fn match_overlapping() {
+		let arr = *b"aaa";
+		let found: Vec<_> = arr.match_indices_sub_array(b"aa").collect();
+		assert_eq!(found, [0, 1]);
+	}
+
+	#[test]
+	fn match_none() {
+		let arr = [1, 2, 3_u8];
+		assert_eq!(arr.match_indices_sub_array(&[3, 2]).next(), None);
+		assert_eq!(arr.match_indices_sub_array(&[1, 2, 3, 4]).next(), None);
+	}
+
+	#[test]
+	fn match_whole() {
+		let arr = [1, 2, 3_u8];
+		let found: Vec<_> = arr.match_indices_sub_array(&arr).collect();
+		assert_eq!(found, [0]);
+	}
+
+	#[test]
+	fn match_empty_pattern() {
+		let arr = [1, 2_u8];
+		let found: Vec<_> = arr.match_indices_sub_array(&[]).collect();
+		assert_eq!(found, [0, 1, 2]);
+	}
+
+	#[test]
+	fn match_double_ended() {
+		let arr = *b"abababa";
+		let slice: &[u8] = &arr;
+
+		let found: Vec<_> = slice.match_indices_sub_array(b"aba").rev().collect();
+		assert_eq!(found, [4, 2, 0]);
+
+		let mut iter = slice.match_indices_sub_array(b"aba");
+		assert_eq!(iter.next(), Some(0));
+		assert_eq!(iter.next_back(), Some(4));
+		assert_eq!(iter.next_back(), Some(2));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next_back(), None);
+	}
+
+	#[test]
+	fn diff_identical() {
+		let arr = [1, 2, 3, 4_u8];
+		assert_eq!(arr.diff_sub_array(1, &[2, 3]).next(), None);
+		assert_eq!(arr.diff_sub_array(1, &[2, 3]).changed_count(), 0);
+		assert_eq!(arr.diff_sub_array(1, &[2, 3]).first_mismatch(), None);
+	}
+
+	#[test]
+	fn diff_last() {
+		let arr = [1, 2, 3, 4_u8];
+		let diff: Vec<_> = arr.diff_sub_array(1, &[2, 3, 9]).collect();
+		assert_eq!(diff, [(2, &9, &4)]);
+	}
+
+	#[test]
+	fn diff_all() {
+		let arr = [1, 2, 3, 4_u8];
+		let snapshot = [5, 6, 7];
+		let diff: Vec<_> = arr.diff_sub_array(0, &snapshot).collect();
+		assert_eq!(diff, [(0, &5, &1), (1, &6, &2), (2, &7, &3)]);
+		assert_eq!(arr.diff_sub_array(0, &snapshot).changed_count(), 3);
+		assert_eq!(
+			arr.diff_sub_array(0, &snapshot).first_mismatch(),
+			Some((0, &5, &1))
+		);
+		assert_eq!(
+			arr.diff_sub_array(0, &snapshot).next_back(),
+			Some((2, &7, &3))
+		);
+	}
+
+	#[derive(Debug)]
+	struct CountEq<'a>(&'a Cell<usize>);
+
+	impl PartialEq for CountEq<'_> {
+		fn eq(&self, _other: &Self) -> bool {
+			self.0.set(self.0.get() + 1);
+			true
+		}
+	}
+
+	#[test]
+	fn diff_out_of_bounds() {
+		let counter = Cell::new(0);
+		let arr: [CountEq; 3] = core::array::from_fn(|_| CountEq(&counter));
+		let snapshot: [CountEq; 2] = core::array::from_fn(|_| CountEq(&counter));
+
+		assert!(arr.try_diff_sub_array(2, &snapshot).is_none());
+		assert_eq!(counter.get(), 0);
+
+		assert_eq!(arr.try_diff_sub_array(1, &snapshot).unwrap().count(), 0);
+		assert_eq!(counter.get(), 2);
+	}
+
+	#[test]
+	#[should_panic]
+	fn diff_out_of_bounds_panic() {
+		let arr = [1, 2, 3_u8];
+		arr.diff_sub_array(2, &[3, 4]);
+	}
+
+	/// Rabin-Karp search, verifying every hash match by direct comparison
+	fn rabin_karp<const N: usize>(haystack: &[u8], pattern: &[u8; N], modulus: u64) -> Vec<usize> {
+		let target = pattern
+			.sub_array_polynomial_rolling_hash::<N>(31, modulus)
+			.next();
+		haystack
+			.sub_array_polynomial_rolling_hash::<N>(31, modulus)
+			.enumerate()
+			.filter(|&(_, hash)| Some(hash) == target)
+			.map(|(offset, _)| offset)
+			.filter(|&offset| haystack.sub_array_ref::<N>(offset) == pattern)
+			.collect()
+	}
+
+	#[test]
+	fn rolling_hash_search() {
+		let haystack = b"abracadabra, abracadabra!";
+		let expected: Vec<_> = haystack.match_indices_sub_array(b"abra").collect();
+		assert_eq!(expected, [0, 7, 13, 20]);
+
+		assert_eq!(rabin_karp(haystack, b"abra", 1_000_000_007), expected);
+		// A tiny modulus causes many collisions, which must be filtered out
+		assert_eq!(rabin_karp(haystack, b"abra", 7), expected);
+		assert_eq!(rabin_karp(haystack, b"cad", 7), [4, 17]);
+		assert_eq!(rabin_karp(haystack, b"xyz", 7), []);
+	}
+
+	#[test]
+	fn rolling_hash_windows() {
+		let arr = [1, 2, 3_u8];
+
+		let hashes = arr.sub_array_polynomial_rolling_hash::<2>(10, 1000);
+		assert_eq!(hashes.len(), 2);
+		assert_eq!(hashes.collect::<Vec<_>>(), [12, 23]);
+
+		let hashes = arr.sub_array_polynomial_rolling_hash::<0>(10, 1000);
+		assert_eq!(hashes.collect::<Vec<_>>(), [0, 0, 0, 0]);
+		let hashes = arr.sub_array_polynomial_rolling_hash::<3>(10, 1000);
+		assert_eq!(hashes.collect::<Vec<_>>(), [123]);
+		let mut hashes = arr.sub_array_polynomial_rolling_hash::<4>(10, 1000);
+		assert_eq!(hashes.len(), 0);
+		assert_eq!(hashes.next(), None);
+	}
+
+	#[test]
+	fn split_segments() {
+		let data = b"one\r\ntwo\r\n\r\nthree";
+		let segments: Vec<_> = data.split_by_sub_array(b"\r\n").collect();
+		assert_eq!(segments, [&b"one"[..], b"two", b"", b"three"]);
+	}
+
+	#[test]
+	fn split_leading_trailing() {
+		let data = [0, 0, 1, 0, 0, 2, 0, 0_u8];
+		let segments: Vec<_> = data.split_by_sub_array(&[0, 0]).collect();
+		assert_eq!(segments, [&[][..], &[1], &[2], &[]]);
+
+		let data = [0, 0_u8];
+		let segments: Vec<_> = data.split_by_sub_array(&[0, 0]).collect();
+		assert_eq!(segments, [&[][..], &[]]);
+	}
+
+	#[test]
+	fn split_non_overlapping() {
+		let data = *b"aaaaa";
+		let segments: Vec<_> = data.split_by_sub_array(b"aa").collect();
+		assert_eq!(segments, [&b""[..], b"", b"a"]);
+	}
+
+	#[test]
+	fn split_empty_and_missing() {
+		let empty: &[u8] = &[];
+		let segments: Vec<_> = empty.split_by_sub_array(b"ab").collect();
+		assert_eq!(segments, [&b""[..]]);
+
+		let data = *b"abc";
+		let mut iter = data.split_by_sub_array(b"cd");
+		assert_eq!(iter.next(), Some(&b"abc"[..]));
+		assert_eq!(iter.next(), None);
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	fn chunks_exact() {
+		let arr = [1, 2, 3, 4, 5, 6, 7_u8];
+
+		let chunks: Vec<&[u8; 3]> = arr.sub_array_chunks().collect();
+		assert_eq!(chunks, [&[1, 2, 3], &[4, 5, 6]]);
+		assert_eq!(arr.sub_array_chunks::<3>().remainder(), &[7]);
+		assert_eq!(arr.sub_array_chunks::<7>().count(), 1);
+		assert_eq!(arr.sub_array_chunks::<8>().next(), None);
+		assert_eq!(arr.sub_array_chunks::<8>().remainder(), &arr);
+	}
+
+	#[test]
+	fn chunks_double_ended() {
+		let arr = [1, 2, 3, 4, 5, 6, 7_u8];
+
+		let mut chunks = arr.sub_array_chunks::<2>();
+		assert_eq!(chunks.len(), 3);
+		assert_eq!(chunks.next_back(), Some(&[5, 6]));
+		assert_eq!(chunks.next(), Some(&[1, 2]));
+		assert_eq!(chunks.len(), 1);
+		assert_eq!(chunks.next_back(), Some(&[3, 4]));
+		assert_eq!(chunks.next(), None);
+		assert_eq!(chunks.next_back(), None);
+		assert_eq!(chunks.remainder(), &[7]);
+	}
+
+	#[test]
+	fn chunks_match_sub_array_ref() {
+		let slice: &[u8] = &[9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+		for (i, chunk) in slice.sub_array_chunks::<4>().enumerate() {
+			assert_eq!(chunk, slice.sub_array_ref::<4>(i * 4));
+		}
+	}
+
+	#[test]
+	fn iter_coverage() {
+		let arr: [u8; 11] = core::array::from_fn(|i| i as u8);
+
+		let subs: Vec<(usize, &[u8; 3])> = arr.sub_array_iter().collect();
+		assert_eq!(subs.len(), 3);
+		let mut covered = 0;
+		for &(offset, sub) in &subs {
+			assert_eq!(offset, covered);
+			assert_eq!(sub, arr.sub_array_ref::<3>(offset));
+			covered += sub.len();
+		}
+		assert_eq!(
+			covered,
+			arr.len() - arr.sub_array_iter::<3>().remainder().len()
+		);
+		assert_eq!(arr.sub_array_iter::<3>().remainder(), &[9, 10]);
+	}
+
+	#[test]
+	fn iter_disjoint() {
+		let arr = [0_u32; 10];
+
+		let ranges: Vec<_> = arr
+			.sub_array_iter::<2>()
+			.map(|(_, sub)| sub.as_ptr_range())
+			.collect();
+		for (i, a) in ranges.iter().enumerate() {
+			for b in &ranges[(i + 1)..] {
+				assert!(a.end <= b.start || b.end <= a.start);
+			}
+		}
+	}
+
+	#[test]
+	fn iter_double_ended() {
+		let arr = [1, 2, 3, 4, 5, 6, 7_u8];
+
+		let mut iter = arr.sub_array_iter::<2>();
+		assert_eq!(iter.next_back(), Some((4, &[5, 6])));
+		assert_eq!(iter.next(), Some((0, &[1, 2])));
+		assert_eq!(iter.next_back(), Some((2, &[3, 4])));
+		assert_eq!(iter.next(), None);
+
+		let rev: Vec<usize> = arr.sub_array_iter::<3>().rev().map(|(o, _)| o).collect();
+		assert_eq!(rev, [3, 0]);
+		assert_eq!(arr.sub_array_iter::<8>().next(), None);
+	}
+
+	/// Keeps all the mutable sub-arrays alive at the same time, which is
+	/// sound since they are disjoint. CI additionally checks this for
+	/// aliasing at runtime, by running this module under Miri via
+	/// `cargo +nightly miri test --lib --features alloc iter::tests`.
+	#[test]
+	fn chunks_mut_all_alive() {
+		let mut arr = [0_u32; 11];
+
+		let (all, remainder) = {
+			let mut chunks = arr.sub_array_chunks_mut::<3>();
+			let all: Vec<&mut [u32; 3]> = chunks.by_ref().collect();
+			(all, chunks.into_remainder())
+		};
+		assert_eq!(all.len(), 3);
+		for (i, sub) in all.into_iter().enumerate() {
+			sub.fill(i as u32 + 1);
+		}
+		remainder.fill(9);
+
+		assert_eq!(arr, [1, 1, 1, 2, 2, 2, 3, 3, 3, 9, 9]);
+	}
+
+	#[test]
+	fn chunks_mut_double_ended() {
+		let mut arr = [1, 2, 3, 4, 5, 6, 7_u8];
+
+		let mut chunks = arr.sub_array_chunks_mut::<2>();
+		assert_eq!(chunks.len(), 3);
+		let back = chunks.next_back().unwrap();
+		let front = chunks.next().unwrap();
+		front.swap_with_slice(back);
+		assert_eq!(chunks.next(), Some(&mut [3, 4]));
+		assert_eq!(chunks.next_back(), None);
+		assert_eq!(chunks.into_remainder(), &mut [7]);
+
+		assert_eq!(arr, [5, 6, 3, 4, 1, 2, 7]);
+		assert_eq!(arr.sub_array_chunks_mut::<8>().next(), None);
+	}
+
+	#[test]
+	fn chunks_mut_per_block_transform() {
+		let mut arr: [u8; 14] = core::array::from_fn(|i| i as u8);
+
+		// A toy block cipher, keyed by the index of the block
+		for (i, block) in arr.sub_array_chunks_mut::<4>().enumerate() {
+			block.rotate_left(1);
+			for byte in block {
+				*byte ^= 0x10 * (i as u8 + 1);
+			}
+		}
+
+		assert_eq!(
+			arr,
+			[
+				0x11, 0x12, 0x13, 0x10, //
+				0x25, 0x26, 0x27, 0x24, //
+				0x39, 0x3A, 0x3B, 0x38, //
+				12, 13,
+			]
+		);
+	}
+
+	#[test]
+	fn windows_compare_similarity() {
+		let a: [u8; 10] = [0xFF, 0x00, 0xAA, 0x55, 0x0F, 0xF0, 0x12, 0x34, 1, 2];
+		let b: [u8; 10] = [0xFF, 0x00, 0xAA, 0xAA, 0xFF, 0x00, 0x12, 0x34, 3, 4];
+
+		/// The number of equal bits of both windows
+		fn similarity<const N: usize>(x: &[u8; N], y: &[u8; N]) -> u32 {
+			x.iter().zip(y).map(|(x, y)| (!(x ^ y)).count_ones()).sum()
+		}
+
+		let scores: Vec<u32> = a.sub_array_windows_compare(&b, similarity::<2>).collect();
+		assert_eq!(scores, [16, 8, 8, 16, 13]);
+
+		let mut iter = a[..9].sub_array_windows_compare(&b[..9], similarity::<4>);
+		assert_eq!(iter.len(), 2);
+		assert_eq!(iter.next_back(), Some(24));
+		assert_eq!(iter.next(), Some(24));
+		assert_eq!(iter.next(), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "the arrays must have the same length")]
+	fn windows_compare_different_lengths() {
+		let a = [0_u8; 4];
+		let _ = a.sub_array_windows_compare::<2, _, _, _>(&a[1..], |x, y| x == y);
+	}
+}
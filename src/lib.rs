@@ -1,7 +1,16 @@
 #![no_std]
 //
-// This crate is entirely safe (tho that's not a guarantee for the future)
-#![forbid(unsafe_code)]
+// This crate is entirely safe (tho that's not a guarantee for the future),
+// except for the opt-in `uninit` and `release-unchecked` features, which are
+// confined to a few `#[allow(unsafe_code)]` items.
+#![cfg_attr(
+	not(any(feature = "uninit", feature = "release-unchecked")),
+	forbid(unsafe_code)
+)]
+#![cfg_attr(
+	any(feature = "uninit", feature = "release-unchecked"),
+	deny(unsafe_code)
+)]
 
 //! Allows to extract a sub-array out of an array
 //!
@@ -42,19 +51,194 @@
 //!     ]
 //! );
 //! ```
+//!
+//! # Features
+//!
+//! - `alloc`: adds support for `Vec`, see `VecSubArray`, and interning of
+//!   repeated sub-arrays, see `SubArrayInterner`
+//! - `uninit`: allows to write sub-arrays into uninitialized memory, this
+//!   requires `unsafe` code
+//! - `release-unchecked`: adds `unsafe` accessors, whose bounds are only
+//!   checked in debug builds, see `SubArrayRef::sub_array_ref_unchecked`
+//! - `bytemuck`: allows to view sub-arrays of plain old data as bytes
+//! - `defmt`: allows to log out of bounds sub-arrays on embedded targets, see
+//!   `SubArrayRef::sub_array_ref_logged`
+//! - `subtle`: allows to compare sub-arrays in constant time, see
+//!   `SubArrayRef::ct_eq_sub_array`
+//! - `trace`: allows to record which parts of a buffer are read, see
+//!   `TrackingBuffer`
+//! - `std` or `libm`: provide the floating-point math for the entropy of byte
+//!   sub-arrays, see `ByteSubArray::sub_array_entropy`
+
+
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::ops::Add;
+use core::ops::BitXor;
+use core::ops::BitXorAssign;
+use core::ops::Sub;
+use core::slice::ChunkBy;
+
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod bytes;
+mod container;
+mod cstr;
+mod decode;
+mod dynamic;
+mod embed;
+mod endian;
+mod guard;
+mod handle;
+mod hash;
+mod hex;
+#[cfg(feature = "alloc")]
+mod intern;
+mod iter;
+mod macros;
+mod matrix;
+mod net;
+mod option;
+mod pair;
+mod reader;
+mod ring;
+mod shared;
+mod source;
+mod spec;
+mod split;
+mod tagged;
+mod text;
+#[cfg(feature = "trace")]
+mod trace;
+mod tracked;
+mod typed;
+mod validate;
+#[cfg(feature = "alloc")]
+mod vec;
+
+pub use bytes::assert_byte_len;
+pub use bytes::sub_bytes_const;
+pub use bytes::ByteSubArray;
+pub use container::AsSubSlice;
+pub use container::SubArrayError;
+pub use cstr::sub_array_ref_cstr;
+pub use decode::FromSubArray;
+pub use dynamic::DynSubArray;
+pub use embed::embed_const;
+pub use embed::Embed;
+pub use endian::BigEndian;
+pub use endian::Endian;
+pub use endian::EndianInt;
+pub use endian::FromSubArrayBytes;
+pub use endian::LittleEndian;
+pub use endian::NativeEndian;
+pub use endian::SwapBytes;
+pub use guard::SubArrayGuard;
+pub use handle::SubArrayHandle;
+pub use hash::PolynomialHash;
+pub use hash::RollingHasher;
+pub use hex::HexError;
+pub use hex::HexSubArray;
+#[cfg(feature = "alloc")]
+pub use intern::SubArrayInterner;
+pub use iter::MatchIndices;
+pub use iter::RollingHashes;
+pub use iter::SplitBySubArray;
+pub use iter::SubArrayChunks;
+pub use iter::SubArrayChunksMut;
+pub use iter::SubArrayDiff;
+pub use iter::SubArrayIter;
+pub use iter::WindowsCompare;
+pub use matrix::SquareMatrix;
+pub use net::NetSubArray;
+pub use option::checked_sub_array_of_option;
+pub use option::checked_sub_array_of_option_mut;
+pub use option::get_or_insert_sub_array_mut;
+pub use option::sub_array_of_option;
+pub use option::sub_array_of_option_mut;
+pub use option::OptionSubArrayError;
+pub use pair::SubArrayRefPair;
+pub use reader::ArrayReader;
+pub use reader::Underflow;
+pub use ring::RingWindow;
+pub use ring::RingWindowIter;
+pub use source::read_sub_array;
+pub use source::ByteSource;
+pub use spec::Layout;
+pub use spec::SubArraySpec;
+pub use spec::ValidatedLayout;
+pub use spec::WindowToken;
+pub use split::SplitArray;
+pub use tagged::TagError;
+pub use tagged::Tagged;
+pub use text::TextWindow;
+#[cfg(feature = "trace")]
+pub use trace::TrackingBuffer;
+pub use tracked::TrackedSubArray;
+pub use typed::TypedBuf;
+pub use validate::ValidateError;
+#[cfg(feature = "alloc")]
+pub use vec::VecSubArray;
 
 
 /// Array that can be slice into a smaller sub-array
 ///
 /// Also see the [crate] level reference.
+///
+/// # Panics
+/// All the implementations in this crate panic with the same message if
+/// [`sub_array_ref`](Self::sub_array_ref) or
+/// [`sub_array_mut`](Self::sub_array_mut) are out of bounds, reported at the
+/// location of the caller:
+///
+/// ```text
+/// sub-array of length 4 at offset 2 exceeds the array of length 5
+/// ```
+///
+/// This format is kept stable across patch releases, such that it can be
+/// matched by e.g. `#[should_panic(expected = "...")]`.
 pub trait SubArray {
 	/// The value type of this array.
 	///
 	/// This is the `T` in `[T; N]` on regular arrays.
 	type Item;
 
+	/// The number of elements in this array.
+	///
+	/// This allows generic code to check whether a sub-array fits before
+	/// extracting it, without requiring further bounds such as
+	/// `AsRef<[Self::Item]>`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// fn fits<A: SubArray<Item = u8>, const N: usize>(buf: &A, offset: usize) -> bool {
+	///     offset <= buf.len() && N <= buf.len() - offset
+	/// }
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// assert!(fits::<_, 2>(&arr, 3));
+	/// assert!(!fits::<_, 2>(&arr, 4));
+	/// ```
+	fn len(&self) -> usize;
+
+	/// Returns `true` if this array has no elements.
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
 	/// Get a reference to a sub-array of length `N` starting at `offset`.
 	///
+	/// A sub-array of length zero is in bounds at any `offset` up to and
+	/// including the length of this array, also see
+	/// [`empty_sub_array_at`](SubArrayRef::empty_sub_array_at).
+	///
 	/// # Panics
 	/// Panics if `offset + N` exceeds the length of this array.
 	///
@@ -87,170 +271,4469 @@ pub trait SubArray {
 	/// assert_eq!(sub, &mut [9, 8]);
 	/// ```
 	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N];
-}
 
-/// Implementation on regular arrays
-impl<T, const M: usize> SubArray for [T; M] {
-	type Item = T;
-
-	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
-		self[offset..(offset + N)].try_into().unwrap()
+	/// Get a reference to a sub-array of length `N` starting at `offset`, if
+	/// it is in bounds.
+	///
+	/// This is the non-panicking version of
+	/// [`sub_array_ref`](Self::sub_array_ref), it returns `None` if
+	/// `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// assert_eq!(arr.try_sub_array_ref::<2>(3), Some(&[6, 5]));
+	/// assert_eq!(arr.try_sub_array_ref::<2>(4), None);
+	/// ```
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		// Implementations may check the bounds more cheaply, but this way they
+		// only need to provide `len` on top of the panicking accessors
+		if offset.checked_add(N)? <= self.len() {
+			Some(self.sub_array_ref(offset))
+		} else {
+			None
+		}
 	}
 
-	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
-		(&mut self[offset..(offset + N)]).try_into().unwrap()
+	/// Get a mutable reference to a sub-array of length `N` starting at
+	/// `offset`, if it is in bounds.
+	///
+	/// This is the non-panicking version of
+	/// [`sub_array_mut`](Self::sub_array_mut), it returns `None` if
+	/// `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// assert_eq!(arr.try_sub_array_mut::<2>(0), Some(&mut [9, 8]));
+	/// assert_eq!(arr.try_sub_array_mut::<6>(0), None);
+	/// ```
+	fn try_sub_array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut [Self::Item; N]> {
+		if offset.checked_add(N)? <= self.len() {
+			Some(self.sub_array_mut(offset))
+		} else {
+			None
+		}
 	}
-}
 
-/// Implementation on slices
-impl<T> SubArray for [T] {
-	type Item = T;
+	/// Get a mutable sub-array of length `N` starting at `offset`, which
+	/// remembers that `offset`.
+	///
+	/// The returned [`TrackedSubArray`] dereferences to `[Self::Item; N]`, and
+	/// in addition reports its [`origin_offset`](TrackedSubArray::origin_offset),
+	/// e.g. for error reporting.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// // Get a tracked sub-array starting at offset 2
+	/// let mut sub = arr.sub_array_tracked_mut::<2>(2);
+	/// sub[1] = 42;
+	/// assert_eq!(*sub, [7, 42]);
+	/// assert_eq!(sub.origin_offset(), 2);
+	/// ```
+	fn sub_array_tracked_mut<const N: usize>(
+		&mut self,
+		offset: usize,
+	) -> TrackedSubArray<'_, Self::Item, N> {
+		TrackedSubArray::new(self.sub_array_mut(offset), offset)
+	}
 
-	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
-		self[offset..(offset + N)].try_into().unwrap()
+	/// Clones the elements of `src` into the sub-array of length `N` starting
+	/// at `offset`.
+	///
+	/// Each element is cloned in-place (via [`Clone::clone_from`]), instead of
+	/// first cloning the entire `src` array and moving it into this array.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [String; 3] = Default::default();
+	///
+	/// arr.sub_array_clone_into(1, &[String::from("foo"), String::from("bar")]);
+	/// assert_eq!(arr, ["", "foo", "bar"]);
+	/// ```
+	fn sub_array_clone_into<const N: usize>(&mut self, offset: usize, src: &[Self::Item; N])
+	where
+		Self::Item: Clone,
+	{
+		self.sub_array_mut::<N>(offset).clone_from_slice(src);
 	}
 
-	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
-		(&mut self[offset..(offset + N)]).try_into().unwrap()
+	/// Get `N` distinct mutable references to the elements of the sub-array of
+	/// length `N` starting at `offset`.
+	///
+	/// Unlike a single `&mut [Self::Item; N]`, the individual references can be
+	/// handed out independently, e.g. to an API taking several `&mut T`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// let [a, b] = arr.sub_array_each_mut(1);
+	/// core::mem::swap(a, b);
+	/// assert_eq!(arr, [9, 7, 8, 6, 5]);
+	/// ```
+	fn sub_array_each_mut<const N: usize>(&mut self, offset: usize) -> [&mut Self::Item; N] {
+		self.sub_array_mut::<N>(offset).each_mut()
 	}
-}
 
-/// Implementation on mutable references
-impl<T> SubArray for &mut T
-where
-	T: SubArray,
-{
-	type Item = T::Item;
+	/// Get `N` distinct mutable references to the elements of the sub-array of
+	/// length `N` starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_sub_array_each_mut<const N: usize>(
+		&mut self,
+		offset: usize,
+	) -> Option<[&mut Self::Item; N]> {
+		Some(self.try_sub_array_mut::<N>(offset)?.each_mut())
+	}
 
-	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
-		(**self).sub_array_ref(offset)
+	/// Get a mutable reference to a sub-array of length `N` starting at
+	/// `offset`, together with the elements before and after it.
+	///
+	/// This is the mutable version of
+	/// [`sub_array_ref_and_remainder`](SubArrayRef::sub_array_ref_and_remainder).
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// let (before, sub, after) = arr.sub_array_mut_and_remainder::<2>(1);
+	/// before[0] = sub[0];
+	/// *sub = [after[0], after[1]];
+	/// assert_eq!(arr, [8, 6, 5, 6, 5]);
+	/// ```
+	#[allow(clippy::type_complexity)]
+	#[track_caller]
+	fn sub_array_mut_and_remainder<const N: usize>(
+		&mut self,
+		offset: usize,
+	) -> (&mut [Self::Item], &mut [Self::Item; N], &mut [Self::Item])
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		let slice = self.as_mut();
+		let len = slice.len();
+		let Some((before, rest)) = slice.split_at_mut_checked(offset) else {
+			container::out_of_bounds(offset, N, len);
+		};
+		let Some((sub, after)) = rest.split_first_chunk_mut() else {
+			container::out_of_bounds(offset, N, len);
+		};
+		(before, sub, after)
 	}
 
-	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
-		(**self).sub_array_mut(offset)
+	/// Get a mutable reference to the sub-array described by `spec`.
+	///
+	/// This is the same as `self.sub_array_mut::<N>(spec.offset)`, see
+	/// [`SubArraySpec`] for details.
+	///
+	/// # Panics
+	/// Panics if `spec.offset + N` exceeds the length of this array.
+	fn apply_mut<const N: usize>(&mut self, spec: SubArraySpec<N>) -> &mut [Self::Item; N] {
+		self.sub_array_mut(spec.offset)
 	}
-}
 
+	/// Get a mutable reference to the sub-array located by `token`.
+	///
+	/// See [`apply_window`](SubArrayRef::apply_window) for details.
+	///
+	/// # Panics
+	/// Panics if this array became shorter than `token.end()` since the token
+	/// was located, which requires it to be shorter than `token.source_len()`.
+	#[track_caller]
+	fn apply_window_mut<const N: usize>(&mut self, token: WindowToken<N>) -> &mut [Self::Item; N] {
+		self.sub_array_mut(token.offset())
+	}
 
+	/// XORs each of the first `count` blocks of length `N` with its preceding
+	/// block, in-place.
+	///
+	/// This array is split into consecutive blocks of length `N` starting at
+	/// offset `0`, then for each `i` in `1..count`, block `i` is XORed with
+	/// block `i - 1`. The blocks are processed in reverse order, such that
+	/// each block is XORed with the original content of its predecessor, as
+	/// needed e.g. for the chaining step of the CBC block cipher mode
+	/// decryption.
+	///
+	/// # Panics
+	/// Panics if `count * N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 6] = [0b0001, 0b0010, 0b0011, 0b0100, 0b0111, 0b1000];
+	///
+	/// arr.sub_array_xor_adjacent_blocks::<2>(3);
+	/// assert_eq!(arr, [0b0001, 0b0010, 0b0010, 0b0110, 0b0100, 0b1100]);
+	/// ```
+	#[track_caller]
+	fn sub_array_xor_adjacent_blocks<const N: usize>(&mut self, count: usize)
+	where
+		Self::Item: BitXorAssign + Copy,
+	{
+		let len = self.len();
+		match count.checked_mul(N) {
+			Some(total) if total <= len => {},
+			_ => container::out_of_bounds(0, count.saturating_mul(N), len),
+		}
+		for i in (1..count).rev() {
+			let offset = i * N;
+			let prev: [Self::Item; N] = *self.sub_array_ref(offset - N);
+			let block = self.sub_array_mut::<N>(offset);
+			for (elem, prev) in block.iter_mut().zip(prev) {
+				*elem ^= prev;
+			}
+		}
+	}
 
-#[cfg(test)]
-mod tests {
-	extern crate alloc;
+	/// Get a mutable sub-array of length `N` starting at `offset`, which calls
+	/// `on_drop` with this entire array once it is dropped.
+	///
+	/// This is useful for derived fields, such as a checksum over the array,
+	/// which must be refreshed after every modification. See
+	/// [`SubArrayGuard`] for details.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// // Three bytes of payload, followed by their sum
+	/// let mut packet: [u8; 4] = [1, 2, 3, 6];
+	///
+	/// let mut payload = packet.sub_array_mut_guarded::<2, _>(1, |packet: &mut [u8; 4]| {
+	///     packet[3] = packet[..3].iter().sum();
+	/// });
+	/// *payload = [4, 5];
+	/// drop(payload);
+	///
+	/// assert_eq!(packet, [1, 4, 5, 10]);
+	/// ```
+	fn sub_array_mut_guarded<const N: usize, F>(
+		&mut self,
+		offset: usize,
+		on_drop: F,
+	) -> SubArrayGuard<'_, Self, F, N>
+	where
+		F: FnOnce(&mut Self),
+	{
+		SubArrayGuard::new(self, offset, on_drop)
+	}
 
-	use alloc::string::String;
-	use alloc::string::ToString;
+	/// Get a [`SubArrayRefPair`] of mutable references to the sub-array of
+	/// length `N` starting at `offset_a` and the one of length `M` starting
+	/// at `offset_b`.
+	///
+	/// Returns `None` if either sub-array is out of bounds or if they overlap.
+	/// The two sub-arrays may be in either order.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut buf = [1, 2, 3, 4, 5_u8];
+	///
+	/// buf.split_pair_mut::<2, 2>(0, 3).unwrap().swap();
+	/// assert_eq!(buf, [4, 5, 3, 1, 2]);
+	///
+	/// assert!(buf.split_pair_mut::<2, 2>(0, 1).is_none());
+	/// ```
+	fn split_pair_mut<const N: usize, const M: usize>(
+		&mut self,
+		offset_a: usize,
+		offset_b: usize,
+	) -> Option<SubArrayRefPair<'_, Self::Item, N, M>>
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		let slice = self.as_mut();
+		let end_a = offset_a.checked_add(N)?;
+		let end_b = offset_b.checked_add(M)?;
+		if end_a > slice.len() || end_b > slice.len() {
+			return None;
+		}
 
-	use super::*;
+		if end_a <= offset_b {
+			let (head, tail) = slice.split_at_mut(offset_b);
+			Some(SubArrayRefPair::new(
+				head.sub_array_mut(offset_a),
+				tail.sub_array_mut(0),
+			))
+		} else if end_b <= offset_a {
+			let (head, tail) = slice.split_at_mut(offset_a);
+			Some(SubArrayRefPair::new(
+				tail.sub_array_mut(0),
+				head.sub_array_mut(offset_b),
+			))
+		} else {
+			None
+		}
+	}
 
+	/// Get an immutable reference to the sub-array of length `N` starting at
+	/// `read_offset` and a mutable one to the sub-array starting at
+	/// `write_offset`, at the same time.
+	///
+	/// Returns `None` if either sub-array is out of bounds or if they overlap.
+	/// The two sub-arrays may be in either order.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut buf = [1, 2, 3, 4, 5, 6_u8];
+	///
+	/// let (src, dst) = buf.split_read_write::<2>(4, 1).unwrap();
+	/// dst.copy_from_slice(src);
+	/// assert_eq!(buf, [1, 5, 6, 4, 5, 6]);
+	///
+	/// assert_eq!(buf.split_read_write::<2>(0, 1), None);
+	/// ```
+	#[allow(clippy::type_complexity)]
+	fn split_read_write<const N: usize>(
+		&mut self,
+		read_offset: usize,
+		write_offset: usize,
+	) -> Option<(&[Self::Item; N], &mut [Self::Item; N])>
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		let slice = self.as_mut();
+		let read_end = read_offset.checked_add(N)?;
+		let write_end = write_offset.checked_add(N)?;
+		if read_end > slice.len() || write_end > slice.len() {
+			return None;
+		}
 
-	#[test]
-	fn empty_ref() {
-		let arr = [0_u8; 0];
-		assert_eq!(arr.sub_array_ref::<0>(0), &[]);
+		if read_end <= write_offset {
+			let (head, tail) = slice.split_at_mut(write_offset);
+			Some((head.sub_array_ref(read_offset), tail.sub_array_mut(0)))
+		} else if write_end <= read_offset {
+			let (head, tail) = slice.split_at_mut(read_offset);
+			Some((tail.sub_array_ref(0), head.sub_array_mut(write_offset)))
+		} else {
+			None
+		}
 	}
 
-	#[test]
-	fn empty_mut() {
-		let mut arr = [0_u8; 0];
-		assert_eq!(arr.sub_array_mut::<0>(0), &mut []);
+	/// Copies `new` into the sub-array of length `N` starting at `offset`,
+	/// but only if it differs from the current content.
+	///
+	/// Returns whether the sub-array was written. This is useful for storage
+	/// that wears out on writes, such as flash or EEPROM pages.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	/// Also see [`try_copy_sub_array_if_changed`](Self::try_copy_sub_array_if_changed).
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut page: [u8; 4] = [1, 2, 3, 4];
+	///
+	/// assert!(!page.copy_sub_array_if_changed(1, &[2, 3]));
+	/// assert!(page.copy_sub_array_if_changed(1, &[2, 9]));
+	/// assert_eq!(page, [1, 2, 9, 4]);
+	/// ```
+	#[track_caller]
+	fn copy_sub_array_if_changed<const N: usize>(
+		&mut self,
+		offset: usize,
+		new: &[Self::Item; N],
+	) -> bool
+	where
+		Self::Item: PartialEq + Copy,
+	{
+		let current = self.sub_array_mut::<N>(offset);
+		let changed = current != new;
+		if changed {
+			*current = *new;
+		}
+		changed
 	}
 
-	#[test]
-	fn full_ref() {
-		let arr = [1, 2, 3_i8];
-		assert_eq!(arr.sub_array_ref::<3>(0), &[1, 2, 3]);
+	/// Copies `new` into the sub-array of length `N` starting at `offset`,
+	/// but only if it differs from the current content and is in bounds.
+	///
+	/// Returns whether the sub-array was written, or `None` if `offset + N`
+	/// exceeds the length of this array.
+	/// Also see [`copy_sub_array_if_changed`](Self::copy_sub_array_if_changed).
+	fn try_copy_sub_array_if_changed<const N: usize>(
+		&mut self,
+		offset: usize,
+		new: &[Self::Item; N],
+	) -> Option<bool>
+	where
+		Self::Item: PartialEq + Copy,
+	{
+		let current = self.try_sub_array_mut::<N>(offset)?;
+		let changed = current != new;
+		if changed {
+			*current = *new;
+		}
+		Some(changed)
 	}
 
-	#[test]
-	fn full_mut() {
-		let mut arr = [1, 2, 3_i8];
-		assert_eq!(arr.sub_array_mut::<3>(0), &mut [1, 2, 3]);
+	/// Clones `new` into the sub-array of length `N` starting at `offset`,
+	/// but only if it differs from the current content.
+	///
+	/// This is the [`Clone`] flavor of
+	/// [`copy_sub_array_if_changed`](Self::copy_sub_array_if_changed), on a
+	/// change the entire sub-array is written (via [`Clone::clone_from`]).
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	#[track_caller]
+	fn clone_sub_array_if_changed<const N: usize>(
+		&mut self,
+		offset: usize,
+		new: &[Self::Item; N],
+	) -> bool
+	where
+		Self::Item: PartialEq + Clone,
+	{
+		let current = self.sub_array_mut::<N>(offset);
+		let changed = current != new;
+		if changed {
+			current.clone_from_slice(new);
+		}
+		changed
 	}
 
-	#[test]
-	fn first_ref() {
-		let arr = [1, 2, 3_u16];
-		assert_eq!(arr.sub_array_ref::<1>(0), &[1]);
+	/// Copies each of the arrays in `new` into the consecutive sub-arrays of
+	/// length `N` starting at `offset`, but only those that differ from the
+	/// current content.
+	///
+	/// Returns the number of sub-arrays that were written. The entire region
+	/// of `new.len() * N` elements is bounds-checked upfront, thus in case of
+	/// a panic, nothing is written.
+	///
+	/// # Panics
+	/// Panics if `offset + new.len() * N` exceeds the length of this array.
+	/// Also see [`try_copy_chunks_if_changed`](Self::try_copy_chunks_if_changed).
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut flash: [u8; 6] = [0, 1, 1, 2, 2, 3];
+	///
+	/// assert_eq!(flash.copy_chunks_if_changed(1, &[[1, 1], [9, 2]]), 1);
+	/// assert_eq!(flash, [0, 1, 1, 9, 2, 3]);
+	/// ```
+	#[track_caller]
+	fn copy_chunks_if_changed<const N: usize>(
+		&mut self,
+		offset: usize,
+		new: &[[Self::Item; N]],
+	) -> usize
+	where
+		Self: AsMut<[Self::Item]>,
+		Self::Item: PartialEq + Copy,
+	{
+		let len = self.len();
+		match self.try_copy_chunks_if_changed(offset, new) {
+			Some(written) => written,
+			None => container::out_of_bounds(offset, new.len().saturating_mul(N), len),
+		}
 	}
 
-	#[test]
-	fn first_mut() {
-		let mut arr = [1, 2, 3_u16];
-		assert_eq!(arr.sub_array_mut::<1>(0), &mut [1]);
-	}
+	/// Copies each of the arrays in `new` into the consecutive sub-arrays of
+	/// length `N` starting at `offset`, but only those that differ from the
+	/// current content, if they are all in bounds.
+	///
+	/// Returns the number of sub-arrays that were written, or `None` (without
+	/// writing anything) if `offset + new.len() * N` exceeds the length of
+	/// this array.
+	/// Also see [`copy_chunks_if_changed`](Self::copy_chunks_if_changed).
+	fn try_copy_chunks_if_changed<const N: usize>(
+		&mut self,
+		offset: usize,
+		new: &[[Self::Item; N]],
+	) -> Option<usize>
+	where
+		Self: AsMut<[Self::Item]>,
+		Self::Item: PartialEq + Copy,
+	{
+		let len = new.len().checked_mul(N)?;
+		let region = self.as_mut().get_mut(offset..)?.get_mut(..len)?;
+		let (chunks, _) = region.as_chunks_mut::<N>();
 
-	#[test]
-	fn middle_ref() {
-		let arr = [1, 2, 3_i16];
-		assert_eq!(arr.sub_array_ref::<1>(1), &[2]);
+		let mut written = 0;
+		for (current, new) in chunks.iter_mut().zip(new) {
+			if current != new {
+				*current = *new;
+				written += 1;
+			}
+		}
+		Some(written)
 	}
 
-	#[test]
-	fn middle_mut() {
-		let mut arr = [1, 2, 3_i16];
-		assert_eq!(arr.sub_array_mut::<1>(1), &mut [2]);
+	/// Reverses the byte order of each integer of the sub-array of length
+	/// `N` starting at `offset`, in-place.
+	///
+	/// This converts between little- and big-endian, e.g. between the native
+	/// and the network byte order on little-endian targets. Also see
+	/// [`ByteSubArray::sub_array_swap_bytes_u16`] for reading integers out of
+	/// a byte array instead.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u16; 3] = [0x1234, 0x5678, 0x9ABC];
+	///
+	/// arr.sub_array_swap_bytes::<2>(1);
+	/// assert_eq!(arr, [0x1234, 0x7856, 0xBC9A]);
+	/// ```
+	fn sub_array_swap_bytes<const N: usize>(&mut self, offset: usize)
+	where
+		Self::Item: SwapBytes,
+	{
+		for int in self.sub_array_mut::<N>(offset) {
+			*int = int.swap_bytes();
+		}
 	}
 
-	#[test]
-	fn last_ref() {
-		let arr = [1, 2, 3_i16];
-		assert_eq!(arr.sub_array_ref::<1>(2), &[3]);
+	/// Reorders the sub-array of length `N` starting at `offset`, such that
+	/// its element at index `k` is at its sorted position.
+	///
+	/// This delegates to [`slice::select_nth_unstable`] on the sub-array,
+	/// thus `k` is relative to `offset`, and the returned `(before, nth,
+	/// after)` are parts of the sub-array: all elements in `before` are less
+	/// than or equal to `nth`, and all in `after` are greater than or equal.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array, or if `k` is
+	/// not less than `N`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 7] = [0xFF, 5, 1, 4, 2, 3, 0xFF];
+	///
+	/// // The median of the sub-array
+	/// let (_, median, _) = arr.sub_array_select_nth_unstable::<5>(1, 2);
+	/// assert_eq!(*median, 3);
+	/// assert_eq!([arr[0], arr[6]], [0xFF, 0xFF]);
+	/// ```
+	#[allow(clippy::type_complexity)]
+	fn sub_array_select_nth_unstable<const N: usize>(
+		&mut self,
+		offset: usize,
+		k: usize,
+	) -> (&mut [Self::Item], &mut Self::Item, &mut [Self::Item])
+	where
+		Self::Item: Ord,
+	{
+		self.sub_array_mut::<N>(offset).select_nth_unstable(k)
 	}
 
-	#[test]
+	/// Sorts the sub-array of length `N` starting at `offset` by the keys
+	/// extracted via `key`, in-place.
+	///
+	/// This delegates to [`slice::sort_by_key`] on the sub-array, thus the sort
+	/// is stable, i.e. elements with equal keys keep their relative order.
+	/// Also see [`sub_array_sort_unstable_by_key`](Self::sub_array_sort_unstable_by_key),
+	/// which does not allocate.
+	///
+	/// Requires the `alloc` feature.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [(char, u8); 5] = [('x', 0), ('a', 2), ('b', 1), ('c', 2), ('y', 0)];
+	///
+	/// arr.sub_array_sort_by_key::<3, _, _>(1, |&(_, prio)| prio);
+	/// assert_eq!(arr, [('x', 0), ('b', 1), ('a', 2), ('c', 2), ('y', 0)]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn sub_array_sort_by_key<const N: usize, K, F>(&mut self, offset: usize, key: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.sub_array_mut::<N>(offset).sort_by_key(key);
+	}
+
+	/// Sorts the sub-array of length `N` starting at `offset` by the keys
+	/// extracted via `key`, in-place, without preserving the order of equal
+	/// elements.
+	///
+	/// This delegates to [`slice::sort_unstable_by_key`] on the sub-array.
+	/// Also see [`sub_array_sort_by_key`](Self::sub_array_sort_by_key).
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [i8; 5] = [9, -3, 1, -2, 9];
+	///
+	/// arr.sub_array_sort_unstable_by_key::<3, _, _>(1, |x| x.abs());
+	/// assert_eq!(arr, [9, 1, -2, -3, 9]);
+	/// ```
+	fn sub_array_sort_unstable_by_key<const N: usize, K, F>(&mut self, offset: usize, key: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K,
+	{
+		self.sub_array_mut::<N>(offset).sort_unstable_by_key(key);
+	}
+
+	/// Writes each of the `values` to the element at the respective index of
+	/// `indices`.
+	///
+	/// This is the inverse of [`gather_sub_array`](SubArrayRef::gather_sub_array),
+	/// e.g. to apply a permutation on write. The values are written in order,
+	/// thus if `indices` contains duplicates, the last of them wins.
+	///
+	/// # Panics
+	/// Panics if any of the `indices` is out of bounds of this array, in which
+	/// case nothing is written.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 4] = [0; 4];
+	///
+	/// arr.scatter_sub_array(&[3, 0, 2], [1, 2, 3]);
+	/// assert_eq!(arr, [2, 0, 3, 1]);
+	/// ```
+	#[track_caller]
+	fn scatter_sub_array<const N: usize>(&mut self, indices: &[usize; N], values: [Self::Item; N])
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		self.try_scatter_sub_array(indices, values)
+			.expect("scatter index out of bounds")
+	}
+
+	/// Writes each of the `values` to the element at the respective index of
+	/// `indices`, if they are all in bounds.
+	///
+	/// Returns `None` if any of the `indices` is out of bounds of this array,
+	/// in which case nothing is written.
+	/// Also see [`scatter_sub_array`](Self::scatter_sub_array).
+	fn try_scatter_sub_array<const N: usize>(
+		&mut self,
+		indices: &[usize; N],
+		values: [Self::Item; N],
+	) -> Option<()>
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		let slice = self.as_mut();
+		if !indices.iter().all(|&i| i < slice.len()) {
+			return None;
+		}
+		for (&i, value) in indices.iter().zip(values) {
+			slice[i] = value;
+		}
+		Some(())
+	}
+
+	/// Get an iterator calling `f` with each of the `count` consecutive mutable
+	/// sub-arrays of length `N` starting at `start`.
+	///
+	/// This allows to fix up records in-place, while decoding them. All
+	/// records are bounds checked once up front, and `f` is called lazily.
+	/// Also see [`records`](SubArrayRef::records). Fails to compile if `N == 0`.
+	///
+	/// # Panics
+	/// Panics if `start + count * N` exceeds the length of this array,
+	/// including if it overflows `usize`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut buf: [u8; 5] = [0xAA, 1, 2, 3, 4];
+	///
+	/// // Swap the bytes of each record, and get their sum
+	/// let sum: u8 = buf
+	///     .records_mut(1, 2, |raw: &mut [u8; 2]| {
+	///         raw.reverse();
+	///         raw[0] + raw[1]
+	///     })
+	///     .sum();
+	/// assert_eq!(sum, 10);
+	/// assert_eq!(buf, [0xAA, 2, 1, 4, 3]);
+	/// ```
+	#[track_caller]
+	fn records_mut<const N: usize, R, F>(
+		&mut self,
+		start: usize,
+		count: usize,
+		f: F,
+	) -> core::iter::Map<core::slice::IterMut<'_, [Self::Item; N]>, F>
+	where
+		Self: AsMut<[Self::Item]>,
+		F: FnMut(&mut [Self::Item; N]) -> R,
+	{
+		let len = self.len();
+		match self.try_records_mut(start, count, f) {
+			Some(records) => records,
+			None => container::out_of_bounds(start, count.saturating_mul(N), len),
+		}
+	}
+
+	/// Get an iterator calling `f` with each of the `count` consecutive mutable
+	/// sub-arrays of length `N` starting at `start`, if they are all in bounds.
+	///
+	/// Returns `None` if `start + count * N` exceeds the length of this array.
+	/// Also see [`records_mut`](Self::records_mut).
+	fn try_records_mut<const N: usize, R, F>(
+		&mut self,
+		start: usize,
+		count: usize,
+		f: F,
+	) -> Option<core::iter::Map<core::slice::IterMut<'_, [Self::Item; N]>, F>>
+	where
+		Self: AsMut<[Self::Item]>,
+		F: FnMut(&mut [Self::Item; N]) -> R,
+	{
+		const { assert!(N > 0, "the records must not be empty") };
+		let len = count.checked_mul(N)?;
+		let region = self.as_mut().get_mut(start..)?.get_mut(..len)?;
+		let (records, _) = region.as_chunks_mut::<N>();
+		Some(records.iter_mut().map(f))
+	}
+
+	/// Get an iterator over the consecutive, non-overlapping mutable
+	/// sub-arrays of length `N`, starting at the beginning of this array.
+	///
+	/// The yielded sub-arrays never overlap, thus they can all be used at the
+	/// same time, see [`SubArrayChunksMut`] for details. Fails to compile if
+	/// `N == 0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	///
+	/// let mut arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let mut chunks = arr.sub_array_chunks_mut::<2>();
+	/// let first: &mut [u8; 2] = chunks.next().unwrap();
+	/// let second: &mut [u8; 2] = chunks.next().unwrap();
+	/// first.swap_with_slice(second);
+	/// chunks.into_remainder()[0] = 0;
+	/// assert_eq!(arr, [3, 4, 1, 2, 0]);
+	/// ```
+	fn sub_array_chunks_mut<const N: usize>(&mut self) -> SubArrayChunksMut<'_, Self::Item, N>
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		SubArrayChunksMut::new(self.as_mut())
+	}
+
+	/// Get a mutable reference to the sub-array of length `N` starting at
+	/// `offset`, only checking the bounds in debug builds.
+	///
+	/// This is the mutable version of
+	/// [`sub_array_ref_unchecked`](SubArrayRef::sub_array_ref_unchecked).
+	///
+	/// Requires the `release-unchecked` feature, which contains `unsafe` code.
+	///
+	/// # Safety
+	/// `offset + N` must not exceed the length of this array. Otherwise, this
+	/// panics in debug builds, but is undefined behavior in release builds.
+	#[cfg(feature = "release-unchecked")]
+	#[allow(unsafe_code)]
+	#[track_caller]
+	unsafe fn sub_array_mut_unchecked<const N: usize>(
+		&mut self,
+		offset: usize,
+	) -> &mut [Self::Item; N]
+	where
+		Self: AsMut<[Self::Item]>,
+	{
+		let slice = self.as_mut();
+		if cfg!(debug_assertions) && offset.checked_add(N).is_none_or(|end| end > slice.len()) {
+			container::out_of_bounds(offset, N, slice.len());
+		}
+		// SAFETY: the caller guarantees that the `N` elements starting at
+		// `offset` are in bounds of `slice`, thus they are a valid `[T; N]`.
+		unsafe { &mut *slice.as_mut_ptr().add(offset).cast::<[Self::Item; N]>() }
+	}
+}
+
+/// Array that allows to read its sub-arrays, including shared containers
+///
+/// This provides the methods of [`SubArray`], which only need shared access.
+/// It is implemented for everything that implements [`SubArray`], and for
+/// shared containers, which can not implement [`SubArray`], since they do not
+/// allow mutable access: shared references to arrays and slices, `Rc` and
+/// `Arc`, the latter two requiring the `alloc` feature. Thus, generic code
+/// that only reads sub-arrays can be bound on this trait to accept these.
+///
+/// Generic code bound on this trait can further call `len`, `is_empty`,
+/// `sub_array_ref` and `try_sub_array_ref`, which are the same as the ones of
+/// [`SubArray`]. On concrete types, these are called via [`SubArray`], thus
+/// both traits can be imported without making any method ambiguous.
+///
+/// This trait can not be implemented directly, implement [`SubArray`]
+/// instead.
+///
+/// # Example
+/// ```
+/// use sub_array::SubArrayRef;
+///
+/// fn magic<B: SubArrayRef<Item = u8> + ?Sized>(buf: &B) -> Option<[u8; 2]> {
+///     Some(buf.try_copy_sub_array(0)?.map(|b| b ^ 0xFF))
+/// }
+///
+/// let arr: [u8; 3] = [0xCA, 0xFE, 0];
+/// let slice: &[u8] = &arr[2..];
+///
+/// assert_eq!(magic(&arr), Some([0x35, 0x01]));
+/// assert_eq!(magic(&slice), None);
+/// ```
+pub trait SubArrayRef: shared::SubArrayRead {
+	/// Get the empty sub-array at `offset`.
+	///
+	/// This is the same as `sub_array_ref::<0>(offset)`, which is in bounds
+	/// for any `offset` up to and including the length of this array, the same
+	/// as the empty range `offset..offset` of a slice. Thus, generic code,
+	/// where `N` happens to be zero, behaves the same as for any other `N` at
+	/// the end of the array.
+	///
+	/// Also see [`try_empty_sub_array_at`](Self::try_empty_sub_array_at).
+	///
+	/// # Panics
+	/// Panics if `offset` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 3] = [1, 2, 3];
+	///
+	/// assert_eq!(arr.empty_sub_array_at(3), &[]);
+	/// assert_eq!(arr.try_empty_sub_array_at(4), None);
+	/// ```
+	#[track_caller]
+	fn empty_sub_array_at(&self, offset: usize) -> &[Self::Item; 0] {
+		match self.try_empty_sub_array_at(offset) {
+			Some(sub) => sub,
+			None => container::out_of_bounds(offset, 0, self.len()),
+		}
+	}
+
+	/// Get the empty sub-array at `offset`, if it is in bounds.
+	///
+	/// This is the non-panicking version of
+	/// [`empty_sub_array_at`](Self::empty_sub_array_at), it returns `None` if
+	/// `offset` exceeds the length of this array.
+	fn try_empty_sub_array_at(&self, offset: usize) -> Option<&[Self::Item; 0]> {
+		self.try_sub_array_ref::<0>(offset)
+	}
+
+	/// Get a copy of the sub-array of length `N` starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// let sub: [u8; 2] = arr.copy_sub_array(1);
+	/// assert_eq!(sub, [8, 7]);
+	/// ```
+	fn copy_sub_array<const N: usize>(&self, offset: usize) -> [Self::Item; N]
+	where
+		Self::Item: Copy,
+	{
+		*self.sub_array_ref(offset)
+	}
+
+	/// Get a copy of the sub-array of length `N` starting at `offset`, if it
+	/// is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_copy_sub_array<const N: usize>(&self, offset: usize) -> Option<[Self::Item; N]>
+	where
+		Self::Item: Copy,
+	{
+		self.try_sub_array_ref(offset).copied()
+	}
+
+	/// Get an owning iterator over a copy of the sub-array of length `N`
+	/// starting at `offset`.
+	///
+	/// This is a shorthand for `copy_sub_array::<N>(offset).into_iter()`, e.g.
+	/// for passing the window by value to APIs expecting an iterator.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// let sum: u8 = arr.sub_array_into_iter::<3>(1).sum();
+	/// assert_eq!(sum, 21);
+	/// ```
+	#[track_caller]
+	fn sub_array_into_iter<const N: usize>(
+		&self,
+		offset: usize,
+	) -> core::array::IntoIter<Self::Item, N>
+	where
+		Self::Item: Copy,
+	{
+		self.copy_sub_array(offset).into_iter()
+	}
+
+	/// Copies the sub-array of length `N` starting at `offset` into `dst`.
+	///
+	/// This is the counterpart to [`copy_sub_array`](Self::copy_sub_array)
+	/// writing into an existing array instead of returning a new one. Since
+	/// `N` is inferred from the type of `dst`, no turbofish is needed.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	/// let mut dst = [0_u8; 3];
+	///
+	/// arr.sub_array_copy_into(2, &mut dst);
+	/// assert_eq!(dst, [7, 6, 5]);
+	/// ```
+	fn sub_array_copy_into<const N: usize>(&self, offset: usize, dst: &mut [Self::Item; N])
+	where
+		Self::Item: Copy,
+	{
+		*dst = *self.sub_array_ref(offset);
+	}
+
+	/// Copies the sub-array of length `N` starting at `offset` into `dst`, if
+	/// it is in bounds.
+	///
+	/// Returns `None` and leaves `dst` untouched if `offset + N` exceeds the
+	/// length of this array.
+	fn try_sub_array_copy_into<const N: usize>(
+		&self,
+		offset: usize,
+		dst: &mut [Self::Item; N],
+	) -> Option<()>
+	where
+		Self::Item: Copy,
+	{
+		*dst = *self.try_sub_array_ref(offset)?;
+		Some(())
+	}
+
+	/// Get a reference to a sub-array of length `N` starting at `offset`,
+	/// clamping `offset` such that the sub-array stays in bounds.
+	///
+	/// If `offset + N` exceeds the length of this array, the last `N`
+	/// elements are returned instead, i.e. the nearest valid sub-array.
+	///
+	/// # Panics
+	/// Panics if `N` exceeds the length of this array, since there is no valid
+	/// sub-array at all in that case.
+	/// See [`try_sub_array_ref_clamped`](Self::try_sub_array_ref_clamped) for
+	/// a non-panicking version.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// assert_eq!(arr.sub_array_ref_clamped::<2>(1), &[8, 7]);
+	/// assert_eq!(arr.sub_array_ref_clamped::<2>(42), &[6, 5]);
+	/// ```
+	fn sub_array_ref_clamped<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+		self.sub_array_ref(offset.min(self.len().saturating_sub(N)))
+	}
+
+	/// Get a reference to a sub-array of length `N` starting at `offset`,
+	/// clamping `offset` such that the sub-array stays in bounds, if `N` does
+	/// not exceed the length of this array.
+	///
+	/// Returns `None` if `N` exceeds the length of this array.
+	fn try_sub_array_ref_clamped<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		self.try_sub_array_ref(offset.min(self.len().checked_sub(N)?))
+	}
+
+	/// Get an iterator over the offsets of all sub-arrays equal to `pattern`.
+	///
+	/// The offsets are yielded in ascending order, and matches may overlap,
+	/// i.e. after a match at `offset`, the next candidate is `offset + 1`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 7] = [0, 1, 1, 1, 0, 1, 1];
+	///
+	/// let mut matches = arr.match_indices_sub_array(&[1, 1]);
+	/// assert_eq!(matches.next(), Some(1));
+	/// assert_eq!(matches.next(), Some(2));
+	/// assert_eq!(matches.next(), Some(5));
+	/// assert_eq!(matches.next(), None);
+	/// ```
+	fn match_indices_sub_array<'a, const N: usize>(
+		&'a self,
+		pattern: &'a [Self::Item; N],
+	) -> MatchIndices<'a, Self::Item, N>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: PartialEq,
+	{
+		MatchIndices::new(self.as_ref(), pattern)
+	}
+
+	/// Get `N` references to the elements of the sub-array of length `N`
+	/// starting at `offset`.
+	///
+	/// This is useful for APIs taking an array of references, e.g.
+	/// `[&dyn Display; N]`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use core::fmt::Display;
+	///
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 4] = [9, 8, 7, 6];
+	///
+	/// let fields: [&dyn Display; 2] = arr.sub_array_each_ref(1).map(|e| e as &dyn Display);
+	/// assert_eq!(format!("{}-{}", fields[0], fields[1]), "8-7");
+	/// ```
+	fn sub_array_each_ref<const N: usize>(&self, offset: usize) -> [&Self::Item; N] {
+		self.sub_array_ref::<N>(offset).each_ref()
+	}
+
+	/// Get `N` references to the elements of the sub-array of length `N`
+	/// starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_sub_array_each_ref<const N: usize>(&self, offset: usize) -> Option<[&Self::Item; N]> {
+		Some(self.try_sub_array_ref::<N>(offset)?.each_ref())
+	}
+
+	/// Get a reference to a sub-array of length `N` starting at `offset`,
+	/// together with the elements before and after it.
+	///
+	/// Returns `(before, sub, after)`, which are disjoint and together cover
+	/// this entire array, i.e. `before` are the elements up to `offset` and
+	/// `after` are those starting at `offset + N`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// let (before, sub, after) = arr.sub_array_ref_and_remainder::<2>(1);
+	/// assert_eq!(before, &[9]);
+	/// assert_eq!(sub, &[8, 7]);
+	/// assert_eq!(after, &[6, 5]);
+	/// ```
+	#[allow(clippy::type_complexity)]
+	#[track_caller]
+	fn sub_array_ref_and_remainder<const N: usize>(
+		&self,
+		offset: usize,
+	) -> (&[Self::Item], &[Self::Item; N], &[Self::Item])
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		let slice = self.as_ref();
+		let Some((before, rest)) = slice.split_at_checked(offset) else {
+			container::out_of_bounds(offset, N, slice.len());
+		};
+		let Some((sub, after)) = rest.split_first_chunk() else {
+			container::out_of_bounds(offset, N, slice.len());
+		};
+		(before, sub, after)
+	}
+
+	/// Get a reference to the sub-array `start..end`, which must have length
+	/// `N`.
+	///
+	/// This is useful if both `start` and `end` are computed at runtime, in
+	/// order to catch mismatches against the expected length `N`.
+	///
+	/// # Panics
+	/// Panics if `end - start` is not `N`, or if `end` exceeds the length of
+	/// this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// let sub: &[u8; 2] = arr.sub_array_ref_between(1, 3);
+	/// assert_eq!(sub, &[8, 7]);
+	/// ```
+	#[track_caller]
+	fn sub_array_ref_between<const N: usize>(&self, start: usize, end: usize) -> &[Self::Item; N] {
+		assert!(
+			end.checked_sub(start) == Some(N),
+			"sub-array range {start}..{end} does not have the expected length {N}",
+		);
+		self.sub_array_ref(start)
+	}
+
+	/// Compares the sub-array starting at `offset` against an earlier
+	/// `snapshot` of it, yielding the elements that changed.
+	///
+	/// The returned [`SubArrayDiff`] iterates over `(index, old, new)`, where
+	/// `index` is relative to `offset`, `old` is the element in `snapshot` and
+	/// `new` the one in this array. No elements are copied for this.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array, before
+	/// comparing any elements.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let snapshot: [u8; 3] = [1, 2, 3];
+	/// let arr: [u8; 5] = [0, 1, 9, 3, 0];
+	///
+	/// let mut diff = arr.diff_sub_array(1, &snapshot);
+	/// assert_eq!(diff.next(), Some((1, &2, &9)));
+	/// assert_eq!(diff.next(), None);
+	/// ```
+	fn diff_sub_array<'a, const N: usize>(
+		&'a self,
+		offset: usize,
+		snapshot: &'a [Self::Item; N],
+	) -> SubArrayDiff<'a, Self::Item, N>
+	where
+		Self::Item: PartialEq,
+	{
+		SubArrayDiff::new(snapshot, self.sub_array_ref(offset))
+	}
+
+	/// Compares the sub-array starting at `offset` against an earlier
+	/// `snapshot` of it, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`diff_sub_array`](Self::diff_sub_array).
+	fn try_diff_sub_array<'a, const N: usize>(
+		&'a self,
+		offset: usize,
+		snapshot: &'a [Self::Item; N],
+	) -> Option<SubArrayDiff<'a, Self::Item, N>>
+	where
+		Self::Item: PartialEq,
+	{
+		Some(SubArrayDiff::new(snapshot, self.try_sub_array_ref(offset)?))
+	}
+
+	/// Get the offset of the first window of length `N` that differs from its
+	/// preceding window.
+	///
+	/// This array is split into consecutive, non-overlapping windows of
+	/// length `N` starting at offset `0`, a trailing remainder shorter than `N`
+	/// is ignored. Returns the offset of the first window that is not equal
+	/// to the window directly before it, or `None` if all windows are equal.
+	/// For `N == 0`, this always returns `None`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 9] = [1, 2, 1, 2, 1, 2, 3, 3, 0];
+	///
+	/// assert_eq!(arr.sub_array_windows_dedup_position::<2>(), Some(6));
+	/// assert_eq!(arr.sub_array_windows_dedup_position::<3>(), Some(3));
+	/// assert_eq!(arr.sub_array_windows_dedup_position::<5>(), None);
+	/// ```
+	fn sub_array_windows_dedup_position<const N: usize>(&self) -> Option<usize>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: PartialEq,
+	{
+		if N == 0 {
+			return None;
+		}
+		let slice = self.as_ref();
+		slice
+			.chunks_exact(N)
+			.zip(slice.chunks_exact(N).skip(1))
+			.position(|(prev, next)| prev != next)
+			.map(|i| (i + 1) * N)
+	}
+
+	/// Get a reference to a sub-array of length `N` starting at `offset`, or
+	/// whatever is available if it is cut short.
+	///
+	/// Returns `Err` with all the elements starting at `offset` if
+	/// `offset + N` exceeds the length of this array, which is empty if
+	/// `offset` itself exceeds the length. This allows to e.g. buffer more
+	/// input or to process the partial tail.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// assert_eq!(arr.sub_array_ref_or_partial::<2>(1), Ok(&[8, 7]));
+	/// assert_eq!(arr.sub_array_ref_or_partial::<4>(3), Err(&[6, 5][..]));
+	/// assert_eq!(arr.sub_array_ref_or_partial::<4>(7), Err(&[][..]));
+	/// ```
+	fn sub_array_ref_or_partial<const N: usize>(
+		&self,
+		offset: usize,
+	) -> Result<&[Self::Item; N], &[Self::Item]>
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		match self.as_ref().get(offset..) {
+			Some(rest) => rest.first_chunk().ok_or(rest),
+			None => Err(&[]),
+		}
+	}
+
+	/// Get a reference to the sub-array described by `spec`.
+	///
+	/// This is the same as `self.sub_array_ref::<N>(spec.offset)`, see
+	/// [`SubArraySpec`] for details.
+	///
+	/// # Panics
+	/// Panics if `spec.offset + N` exceeds the length of this array.
+	fn apply_ref<const N: usize>(&self, spec: SubArraySpec<N>) -> &[Self::Item; N] {
+		self.sub_array_ref(spec.offset)
+	}
+
+	/// Get a [`WindowToken`] of the sub-array of length `N` starting at
+	/// `offset`, if it is in bounds of this array.
+	///
+	/// The token can then be applied to this array any number of times via
+	/// [`apply_window`](Self::apply_window) and
+	/// [`apply_window_mut`](SubArray::apply_window_mut), without having to handle
+	/// the out of bounds case each time.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	/// use sub_array::SubArrayRef;
+	///
+	/// let mut arr: [u8; 4] = [1, 2, 3, 4];
+	///
+	/// let counter = arr.locate_sub_array::<2>(2).unwrap();
+	/// for _ in 0..3 {
+	///     arr.apply_window_mut(counter)[1] += 1;
+	/// }
+	/// assert_eq!(arr.apply_window(counter), &[3, 7]);
+	/// assert_eq!(arr.locate_sub_array::<2>(3), None);
+	/// ```
+	fn locate_sub_array<const N: usize>(&self, offset: usize) -> Option<WindowToken<N>> {
+		WindowToken::locate(offset, self.len())
+	}
+
+	/// Get a reference to the sub-array located by `token`.
+	///
+	/// The token already proves that its offset does not overflow and that
+	/// the sub-array fits into an array of [`source_len`]. Safe code can not
+	/// skip the remaining comparison against the current length, which keeps
+	/// this sound if the array shrank in the meantime, e.g. if it is a `Vec`.
+	///
+	/// [`source_len`]: WindowToken::source_len
+	///
+	/// # Panics
+	/// Panics if this array became shorter than `token.end()` since the token
+	/// was located, which requires it to be shorter than `token.source_len()`.
+	#[track_caller]
+	fn apply_window<const N: usize>(&self, token: WindowToken<N>) -> &[Self::Item; N] {
+		self.sub_array_ref(token.offset())
+	}
+
+	/// Get the largest offset at which a sub-array of length `N` is still in
+	/// bounds, i.e. `len - N`.
+	///
+	/// This allows to clamp the offset of a sliding window, e.g. to retry a
+	/// failed extraction at the end of this array. Returns `None` if this
+	/// array is shorter than `N`, thus there is no such offset.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArray;
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let offset = 4.min(arr.max_valid_offset::<3>().unwrap());
+	/// assert_eq!(arr.sub_array_ref::<3>(offset), &[3, 4, 5]);
+	/// assert_eq!(arr.max_valid_offset::<6>(), None);
+	/// ```
+	fn max_valid_offset<const N: usize>(&self) -> Option<usize> {
+		self.len().checked_sub(N)
+	}
+
+	/// Get a reversed copy of the sub-array of length `N` starting at
+	/// `offset`.
+	///
+	/// This array itself is left untouched.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let buf: [u8; 6] = [0xFF, 0x12, 0x34, 0x56, 0x78, 0xFF];
+	///
+	/// // Swap a big-endian field to little-endian
+	/// let le: [u8; 4] = buf.sub_array_reversed(1);
+	/// assert_eq!(u32::from_le_bytes(le), 0x1234_5678);
+	/// ```
+	fn sub_array_reversed<const N: usize>(&self, offset: usize) -> [Self::Item; N]
+	where
+		Self::Item: Copy,
+	{
+		let mut reversed = self.copy_sub_array(offset);
+		reversed.reverse();
+		reversed
+	}
+
+	/// Get a byte view of the sub-array of length `N` starting at `offset`.
+	///
+	/// The returned array has length `B`, which must equal
+	/// `N * size_of::<Self::Item>()`, otherwise this fails to compile. The
+	/// bytes are in native byte order, as given by
+	/// [`bytemuck::bytes_of`].
+	///
+	/// Requires the `bytemuck` feature.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u32; 4] = [1, 2, 0x1234_5678, 4];
+	///
+	/// let bytes: &[u8; 4] = arr.sub_array_as_bytes::<1, 4>(2);
+	/// assert_eq!(bytes, &0x1234_5678_u32.to_ne_bytes());
+	/// ```
+	///
+	/// A mismatching byte length fails to compile:
+	/// ```compile_fail
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u32; 4] = [1, 2, 3, 4];
+	///
+	/// let bytes: &[u8; 5] = arr.sub_array_as_bytes::<1, 5>(2);
+	/// ```
+	#[cfg(feature = "bytemuck")]
+	fn sub_array_as_bytes<const N: usize, const B: usize>(&self, offset: usize) -> &[u8; B]
+	where
+		Self::Item: bytemuck::Pod,
+	{
+		const {
+			assert!(
+				B == N * core::mem::size_of::<Self::Item>(),
+				"the byte length must be `N * size_of::<Self::Item>()`"
+			)
+		};
+		let sub: &[Self::Item] = self.sub_array_ref::<N>(offset);
+		bytemuck::cast_slice(sub).try_into().unwrap()
+	}
+
+	/// Compares the sub-array of length `N` starting at `offset` with `other`
+	/// in constant time.
+	///
+	/// Unlike `==`, the comparison does not exit early at the first mismatch,
+	/// thus its timing does not depend on the contents of either array. This is
+	/// needed when comparing secrets, such as MAC tags. The bounds check only
+	/// depends on `offset` and the length of this array, which are usually
+	/// not secret.
+	///
+	/// Requires the `subtle` feature.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array, before
+	/// comparing anything.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let message: [u8; 6] = [b'h', b'i', 0xDE, 0xAD, 0xBE, 0xEF];
+	///
+	/// let tag = message.ct_eq_sub_array(2, &[0xDE, 0xAD, 0xBE, 0xEF]);
+	/// assert!(bool::from(tag));
+	/// ```
+	#[cfg(feature = "subtle")]
+	#[track_caller]
+	fn ct_eq_sub_array<const N: usize>(
+		&self,
+		offset: usize,
+		other: &[Self::Item; N],
+	) -> subtle::Choice
+	where
+		Self::Item: subtle::ConstantTimeEq,
+	{
+		use subtle::ConstantTimeEq;
+
+		let sub: &[Self::Item] = self.sub_array_ref::<N>(offset);
+		sub.ct_eq(other)
+	}
+
+	/// Get a reference to the sub-array of length `N` centered on the element
+	/// at index `center`.
+	///
+	/// The sub-array starts at `center - N / 2`. Thus, for an odd `N` there are
+	/// `N / 2` elements on either side of `center`, while for an even `N` there
+	/// is one more element before `center` than after it.
+	///
+	/// # Panics
+	/// Panics if the sub-array exceeds either end of this array, i.e. if
+	/// `center < N / 2` or if `center - N / 2 + N` exceeds the length of this
+	/// array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 6] = [0, 1, 2, 3, 4, 5];
+	///
+	/// assert_eq!(arr.sub_array_centered_ref::<3>(2), &[1, 2, 3]);
+	/// assert_eq!(arr.sub_array_centered_ref::<4>(2), &[0, 1, 2, 3]);
+	/// ```
+	#[track_caller]
+	fn sub_array_centered_ref<const N: usize>(&self, center: usize) -> &[Self::Item; N] {
+		let offset = center
+			.checked_sub(N / 2)
+			.expect("centered sub-array exceeds the start of the array");
+		self.sub_array_ref(offset)
+	}
+
+	/// Get a reference to the sub-array of length `N` centered on the element
+	/// at index `center`, if it is in bounds.
+	///
+	/// Returns `None` if the sub-array exceeds either end of this array.
+	/// Also see [`sub_array_centered_ref`](Self::sub_array_centered_ref).
+	fn try_sub_array_centered_ref<const N: usize>(
+		&self,
+		center: usize,
+	) -> Option<&[Self::Item; N]> {
+		self.try_sub_array_ref(center.checked_sub(N / 2)?)
+	}
+
+	/// Lexicographically compares the sub-array of length `N` starting at
+	/// `offset` with the one starting at `other_offset` in `other`.
+	///
+	/// # Panics
+	/// Panics if either `offset + N` exceeds the length of this array, or
+	/// `other_offset + N` exceeds the length of `other`.
+	///
+	/// # Example
+	/// ```
+	/// use core::cmp::Ordering;
+	///
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 4] = [1, 2, 3, 4];
+	/// let other: &[u8] = &[0, 1, 2, 4];
+	///
+	/// assert_eq!(arr.sub_array_partial_cmp::<2, _>(0, other, 1), Ordering::Equal);
+	/// assert_eq!(arr.sub_array_partial_cmp::<3, _>(0, other, 1), Ordering::Less);
+	/// ```
+	fn sub_array_partial_cmp<const N: usize, S>(
+		&self,
+		offset: usize,
+		other: &S,
+		other_offset: usize,
+	) -> Ordering
+	where
+		S: SubArray<Item = Self::Item> + ?Sized,
+		Self::Item: Ord,
+	{
+		self.sub_array_ref::<N>(offset)
+			.cmp(other.sub_array_ref::<N>(other_offset))
+	}
+
+	/// Get the offset and sum of the window of length `N` with the largest
+	/// sum.
+	///
+	/// All overlapping windows are considered, i.e. one starting at each
+	/// offset from `0` to `len - N`. The sum is updated incrementally from one
+	/// window to the next, thus this runs in `O(len)` regardless of `N`. If
+	/// several windows have the same largest sum, the first one is returned.
+	///
+	/// # Panics
+	/// Panics if this array is shorter than `N`.
+	/// Also see [`try_sub_array_windows_max_sum`](Self::try_sub_array_windows_max_sum).
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let jitter: [i32; 7] = [3, -1, 4, 1, -5, 9, 2];
+	///
+	/// assert_eq!(jitter.sub_array_windows_max_sum::<2>(), (5, 11));
+	/// assert_eq!(jitter.sub_array_windows_max_sum::<4>(), (2, 9));
+	/// ```
+	#[track_caller]
+	fn sub_array_windows_max_sum<const N: usize>(&self) -> (usize, Self::Item)
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Sum + Add<Output = Self::Item> + Sub<Output = Self::Item> + PartialOrd + Copy,
+	{
+		self.try_sub_array_windows_max_sum::<N>()
+			.expect("array is shorter than the window")
+	}
+
+	/// Get the offset and sum of the window of length `N` with the largest
+	/// sum, if there is any window.
+	///
+	/// Returns `None` if this array is shorter than `N`.
+	/// Also see [`sub_array_windows_max_sum`](Self::sub_array_windows_max_sum).
+	fn try_sub_array_windows_max_sum<const N: usize>(&self) -> Option<(usize, Self::Item)>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Sum + Add<Output = Self::Item> + Sub<Output = Self::Item> + PartialOrd + Copy,
+	{
+		self.try_sub_array_windows_min_max_sum::<N>()
+			.map(|(_, max)| max)
+	}
+
+	/// Get the offsets and sums of the windows of length `N` with the
+	/// smallest and the largest sum.
+	///
+	/// Returns `((min_offset, min_sum), (max_offset, max_sum))`, computed in a
+	/// single pass over this array. Just like
+	/// [`sub_array_windows_max_sum`](Self::sub_array_windows_max_sum), all
+	/// overlapping windows are considered and ties are resolved in favor of
+	/// the first window.
+	///
+	/// # Panics
+	/// Panics if this array is shorter than `N`.
+	/// Also see [`try_sub_array_windows_min_max_sum`](Self::try_sub_array_windows_min_max_sum).
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let returns: [i32; 6] = [2, -3, -1, 4, 5, -2];
+	///
+	/// assert_eq!(returns.sub_array_windows_min_max_sum::<2>(), ((1, -4), (3, 9)));
+	/// ```
+	#[track_caller]
+	#[allow(clippy::type_complexity)]
+	fn sub_array_windows_min_max_sum<const N: usize>(
+		&self,
+	) -> ((usize, Self::Item), (usize, Self::Item))
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Sum + Add<Output = Self::Item> + Sub<Output = Self::Item> + PartialOrd + Copy,
+	{
+		self.try_sub_array_windows_min_max_sum::<N>()
+			.expect("array is shorter than the window")
+	}
+
+	/// Get the offsets and sums of the windows of length `N` with the
+	/// smallest and the largest sum, if there is any window.
+	///
+	/// Returns `None` if this array is shorter than `N`.
+	/// Also see [`sub_array_windows_min_max_sum`](Self::sub_array_windows_min_max_sum).
+	#[allow(clippy::type_complexity)]
+	fn try_sub_array_windows_min_max_sum<const N: usize>(
+		&self,
+	) -> Option<((usize, Self::Item), (usize, Self::Item))>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Sum + Add<Output = Self::Item> + Sub<Output = Self::Item> + PartialOrd + Copy,
+	{
+		let slice = self.as_ref();
+		let first: &[Self::Item; N] = slice.first_chunk()?;
+
+		let mut sum: Self::Item = first.iter().copied().sum();
+		let mut min = (0, sum);
+		let mut max = (0, sum);
+		if N == 0 {
+			// All empty windows have the same sum
+			return Some((min, max));
+		}
+		// Slide the window by one, dropping `outgoing` and adding `incoming`
+		for (i, (&outgoing, &incoming)) in slice.iter().zip(&slice[N..]).enumerate() {
+			sum = sum - outgoing + incoming;
+			if sum < min.1 {
+				min = (i + 1, sum);
+			}
+			if sum > max.1 {
+				max = (i + 1, sum);
+			}
+		}
+		Some((min, max))
+	}
+
+	/// Lexicographically compares the two sub-arrays of length `N` starting at
+	/// `offset_a` and `offset_b`.
+	///
+	/// This compares the regions in-place, without copying them, which is
+	/// useful as a key comparison when sorting fixed-size records.
+	/// Also see [`sub_array_partial_cmp`](Self::sub_array_partial_cmp) to
+	/// compare against another array.
+	///
+	/// # Panics
+	/// Panics if either `offset_a + N` or `offset_b + N` exceeds the length of
+	/// this array.
+	///
+	/// # Example
+	/// ```
+	/// use core::cmp::Ordering;
+	///
+	/// use sub_array::SubArrayRef;
+	///
+	/// let records: [u8; 6] = [3, 1, 2, 7, 3, 0];
+	///
+	/// assert_eq!(records.cmp_sub_arrays::<2>(0, 4), Ordering::Greater);
+	/// assert_eq!(records.cmp_sub_arrays::<1>(0, 4), Ordering::Equal);
+	/// ```
+	fn cmp_sub_arrays<const N: usize>(&self, offset_a: usize, offset_b: usize) -> Ordering
+	where
+		Self::Item: Ord,
+	{
+		self.sub_array_ref::<N>(offset_a)
+			.cmp(self.sub_array_ref::<N>(offset_b))
+	}
+
+	/// Get an iterator over the hashes of all windows of length `N`.
+	///
+	/// The hasher is created once by `hasher_factory`, for the first window,
+	/// and then updated incrementally by removing the outgoing and adding the
+	/// incoming element of each subsequent window. Thus, this runs in
+	/// `O(len)` regardless of `N`. The windows overlap, i.e. there is one
+	/// starting at each offset from `0` to `len - N`, and none if this array
+	/// is shorter than `N`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::PolynomialHash;
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 4] = [1, 2, 3, 4];
+	///
+	/// let mut hashes = arr.sub_array_rolling_hash::<2, _>(|| PolynomialHash::new(10, 1000));
+	/// assert_eq!(hashes.next(), Some(12));
+	/// assert_eq!(hashes.next(), Some(23));
+	/// assert_eq!(hashes.next(), Some(34));
+	/// assert_eq!(hashes.next(), None);
+	/// ```
+	fn sub_array_rolling_hash<const N: usize, H>(
+		&self,
+		hasher_factory: impl FnOnce() -> H,
+	) -> RollingHashes<'_, Self::Item, H, N>
+	where
+		Self: AsRef<[Self::Item]>,
+		H: RollingHasher<Self::Item>,
+	{
+		RollingHashes::new(self.as_ref(), hasher_factory())
+	}
+
+	/// Get an iterator over the polynomial hashes of all windows of length
+	/// `N`.
+	///
+	/// This is [`sub_array_rolling_hash`](Self::sub_array_rolling_hash) using
+	/// a [`PolynomialHash`] with the given `base` and `modulus`.
+	///
+	/// # Panics
+	/// Panics if `modulus` is zero.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let text = *b"abcabc";
+	/// let pattern = text.sub_array_polynomial_rolling_hash::<3>(256, 1_000_003).next();
+	///
+	/// let found: Vec<usize> = text
+	///     .sub_array_polynomial_rolling_hash::<3>(256, 1_000_003)
+	///     .enumerate()
+	///     .filter_map(|(offset, hash)| (Some(hash) == pattern).then_some(offset))
+	///     .collect();
+	/// assert_eq!(found, [0, 3]);
+	/// ```
+	fn sub_array_polynomial_rolling_hash<const N: usize>(
+		&self,
+		base: u64,
+		modulus: u64,
+	) -> RollingHashes<'_, Self::Item, PolynomialHash, N>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Copy + Into<u64>,
+	{
+		self.sub_array_rolling_hash(|| PolynomialHash::new(base, modulus))
+	}
+
+	/// Get a view of the `N` elements starting at `start`, wrapping around
+	/// the end of this array.
+	///
+	/// The elements are not copied, instead the [`RingWindow`] computes their
+	/// indices modulo the length of this array on access. Thus, `start` may
+	/// be any value, and `N` may even exceed the length of this array.
+	///
+	/// # Panics
+	/// Panics if this array is empty.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let ring: [u8; 5] = [0, 1, 2, 3, 4];
+	///
+	/// let window = ring.ring_window::<3>(4);
+	/// assert_eq!(window[0], 4);
+	/// assert!(window.iter().eq(&[4, 0, 1]));
+	/// ```
+	#[track_caller]
+	fn ring_window<const N: usize>(&self, start: usize) -> RingWindow<'_, Self::Item, N>
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		RingWindow::new(self.as_ref(), start)
+	}
+
+	/// Get a [`SubArrayHandle`] to the sub-array of length `N` starting at
+	/// `offset`.
+	///
+	/// The handle dereferences to the sub-array, just like the reference
+	/// returned by [`sub_array_ref`](SubArray::sub_array_ref), but also exposes
+	/// `N` as the associated const [`SubArrayHandle::LEN`].
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	/// use sub_array::SubArrayHandle;
+	///
+	/// let arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let handle: SubArrayHandle<u8, 2> = arr.sub_array_handle(3);
+	/// assert_eq!(*handle, [4, 5]);
+	/// assert_eq!(SubArrayHandle::<u8, 2>::LEN, 2);
+	/// ```
+	fn sub_array_handle<const N: usize>(&self, offset: usize) -> SubArrayHandle<'_, Self::Item, N> {
+		SubArrayHandle::new(self.sub_array_ref(offset))
+	}
+
+	/// Get a [`SubArrayHandle`] to the sub-array of length `N` starting at
+	/// `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	fn try_sub_array_handle<const N: usize>(
+		&self,
+		offset: usize,
+	) -> Option<SubArrayHandle<'_, Self::Item, N>> {
+		self.try_sub_array_ref(offset).map(SubArrayHandle::new)
+	}
+
+	/// Get the offsets of all occurrences of `pattern` in this array.
+	///
+	/// Matches may overlap, e.g. the pattern `[1, 1]` occurs at the offsets
+	/// `0` and `1` in `[1, 1, 1]`. This collects
+	/// [`match_indices_sub_array`](Self::match_indices_sub_array), which
+	/// avoids the allocation.
+	///
+	/// Requires the `alloc` feature.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let stream: [u8; 8] = [0xFF, 0xD8, 1, 2, 0xFF, 0xD8, 0xFF, 0xD8];
+	///
+	/// assert_eq!(stream.find_all_sub_arrays(&[0xFF, 0xD8]), [0, 4, 6]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	fn find_all_sub_arrays<const N: usize>(
+		&self,
+		pattern: &[Self::Item; N],
+	) -> alloc::vec::Vec<usize>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: PartialEq,
+	{
+		self.match_indices_sub_array(pattern).collect()
+	}
+
+	/// Get a clone of the smallest element of the sub-array of length `N`
+	/// starting at `offset`.
+	///
+	/// If several elements are equally minimal, the first one is returned.
+	/// Fails to compile if `N == 0`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [1, 7, 3, 9, 0];
+	///
+	/// assert_eq!(arr.sub_array_min::<3>(1), 3);
+	/// assert_eq!(arr.sub_array_max::<3>(1), 9);
+	/// assert_eq!(arr.sub_array_sum::<3>(1), 19);
+	/// ```
+	fn sub_array_min<const N: usize>(&self, offset: usize) -> Self::Item
+	where
+		Self::Item: Ord + Clone,
+	{
+		const { assert!(N > 0, "the minimum of an empty sub-array is undefined") };
+		self.sub_array_ref::<N>(offset)
+			.iter()
+			.min()
+			.unwrap()
+			.clone()
+	}
+
+	/// Get a clone of the smallest element of the sub-array of length `N`
+	/// starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`sub_array_min`](Self::sub_array_min).
+	fn try_sub_array_min<const N: usize>(&self, offset: usize) -> Option<Self::Item>
+	where
+		Self::Item: Ord + Clone,
+	{
+		const { assert!(N > 0, "the minimum of an empty sub-array is undefined") };
+		self.try_sub_array_ref::<N>(offset)?.iter().min().cloned()
+	}
+
+	/// Get a clone of the largest element of the sub-array of length `N`
+	/// starting at `offset`.
+	///
+	/// If several elements are equally maximal, the last one is returned.
+	/// Fails to compile if `N == 0`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	fn sub_array_max<const N: usize>(&self, offset: usize) -> Self::Item
+	where
+		Self::Item: Ord + Clone,
+	{
+		const { assert!(N > 0, "the maximum of an empty sub-array is undefined") };
+		self.sub_array_ref::<N>(offset)
+			.iter()
+			.max()
+			.unwrap()
+			.clone()
+	}
+
+	/// Get a clone of the largest element of the sub-array of length `N`
+	/// starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`sub_array_max`](Self::sub_array_max).
+	fn try_sub_array_max<const N: usize>(&self, offset: usize) -> Option<Self::Item>
+	where
+		Self::Item: Ord + Clone,
+	{
+		const { assert!(N > 0, "the maximum of an empty sub-array is undefined") };
+		self.try_sub_array_ref::<N>(offset)?.iter().max().cloned()
+	}
+
+	/// Sums up the elements of the sub-array of length `N` starting at
+	/// `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array, or if the
+	/// summation overflows, just like [`Iterator::sum`].
+	fn sub_array_sum<const N: usize>(&self, offset: usize) -> Self::Item
+	where
+		Self::Item: for<'a> Sum<&'a Self::Item>,
+	{
+		self.sub_array_ref::<N>(offset).iter().sum()
+	}
+
+	/// Sums up the elements of the sub-array of length `N` starting at
+	/// `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`sub_array_sum`](Self::sub_array_sum).
+	fn try_sub_array_sum<const N: usize>(&self, offset: usize) -> Option<Self::Item>
+	where
+		Self::Item: for<'a> Sum<&'a Self::Item>,
+	{
+		Some(self.try_sub_array_ref::<N>(offset)?.iter().sum())
+	}
+
+	/// Folds the elements of the sub-array of length `N` starting at `offset`
+	/// into an accumulator, starting with `init`.
+	///
+	/// This is like [`Iterator::fold`] on the sub-array, with a single bounds
+	/// check upfront.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// // A ones' complement checksum of the 16-bit words after the header
+	/// let packet: [u16; 4] = [0x4500, 0xFFFF, 0x0002, 0x0001];
+	///
+	/// let sum = packet.fold_sub_array::<3, _>(1, 0_u32, |acc, &word| {
+	///     let acc = acc + u32::from(word);
+	///     (acc & 0xFFFF) + (acc >> 16)
+	/// });
+	/// assert_eq!(sum, 0x0003);
+	/// ```
+	fn fold_sub_array<const N: usize, A>(
+		&self,
+		offset: usize,
+		init: A,
+		f: impl FnMut(A, &Self::Item) -> A,
+	) -> A {
+		self.sub_array_ref::<N>(offset).iter().fold(init, f)
+	}
+
+	/// Folds the elements of the sub-array of length `N` starting at `offset`
+	/// into an accumulator, starting with `init`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`fold_sub_array`](Self::fold_sub_array).
+	fn try_fold_sub_array<const N: usize, A>(
+		&self,
+		offset: usize,
+		init: A,
+		f: impl FnMut(A, &Self::Item) -> A,
+	) -> Option<A> {
+		Some(self.try_sub_array_ref::<N>(offset)?.iter().fold(init, f))
+	}
+
+	/// XORs all the elements of the sub-array of length `N` starting at
+	/// `offset`.
+	///
+	/// This is e.g. the longitudinal redundancy check of bytes. The empty
+	/// sub-array yields the [`Default`] value, i.e. zero for integers. Also see
+	/// [`sub_array_sum`](Self::sub_array_sum).
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let frame: [u8; 5] = [0x02, 0x10, 0x20, 0x03, 0x33];
+	///
+	/// assert_eq!(frame.xor_sub_array::<3>(1), frame[4]);
+	/// ```
+	fn xor_sub_array<const N: usize>(&self, offset: usize) -> Self::Item
+	where
+		Self::Item: Copy + Default + BitXor<Output = Self::Item>,
+	{
+		self.fold_sub_array::<N, _>(offset, Default::default(), |acc, &x| acc ^ x)
+	}
+
+	/// XORs all the elements of the sub-array of length `N` starting at
+	/// `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`xor_sub_array`](Self::xor_sub_array).
+	fn try_xor_sub_array<const N: usize>(&self, offset: usize) -> Option<Self::Item>
+	where
+		Self::Item: Copy + Default + BitXor<Output = Self::Item>,
+	{
+		self.try_fold_sub_array::<N, _>(offset, Default::default(), |acc, &x| acc ^ x)
+	}
+
+	/// Get an iterator over the segments of this array that are separated by
+	/// `delimiter`.
+	///
+	/// This is like [`slice::split`], but with a delimiter of `N` elements,
+	/// see [`SplitBySubArray`] for details. Fails to compile if `N == 0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let records = *b"ab--cd----e";
+	///
+	/// let segments: Vec<&[u8]> = records.split_by_sub_array(b"--").collect();
+	/// assert_eq!(segments, [&b"ab"[..], b"cd", b"", b"e"]);
+	/// ```
+	fn split_by_sub_array<'a, const N: usize>(
+		&'a self,
+		delimiter: &'a [Self::Item; N],
+	) -> SplitBySubArray<'a, Self::Item, N>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: PartialEq,
+	{
+		SplitBySubArray::new(self.as_ref(), delimiter)
+	}
+
+	/// Get the partition point of the sub-array of length `N` starting at
+	/// `offset`, i.e. the index of its first element for which `pred` returns
+	/// `false`.
+	///
+	/// This delegates to [`slice::partition_point`] on the sub-array, thus the
+	/// sub-array must be partitioned according to `pred`, and the returned
+	/// index is relative to `offset`. With `|x| x < &value` on a sorted
+	/// sub-array, this is the index at which `value` can be inserted while
+	/// keeping it sorted.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 6] = [9, 1, 3, 3, 7, 0];
+	///
+	/// assert_eq!(arr.sub_array_partition_point::<4, _>(1, |&x| x < 3), 1);
+	/// assert_eq!(arr.sub_array_partition_point::<4, _>(1, |&x| x <= 3), 3);
+	/// assert_eq!(arr.sub_array_partition_point::<4, _>(1, |&x| x < 8), 4);
+	/// ```
+	fn sub_array_partition_point<const N: usize, F>(&self, offset: usize, pred: F) -> usize
+	where
+		F: FnMut(&Self::Item) -> bool,
+	{
+		self.sub_array_ref::<N>(offset).partition_point(pred)
+	}
+
+	/// Get an array of copies of the elements at the given `indices`.
+	///
+	/// The indices may be in any order and may repeat, e.g. to apply a
+	/// permutation or an S-box.
+	///
+	/// # Panics
+	/// Panics if any of the `indices` is out of bounds of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 4] = [10, 11, 12, 13];
+	///
+	/// assert_eq!(arr.gather_sub_array(&[3, 0, 0, 2]), [13, 10, 10, 12]);
+	/// ```
+	fn gather_sub_array<const N: usize>(&self, indices: &[usize; N]) -> [Self::Item; N]
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Copy,
+	{
+		let slice = self.as_ref();
+		indices.map(|i| slice[i])
+	}
+
+	/// Get an array of copies of the elements at the given `indices`, if they
+	/// are all in bounds.
+	///
+	/// Returns `None` if any of the `indices` is out of bounds of this array.
+	/// Also see [`gather_sub_array`](Self::gather_sub_array).
+	fn try_gather_sub_array<const N: usize>(&self, indices: &[usize; N]) -> Option<[Self::Item; N]>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Copy,
+	{
+		let slice = self.as_ref();
+		if indices.iter().all(|&i| i < slice.len()) {
+			Some(indices.map(|i| slice[i]))
+		} else {
+			None
+		}
+	}
+
+	/// Get an iterator over the runs of consecutive elements of the sub-array
+	/// of length `N` starting at `offset`, where `pred` holds for each pair of
+	/// adjacent elements within a run.
+	///
+	/// This delegates to [`slice::chunk_by`] on the sub-array, thus every
+	/// element of the sub-array is part of exactly one run, and runs are never
+	/// empty.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr = *b"#aaabcc#";
+	///
+	/// let runs: Vec<&[u8]> = arr.sub_array_group_by::<6, _>(1, |a, b| a == b).collect();
+	/// assert_eq!(runs, [&b"aaa"[..], b"b", b"cc"]);
+	/// ```
+	fn sub_array_group_by<const N: usize, F>(
+		&self,
+		offset: usize,
+		pred: F,
+	) -> ChunkBy<'_, Self::Item, F>
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> bool,
+	{
+		self.sub_array_ref::<N>(offset).chunk_by(pred)
+	}
+
+	/// Get an iterator over the runs of consecutive elements of the sub-array
+	/// of length `N` starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`sub_array_group_by`](Self::sub_array_group_by).
+	fn try_sub_array_group_by<const N: usize, F>(
+		&self,
+		offset: usize,
+		pred: F,
+	) -> Option<ChunkBy<'_, Self::Item, F>>
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> bool,
+	{
+		Some(self.try_sub_array_ref::<N>(offset)?.chunk_by(pred))
+	}
+
+	/// Get an iterator over the `count` consecutive sub-arrays of length `N`
+	/// starting at `start`.
+	///
+	/// This matches the common layout of a record count, followed by that many
+	/// fixed-size records. Fails to compile if `N == 0`.
+	///
+	/// # Panics
+	/// Panics if `start + count * N` exceeds the length of this array,
+	/// including if it overflows `usize`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// // A count of 2, followed by 2 records of 3 bytes each
+	/// let buf: [u8; 8] = [2, b'a', b'b', b'c', b'x', b'y', b'z', 0xFF];
+	///
+	/// let count = usize::from(buf[0]);
+	/// let records: Vec<&[u8; 3]> = buf.sub_array_records(1, count).collect();
+	/// assert_eq!(records, [b"abc", b"xyz"]);
+	/// ```
+	#[track_caller]
+	fn sub_array_records<const N: usize>(
+		&self,
+		start: usize,
+		count: usize,
+	) -> core::slice::Iter<'_, [Self::Item; N]>
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		match self.try_sub_array_records(start, count) {
+			Some(records) => records,
+			None => container::out_of_bounds(start, count.saturating_mul(N), self.len()),
+		}
+	}
+
+	/// Get an iterator over the `count` consecutive sub-arrays of length `N`
+	/// starting at `start`, if they are all in bounds.
+	///
+	/// Returns `None` if `start + count * N` exceeds the length of this array.
+	/// Also see [`sub_array_records`](Self::sub_array_records).
+	fn try_sub_array_records<const N: usize>(
+		&self,
+		start: usize,
+		count: usize,
+	) -> Option<core::slice::Iter<'_, [Self::Item; N]>>
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		const { assert!(N > 0, "the records must not be empty") };
+		let len = count.checked_mul(N)?;
+		let region = self.as_ref().get(start..)?.get(..len)?;
+		let (records, _) = region.as_chunks::<N>();
+		Some(records.iter())
+	}
+
+	/// Get an iterator decoding each of the `count` consecutive sub-arrays of
+	/// length `N` starting at `start` via `decode`.
+	///
+	/// This is the same as mapping over
+	/// [`sub_array_records`](Self::sub_array_records), thus all records are
+	/// bounds checked once up front, and `decode` is called lazily. Also see
+	/// [`records_mut`](SubArray::records_mut). Fails to compile if `N == 0`.
+	///
+	/// # Panics
+	/// Panics if `start + count * N` exceeds the length of this array,
+	/// including if it overflows `usize`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// // A magic byte, followed by 2 big-endian `u16`
+	/// let buf: [u8; 5] = [0xAA, 0x01, 0x02, 0x03, 0x04];
+	///
+	/// let values: Vec<u16> = buf.records(1, 2, |raw| u16::from_be_bytes(*raw)).collect();
+	/// assert_eq!(values, [0x0102, 0x0304]);
+	/// ```
+	#[track_caller]
+	fn records<const N: usize, R, F>(
+		&self,
+		start: usize,
+		count: usize,
+		decode: F,
+	) -> core::iter::Map<core::slice::Iter<'_, [Self::Item; N]>, F>
+	where
+		Self: AsRef<[Self::Item]>,
+		F: FnMut(&[Self::Item; N]) -> R,
+	{
+		match self.try_records(start, count, decode) {
+			Some(records) => records,
+			None => container::out_of_bounds(start, count.saturating_mul(N), self.len()),
+		}
+	}
+
+	/// Get an iterator decoding each of the `count` consecutive sub-arrays of
+	/// length `N` starting at `start` via `decode`, if they are all in bounds.
+	///
+	/// Returns `None` if `start + count * N` exceeds the length of this array.
+	/// Also see [`records`](Self::records).
+	fn try_records<const N: usize, R, F>(
+		&self,
+		start: usize,
+		count: usize,
+		decode: F,
+	) -> Option<core::iter::Map<core::slice::Iter<'_, [Self::Item; N]>, F>>
+	where
+		Self: AsRef<[Self::Item]>,
+		F: FnMut(&[Self::Item; N]) -> R,
+	{
+		Some(self.try_sub_array_records(start, count)?.map(decode))
+	}
+
+	/// Get a reference to the sub-array of length `N` starting at `offset`,
+	/// if all of its elements are valid.
+	///
+	/// The predicate `valid` is called with each element of the sub-array,
+	/// in order, along with its index within the sub-array, until it returns
+	/// `false` for one. It is not called at all if the sub-array is out of
+	/// bounds. Also see [`validate_sub_array_with`](Self::validate_sub_array_with),
+	/// which allows to return a custom error.
+	///
+	/// # Errors
+	/// Returns [`ValidateError::OutOfBounds`] if `offset + N` exceeds the
+	/// length of this array, and [`ValidateError::Invalid`] with a reference
+	/// to the first element for which `valid` returned `false`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	/// use sub_array::ValidateError;
+	///
+	/// let arr: [u8; 6] = *b"ab\x00cd?";
+	///
+	/// assert_eq!(arr.validate_sub_array::<2>(3, |_, b| b.is_ascii_lowercase()), Ok(b"cd"));
+	/// assert_eq!(
+	///     arr.validate_sub_array::<3>(0, |_, b| b.is_ascii_lowercase()),
+	///     Err(ValidateError::Invalid { index: 2, offset: 2, error: &0 })
+	/// );
+	/// ```
+	fn validate_sub_array<const N: usize>(
+		&self,
+		offset: usize,
+		mut valid: impl FnMut(usize, &Self::Item) -> bool,
+	) -> Result<&[Self::Item; N], ValidateError<&Self::Item>> {
+		self.validate_sub_array_with(
+			offset,
+			|i, elem| {
+				if valid(i, elem) {
+					Ok(())
+				} else {
+					Err(elem)
+				}
+			},
+		)
+	}
+
+	/// Get a reference to the sub-array of length `N` starting at `offset`,
+	/// if the validator accepts all of its elements.
+	///
+	/// The validator `check` is called with each element of the sub-array,
+	/// in order, along with its index within the sub-array, until it returns
+	/// an error for one. It is not called at all if the sub-array is out of
+	/// bounds.
+	///
+	/// # Errors
+	/// Returns [`ValidateError::OutOfBounds`] if `offset + N` exceeds the
+	/// length of this array, and [`ValidateError::Invalid`] with the first
+	/// error returned by `check`.
+	fn validate_sub_array_with<'a, const N: usize, E>(
+		&'a self,
+		offset: usize,
+		mut check: impl FnMut(usize, &'a Self::Item) -> Result<(), E>,
+	) -> Result<&'a [Self::Item; N], ValidateError<E>> {
+		let sub = self
+			.try_sub_array_ref::<N>(offset)
+			.ok_or(ValidateError::OutOfBounds)?;
+		for (index, elem) in sub.iter().enumerate() {
+			check(index, elem).map_err(|error| {
+				ValidateError::Invalid {
+					index,
+					offset: offset + index,
+					error,
+				}
+			})?;
+		}
+		Ok(sub)
+	}
+
+	/// Get an iterator over the consecutive, non-overlapping sub-arrays of
+	/// length `N`, starting at the beginning of this array.
+	///
+	/// The trailing elements that do not fill a whole sub-array are not
+	/// yielded, see [`SubArrayChunks`] for details. Fails to compile if
+	/// `N == 0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let mut chunks = arr.sub_array_chunks::<2>();
+	/// assert_eq!(chunks.next(), Some(&[1, 2]));
+	/// assert_eq!(chunks.next(), Some(&[3, 4]));
+	/// assert_eq!(chunks.next(), None);
+	/// assert_eq!(chunks.remainder(), &[5]);
+	/// ```
+	fn sub_array_chunks<const N: usize>(&self) -> SubArrayChunks<'_, Self::Item, N>
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		SubArrayChunks::new(self.as_ref())
+	}
+
+	/// Get an iterator applying `f` to the corresponding consecutive,
+	/// non-overlapping sub-arrays of length `N` of this array and `other`.
+	///
+	/// This compares two arrays window by window, e.g. two audio streams,
+	/// yielding one result of `f` per window, see [`WindowsCompare`] for
+	/// details. Fails to compile if `N == 0`.
+	///
+	/// # Panics
+	/// Panics if this array and `other` have different lengths.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let a: [u8; 6] = [1, 2, 3, 4, 5, 6];
+	/// let b: [u8; 6] = [1, 2, 3, 0, 5, 0];
+	///
+	/// let equal: Vec<bool> = a.sub_array_windows_compare::<2, _, _, _>(&b, |x, y| x == y).collect();
+	/// assert_eq!(equal, [true, false, false]);
+	/// ```
+	#[track_caller]
+	fn sub_array_windows_compare<'a, const N: usize, U, F, S2>(
+		&'a self,
+		other: &'a S2,
+		f: F,
+	) -> WindowsCompare<'a, Self::Item, F, N>
+	where
+		Self: AsRef<[Self::Item]>,
+		S2: SubArray<Item = Self::Item> + AsRef<[Self::Item]> + ?Sized,
+		F: FnMut(&'a [Self::Item; N], &'a [Self::Item; N]) -> U,
+	{
+		WindowsCompare::new(self.as_ref(), other.as_ref(), f)
+	}
+
+	/// Get an iterator over the consecutive, non-overlapping sub-arrays of
+	/// length `N`, along with their offsets.
+	///
+	/// This yields `(offset, sub_array)` for each offset that is a multiple of
+	/// `N` and where `offset + N` is in bounds, see [`SubArrayIter`] for
+	/// details. Fails to compile if `N == 0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let subs: Vec<(usize, &[u8; 2])> = arr.sub_array_iter().collect();
+	/// assert_eq!(subs, [(0, &[1, 2]), (2, &[3, 4])]);
+	/// ```
+	fn sub_array_iter<const N: usize>(&self) -> SubArrayIter<'_, Self::Item, N>
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		SubArrayIter::new(self.as_ref())
+	}
+
+	/// Folds the consecutive, non-overlapping sub-arrays of length `N` into
+	/// an accumulator of the same type, via `mul`.
+	///
+	/// This is like [`Iterator::fold`] over [`sub_array_chunks`](Self::sub_array_chunks),
+	/// with `[T; N]` as both the accumulator and the input type, e.g. for the
+	/// element-wise product of blocks in a finite field. The trailing elements
+	/// that do not fill a whole sub-array are ignored. Fails to compile if
+	/// `N == 0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let blocks: [u32; 6] = [1, 2, 3, 4, 5, 6];
+	///
+	/// let product = blocks.sub_array_chunks_product([1, 1], |acc, block| {
+	///     [acc[0] * block[0], acc[1] * block[1]]
+	/// });
+	/// assert_eq!(product, [1 * 3 * 5, 2 * 4 * 6]);
+	/// ```
+	fn sub_array_chunks_product<const N: usize, F>(
+		&self,
+		init: [Self::Item; N],
+		mut mul: F,
+	) -> [Self::Item; N]
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Copy,
+		F: FnMut([Self::Item; N], [Self::Item; N]) -> [Self::Item; N],
+	{
+		self.sub_array_chunks::<N>()
+			.fold(init, |acc, block| mul(acc, *block))
+	}
+
+	/// Writes the result of `f` on each of the consecutive, non-overlapping
+	/// sub-arrays of length `N` into `out`, returning how many were written.
+	///
+	/// This stops at whichever is exhausted first, the whole sub-arrays of
+	/// this array, see [`sub_array_chunks`](Self::sub_array_chunks), or `out`,
+	/// thus it never allocates. The elements of `out` beyond the returned
+	/// count are left untouched. Fails to compile if `N == 0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 7] = [1, 2, 3, 4, 5, 6, 7];
+	///
+	/// let mut sums = [0_u8; 4];
+	/// let count = arr.sub_array_chunks_collect_map::<2, _, _>(&mut sums, |[a, b]| a + b);
+	/// assert_eq!(count, 3);
+	/// assert_eq!(sums, [3, 7, 11, 0]);
+	/// ```
+	fn sub_array_chunks_collect_map<const N: usize, U, F>(&self, out: &mut [U], mut f: F) -> usize
+	where
+		Self: AsRef<[Self::Item]>,
+		F: FnMut(&[Self::Item; N]) -> U,
+	{
+		let mut count = 0;
+		for (slot, chunk) in out.iter_mut().zip(self.sub_array_chunks::<N>()) {
+			*slot = f(chunk);
+			count += 1;
+		}
+		count
+	}
+
+	/// Copies the `K * N` elements starting at `offset` into `N` arrays of
+	/// length `K` each.
+	///
+	/// This reshapes a flat region into a two-dimensional array, e.g. rows of
+	/// a matrix or pixels of several channels each. Fails to compile if
+	/// `K == 0`.
+	///
+	/// # Panics
+	/// Panics if `offset + K * N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// // Two RGB pixels after a header byte
+	/// let buf: [u8; 7] = [0xFF, 1, 2, 3, 4, 5, 6];
+	///
+	/// let pixels: [[u8; 3]; 2] = buf.regroup(1);
+	/// assert_eq!(pixels, [[1, 2, 3], [4, 5, 6]]);
+	/// ```
+	#[track_caller]
+	fn regroup<const K: usize, const N: usize>(&self, offset: usize) -> [[Self::Item; K]; N]
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Copy,
+	{
+		match self.try_regroup(offset) {
+			Some(groups) => groups,
+			None => container::out_of_bounds(offset, K.saturating_mul(N), self.len()),
+		}
+	}
+
+	/// Copies the `K * N` elements starting at `offset` into `N` arrays of
+	/// length `K` each, if they are in bounds.
+	///
+	/// Returns `None` if `offset + K * N` exceeds the length of this array.
+	/// Also see [`regroup`](Self::regroup).
+	fn try_regroup<const K: usize, const N: usize>(
+		&self,
+		offset: usize,
+	) -> Option<[[Self::Item; K]; N]>
+	where
+		Self: AsRef<[Self::Item]>,
+		Self::Item: Copy,
+	{
+		const { assert!(K > 0, "the groups must not be empty") };
+		let (groups, _) = self.as_ref().get(offset..)?.as_chunks::<K>();
+		groups.first_chunk::<N>().copied()
+	}
+
+	/// Copies the `N` arrays of length `K` starting at `offset` of this array
+	/// of arrays into a single flat array of length `KN`.
+	///
+	/// This is the inverse of [`regroup`](Self::regroup), e.g. to pass rows
+	/// of a matrix to an API expecting a flat buffer. `KN` must equal
+	/// `K * N`, otherwise this fails to compile.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let pixels: [[u8; 3]; 3] = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+	///
+	/// let flat: [u8; 6] = pixels.flatten_window_copy::<_, 3, 2, 6>(1);
+	/// assert_eq!(flat, [4, 5, 6, 7, 8, 9]);
+	/// ```
+	///
+	/// A mismatching flat length fails to compile:
+	/// ```compile_fail
+	/// use sub_array::SubArrayRef;
+	///
+	/// let pixels: [[u8; 3]; 3] = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+	///
+	/// let flat: [u8; 5] = pixels.flatten_window_copy::<_, 3, 2, 5>(1);
+	/// ```
+	#[track_caller]
+	fn flatten_window_copy<U, const K: usize, const N: usize, const KN: usize>(
+		&self,
+		offset: usize,
+	) -> [U; KN]
+	where
+		Self: SubArrayRef<Item = [U; K]>,
+		U: Copy,
+	{
+		match self.try_flatten_window_copy::<U, K, N, KN>(offset) {
+			Some(flat) => flat,
+			None => container::out_of_bounds(offset, N, self.len()),
+		}
+	}
+
+	/// Copies the `N` arrays of length `K` starting at `offset` of this array
+	/// of arrays into a single flat array of length `KN`, if they are in
+	/// bounds.
+	///
+	/// Returns `None` if `offset + N` exceeds the length of this array.
+	/// Also see [`flatten_window_copy`](Self::flatten_window_copy).
+	fn try_flatten_window_copy<U, const K: usize, const N: usize, const KN: usize>(
+		&self,
+		offset: usize,
+	) -> Option<[U; KN]>
+	where
+		Self: SubArrayRef<Item = [U; K]>,
+		U: Copy,
+	{
+		const { assert!(KN == K * N, "the flat length must be `K * N`") };
+		let rows = self.try_sub_array_ref::<N>(offset)?;
+		Some(rows.as_flattened().try_into().unwrap())
+	}
+
+	/// Get a reference to the sub-array of length `N` starting at `offset`, if
+	/// it is in bounds, logging a warning via `defmt` otherwise.
+	///
+	/// This is the same as [`try_sub_array_ref`](SubArray::try_sub_array_ref),
+	/// but if the sub-array is out of bounds, the warning includes `offset`,
+	/// `N` and the length of this array, which helps to diagnose parsing
+	/// issues on embedded targets without panicking. As with all `defmt`
+	/// logs, the warning is only emitted if enabled via `DEFMT_LOG`, e.g. by
+	/// building with `DEFMT_LOG=warn`.
+	///
+	/// Requires the `defmt` feature.
+	#[cfg(feature = "defmt")]
+	fn sub_array_ref_logged<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		let sub = self.try_sub_array_ref(offset);
+		if sub.is_none() {
+			defmt::warn!(
+				"sub-array of length {=usize} at offset {=usize} exceeds the array of length \
+				 {=usize}",
+				N,
+				offset,
+				self.len(),
+			);
+		}
+		sub
+	}
+
+	/// Get a reference to the sub-array of length `N` starting at `offset`,
+	/// only checking the bounds in debug builds.
+	///
+	/// This trades safety for performance in release builds, where the bounds
+	/// check is elided, e.g. in hot loops, where the compiler can not prove
+	/// the offsets to be in bounds. Prefer [`sub_array_ref`](SubArray::sub_array_ref),
+	/// whose bounds check is usually cheap or elided by the compiler anyway.
+	///
+	/// Requires the `release-unchecked` feature, which contains `unsafe` code.
+	///
+	/// # Safety
+	/// `offset + N` must not exceed the length of this array. Otherwise, this
+	/// panics in debug builds, but is undefined behavior in release builds.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SubArrayRef;
+	///
+	/// let arr: [u8; 5] = [9, 8, 7, 6, 5];
+	///
+	/// // SAFETY: 3 + 2 does not exceed the length 5
+	/// let sub: &[u8; 2] = unsafe { arr.sub_array_ref_unchecked(3) };
+	/// assert_eq!(sub, &[6, 5]);
+	/// ```
+	#[cfg(feature = "release-unchecked")]
+	#[allow(unsafe_code)]
+	#[track_caller]
+	unsafe fn sub_array_ref_unchecked<const N: usize>(&self, offset: usize) -> &[Self::Item; N]
+	where
+		Self: AsRef<[Self::Item]>,
+	{
+		let slice = self.as_ref();
+		if cfg!(debug_assertions) && offset.checked_add(N).is_none_or(|end| end > slice.len()) {
+			container::out_of_bounds(offset, N, slice.len());
+		}
+		// SAFETY: the caller guarantees that the `N` elements starting at
+		// `offset` are in bounds of `slice`, thus they are a valid `[T; N]`.
+		unsafe { &*slice.as_ptr().add(offset).cast::<[Self::Item; N]>() }
+	}
+}
+
+/// Implementation on everything readable, i.e. on everything that implements
+/// [`SubArray`] and on the shared containers
+impl<A> SubArrayRef for A where A: shared::SubArrayRead + ?Sized {}
+
+/// Implementation on mutable references
+///
+/// Only the required methods and the `try_` accessors are forwarded, all the
+/// others are provided on top of them, thus they are available through any
+/// number of references.
+impl<T> SubArray for &mut T
+where
+	T: SubArray,
+{
+	type Item = T::Item;
+
+	fn len(&self) -> usize {
+		(**self).len()
+	}
+
+	#[track_caller]
+	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+		(**self).sub_array_ref(offset)
+	}
+
+	#[track_caller]
+	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
+		(**self).sub_array_mut(offset)
+	}
+
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		(**self).try_sub_array_ref(offset)
+	}
+
+	fn try_sub_array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut [Self::Item; N]> {
+		(**self).try_sub_array_mut(offset)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::rc::Rc;
+	use alloc::string::String;
+	use alloc::string::ToString;
+	use alloc::vec::Vec;
+	use core::cell::Cell;
+
+	use super::*;
+
+
+	#[test]
+	fn empty_ref() {
+		let arr = [0_u8; 0];
+		assert_eq!(arr.sub_array_ref::<0>(0), &[]);
+	}
+
+	#[test]
+	fn empty_mut() {
+		let mut arr = [0_u8; 0];
+		assert_eq!(arr.sub_array_mut::<0>(0), &mut []);
+	}
+
+	#[test]
+	fn full_ref() {
+		let arr = [1, 2, 3_i8];
+		assert_eq!(arr.sub_array_ref::<3>(0), &[1, 2, 3]);
+	}
+
+	#[test]
+	fn full_mut() {
+		let mut arr = [1, 2, 3_i8];
+		assert_eq!(arr.sub_array_mut::<3>(0), &mut [1, 2, 3]);
+	}
+
+	#[test]
+	fn first_ref() {
+		let arr = [1, 2, 3_u16];
+		assert_eq!(arr.sub_array_ref::<1>(0), &[1]);
+	}
+
+	#[test]
+	fn first_mut() {
+		let mut arr = [1, 2, 3_u16];
+		assert_eq!(arr.sub_array_mut::<1>(0), &mut [1]);
+	}
+
+	#[test]
+	fn middle_ref() {
+		let arr = [1, 2, 3_i16];
+		assert_eq!(arr.sub_array_ref::<1>(1), &[2]);
+	}
+
+	#[test]
+	fn middle_mut() {
+		let mut arr = [1, 2, 3_i16];
+		assert_eq!(arr.sub_array_mut::<1>(1), &mut [2]);
+	}
+
+	#[test]
+	fn last_ref() {
+		let arr = [1, 2, 3_i16];
+		assert_eq!(arr.sub_array_ref::<1>(2), &[3]);
+	}
+
+	#[test]
 	fn last_mut() {
 		let mut arr = [1, 2, 3_i16];
 		assert_eq!(arr.sub_array_mut::<1>(2), &mut [3]);
 	}
 
-	#[derive(Debug, PartialEq, Eq)]
-	struct NotClone(&'static str);
+	#[test]
+	fn try_in_bounds() {
+		let mut arr = [1, 2, 3_u8];
+		assert_eq!(arr.try_sub_array_ref::<2>(1), Some(&[2, 3]));
+		assert_eq!(arr.try_sub_array_mut::<2>(1), Some(&mut [2, 3]));
+		assert_eq!(arr.try_sub_array_ref::<0>(3), Some(&[]));
+	}
+
+	#[test]
+	fn try_out_of_bounds() {
+		let mut arr = [1, 2, 3_u8];
+		assert_eq!(arr.try_sub_array_ref::<2>(2), None);
+		assert_eq!(arr.try_sub_array_mut::<4>(0), None);
+		assert_eq!(arr.try_sub_array_ref::<0>(4), None);
+		assert_eq!(arr.try_sub_array_ref::<1>(usize::MAX), None);
+	}
+
+	/// A container implementing only the required methods
+	struct Minimal([u8; 4]);
+
+	impl SubArray for Minimal {
+		type Item = u8;
+
+		fn len(&self) -> usize {
+			4
+		}
+
+		fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[u8; N] {
+			self.0.sub_array_ref(offset)
+		}
+
+		fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [u8; N] {
+			self.0.sub_array_mut(offset)
+		}
+	}
+
+	#[test]
+	fn try_provided() {
+		let mut minimal = Minimal([1, 2, 3, 4]);
+		assert_eq!(minimal.try_sub_array_ref::<2>(2), Some(&[3, 4]));
+		assert_eq!(minimal.try_sub_array_ref::<2>(3), None);
+		assert_eq!(minimal.try_sub_array_ref::<1>(usize::MAX), None);
+		assert_eq!(minimal.try_sub_array_mut::<0>(4), Some(&mut []));
+		assert_eq!(minimal.try_sub_array_mut::<4>(1), None);
+		*minimal.try_sub_array_mut::<1>(0).unwrap() = [5];
+		assert_eq!(minimal.0, [5, 2, 3, 4]);
+	}
+
+	#[derive(Debug, PartialEq, Eq)]
+	struct NotClone(&'static str);
+
+	const NOT_CLONE_ARRAY: [NotClone; 5] = [
+		NotClone("abc"),
+		NotClone("foo"),
+		NotClone("bar"),
+		NotClone("qux"),
+		NotClone("fox"),
+	];
+
+	#[test]
+	fn not_clone_ref() {
+		let exp_arr = [NotClone("foo"), NotClone("bar"), NotClone("qux")];
+		let arr = NOT_CLONE_ARRAY;
+		assert_eq!(arr.sub_array_ref::<3>(1), &exp_arr);
+	}
+
+	#[test]
+	fn not_clone_mut() {
+		let mut exp_arr = [NotClone("foo"), NotClone("bar"), NotClone("qux")];
+		let mut arr = NOT_CLONE_ARRAY;
+		assert_eq!(arr.sub_array_mut::<3>(1), &mut exp_arr);
+	}
+
+	#[test]
+	fn some_strings() {
+		let arr: [String; 5] = NOT_CLONE_ARRAY.map(|s| s.0.to_string());
+		assert_eq!(
+			arr.sub_array_ref::<2>(2),
+			&[String::from("bar"), String::from("qux")]
+		);
+	}
+
+	fn test_by_slice(s: &[u8]) -> &[u8; 3] {
+		s.sub_array_ref(4)
+	}
+
+	#[test]
+	fn slices() {
+		let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9_u8];
+
+		let slice: &[u8] = &arr;
+
+		let arr_ref = test_by_slice(slice);
+
+		assert_eq!(arr_ref, &[5, 6, 7]);
+		assert_eq!(arr_ref, arr.sub_array_ref(4));
+		assert_eq!(arr_ref, &slice[4..7]);
+	}
+
+	#[test]
+	fn clone_into() {
+		let src = [Rc::new(1_u32), Rc::new(2), Rc::new(3)];
+		let mut arr: [Rc<u32>; 5] = Default::default();
+
+		arr.sub_array_clone_into(1, &src);
+
+		// Exactly one clone per element, and no left-over temporaries
+		for rc in &src {
+			assert_eq!(Rc::strong_count(rc), 2);
+		}
+		assert_eq!(arr.map(|rc| *rc), [0, 1, 2, 3, 0]);
+	}
+
+	#[derive(Debug, Default)]
+	struct CountClones<'a>(Option<&'a Cell<usize>>);
+
+	impl Clone for CountClones<'_> {
+		fn clone(&self) -> Self {
+			if let Some(counter) = self.0 {
+				counter.set(counter.get() + 1);
+			}
+			Self(self.0)
+		}
+	}
+
+	#[test]
+	fn clone_into_count() {
+		let counter = Cell::new(0);
+		let src: [CountClones; 3] = core::array::from_fn(|_| CountClones(Some(&counter)));
+		let mut arr: [CountClones; 4] = Default::default();
+
+		arr.sub_array_clone_into(0, &src);
+
+		assert_eq!(counter.get(), 3);
+		assert!(arr[..3].iter().all(|c| c.0.is_some()));
+		assert!(arr[3].0.is_none());
+	}
+
+	#[test]
+	#[should_panic]
+	fn clone_into_out_of_bounds() {
+		let mut arr = [0_u8; 3];
+		arr.sub_array_clone_into(2, &[1, 2]);
+	}
+
+	#[test]
+	fn copy_round_trip() {
+		let arr = [1, 2, 3, 4, 5, 6_u8];
+		let mut other = [0_u8; 6];
+
+		let sub: [u8; 3] = arr.copy_sub_array(2);
+		sub.sub_array_copy_into(0, other.sub_array_mut::<3>(2));
+		assert_eq!(other, [0, 0, 3, 4, 5, 0]);
+
+		let mut dst = [0_u8; 3];
+		other.sub_array_copy_into(2, &mut dst);
+		assert_eq!(dst, sub);
+		assert_eq!(other.copy_sub_array::<3>(2), sub);
+	}
+
+	#[test]
+	fn copy_into_inferred_len() {
+		struct Header {
+			magic: [u8; 2],
+			len: [u8; 4],
+		}
+
+		let buf = [0xCA, 0xFE, 0, 0, 1, 0, 0xAA_u8];
+		let mut header = Header {
+			magic: [0; 2],
+			len: [0; 4],
+		};
+
+		// No turbofish, the lengths are inferred from the fields
+		buf.sub_array_copy_into(0, &mut header.magic);
+		buf.sub_array_copy_into(2, &mut header.len);
+
+		assert_eq!(header.magic, [0xCA, 0xFE]);
+		assert_eq!(u32::from_be_bytes(header.len), 256);
+	}
+
+	#[test]
+	fn try_copy() {
+		let arr = [1, 2, 3_u8];
+		let mut dst = [0_u8; 2];
+
+		assert_eq!(arr.try_copy_sub_array::<2>(1), Some([2, 3]));
+		assert_eq!(arr.try_copy_sub_array::<2>(2), None);
+
+		assert_eq!(arr.try_sub_array_copy_into(2, &mut dst), None);
+		assert_eq!(dst, [0, 0]);
+		assert_eq!(arr.try_sub_array_copy_into(0, &mut dst), Some(()));
+		assert_eq!(dst, [1, 2]);
+	}
+
+	#[test]
+	fn clamped() {
+		let arr = [1, 2, 3, 4, 5_u8];
+		assert_eq!(arr.sub_array_ref_clamped::<2>(0), &[1, 2]);
+		assert_eq!(arr.sub_array_ref_clamped::<2>(3), &[4, 5]);
+		assert_eq!(arr.sub_array_ref_clamped::<2>(4), &[4, 5]);
+		assert_eq!(arr.sub_array_ref_clamped::<2>(usize::MAX), &[4, 5]);
+		assert_eq!(arr.sub_array_ref_clamped::<5>(1), &[1, 2, 3, 4, 5]);
+
+		let slice: &[u8] = &arr;
+		assert_eq!(slice.sub_array_ref_clamped::<3>(10), &[3, 4, 5]);
+	}
+
+	#[test]
+	fn try_clamped() {
+		let arr = [1, 2, 3_u8];
+		assert_eq!(arr.try_sub_array_ref_clamped::<2>(7), Some(&[2, 3]));
+		assert_eq!(arr.try_sub_array_ref_clamped::<0>(7), Some(&[]));
+		assert_eq!(arr.try_sub_array_ref_clamped::<4>(0), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn clamped_too_long() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_ref_clamped::<4>(0);
+	}
+
+	#[test]
+	fn each_mut() {
+		let mut arr = [0_u8; 5];
+
+		let refs: [&mut u8; 3] = arr.sub_array_each_mut(1);
+		for (i, r) in refs.into_iter().enumerate() {
+			*r = i as u8 + 10;
+		}
+
+		assert_eq!(arr, [0, 10, 11, 12, 0]);
+	}
+
+	#[test]
+	fn each_mut_all_at_once() {
+		let mut arr = [0_u8; 6];
+		let slice: &mut [u8] = &mut arr;
+
+		let [a, b, c, d] = slice.try_sub_array_each_mut(1).unwrap();
+		// All references are alive at the same time
+		*d = 4;
+		*b = 2;
+		*c = 3;
+		*a = 1;
+		assert_eq!(arr, [0, 1, 2, 3, 4, 0]);
+
+		assert!(arr.try_sub_array_each_mut::<4>(3).is_none());
+		assert_eq!(arr.try_sub_array_each_mut::<0>(6), Some([]));
+		assert!(arr.try_sub_array_each_mut::<0>(7).is_none());
+	}
+
+	#[test]
+	fn each_ref() {
+		let arr = [String::from("a"), String::from("b"), String::from("c")];
+
+		let [b, c]: [&String; 2] = arr.sub_array_each_ref(1);
+		assert!(core::ptr::eq(b, &arr[1]));
+		assert!(core::ptr::eq(c, &arr[2]));
+
+		assert_eq!(arr.sub_array_each_ref::<0>(3), [] as [&String; 0]);
+		assert_eq!(arr.try_sub_array_each_ref::<1>(2), Some([&arr[2]]));
+		assert_eq!(arr.try_sub_array_each_ref::<2>(2), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn each_mut_out_of_bounds() {
+		let mut arr = [0_u8; 5];
+		arr.sub_array_each_mut::<3>(3);
+	}
+
+	#[test]
+	fn and_remainder() {
+		let arr = [1, 2, 3, 4, 5, 6_u8];
+
+		for offset in 0..=3 {
+			let (before, sub, after) = arr.sub_array_ref_and_remainder::<3>(offset);
+			assert_eq!(before.len(), offset);
+			let flat: Vec<u8> = [before, sub, after].concat();
+			assert_eq!(flat, arr);
+		}
+	}
+
+	#[test]
+	fn and_remainder_mut() {
+		let mut arr = [1, 2, 3, 4, 5, 6_u8];
+
+		let (before, sub, after) = arr.sub_array_mut_and_remainder::<2>(2);
+		assert_eq!([&before[..], sub, after].concat(), [1, 2, 3, 4, 5, 6]);
+		before.fill(0);
+		*sub = [7, 7];
+		after.fill(9);
+
+		assert_eq!(arr, [0, 0, 7, 7, 9, 9]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 2 exceeds the array of length 3")]
+	fn and_remainder_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_ref_and_remainder::<2>(2);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 0 at offset 4 exceeds the array of length 3")]
+	fn and_remainder_mut_out_of_bounds() {
+		let mut arr = [1, 2, 3_u8];
+		arr.sub_array_mut_and_remainder::<0>(4);
+	}
+
+	#[test]
+	fn between() {
+		let arr = [1, 2, 3, 4, 5_u8];
+		assert_eq!(arr.sub_array_ref_between::<3>(1, 4), &[2, 3, 4]);
+		assert_eq!(arr.sub_array_ref_between::<0>(5, 5), &[]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array range 1..3 does not have the expected length 3")]
+	fn between_mismatch() {
+		let arr = [1, 2, 3, 4, 5_u8];
+		arr.sub_array_ref_between::<3>(1, 3);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array range 3..1 does not have the expected length 2")]
+	fn between_reversed() {
+		let arr = [1, 2, 3, 4, 5_u8];
+		arr.sub_array_ref_between::<2>(3, 1);
+	}
+
+	#[test]
+	fn windows_dedup_position() {
+		let mut arr = [0_i16; 64];
+		assert_eq!(arr.sub_array_windows_dedup_position::<4>(), None);
+
+		arr[41] = 1;
+		assert_eq!(arr.sub_array_windows_dedup_position::<4>(), Some(40));
+		assert_eq!(arr.sub_array_windows_dedup_position::<8>(), Some(40));
+		assert_eq!(arr.sub_array_windows_dedup_position::<7>(), Some(35));
+		assert_eq!(arr.sub_array_windows_dedup_position::<1>(), Some(41));
+		assert_eq!(arr.sub_array_windows_dedup_position::<0>(), None);
+	}
+
+	#[test]
+	fn windows_dedup_position_remainder() {
+		let arr = [1, 1, 1, 1, 2_u8];
+		assert_eq!(arr.sub_array_windows_dedup_position::<2>(), None);
+		assert_eq!(arr.sub_array_windows_dedup_position::<5>(), None);
+		assert_eq!(arr.sub_array_windows_dedup_position::<6>(), None);
+	}
+
+	#[test]
+	fn or_partial() {
+		let arr = [1, 2, 3, 4_u8];
+		assert_eq!(arr.sub_array_ref_or_partial::<4>(0), Ok(&[1, 2, 3, 4]));
+		assert_eq!(arr.sub_array_ref_or_partial::<2>(2), Ok(&[3, 4]));
+		assert_eq!(arr.sub_array_ref_or_partial::<0>(4), Ok(&[]));
+		assert_eq!(arr.sub_array_ref_or_partial::<3>(2), Err(&[3, 4][..]));
+		assert_eq!(arr.sub_array_ref_or_partial::<1>(4), Err(&[][..]));
+		assert_eq!(arr.sub_array_ref_or_partial::<1>(usize::MAX), Err(&[][..]));
+		assert_eq!(arr.sub_array_ref_or_partial::<0>(5), Err(&[][..]));
+	}
+
+	const SPEC: SubArraySpec<3> = SubArraySpec::new(2);
+
+	#[test]
+	fn spec() {
+		let long = [1, 2, 3, 4, 5, 6_u8];
+		let mut short = [1, 2, 3, 4, 5_u8];
+
+		assert_eq!(SubArraySpec::<3>::LEN, 3);
+		assert_eq!(long.apply_ref(SPEC), &[3, 4, 5]);
+		*short.apply_mut(SPEC) = [0; 3];
+		assert_eq!(short, [1, 2, 0, 0, 0]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn spec_out_of_bounds() {
+		let arr = [1, 2, 3, 4_u8];
+		arr.apply_ref(SPEC);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn locate_and_apply() {
+		let mut vec = alloc::vec![1, 2, 3, 4, 5_u8];
+
+		// Located once, applied many times
+		let token = vec.locate_sub_array::<2>(3).unwrap();
+		assert_eq!(token.offset(), 3);
+		assert_eq!(token.end(), 5);
+		assert_eq!(token.source_len(), 5);
+		for i in 0..3 {
+			vec.apply_window_mut(token)[0] += i;
+			assert_eq!(vec.apply_window(token)[1], 5);
+		}
+		assert_eq!(vec, [1, 2, 3, 7, 5]);
+		assert_eq!(token.to_spec(), SubArraySpec::new(3));
+
+		assert_eq!(vec.locate_sub_array::<2>(4), None);
+		assert_eq!(
+			vec.locate_sub_array::<0>(5).map(WindowToken::offset),
+			Some(5)
+		);
+		assert_eq!(vec.locate_sub_array::<1>(usize::MAX), None);
+
+		// Growing keeps the token valid
+		vec.push(6);
+		assert_eq!(vec.apply_window(token), &[7, 5]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 2 exceeds the array of length 3")]
+	fn locate_then_shrink() {
+		let mut vec = alloc::vec![1, 2, 3, 4_u8];
+		let token = vec.locate_sub_array::<2>(2).unwrap();
+		vec.pop();
+		vec.apply_window(token);
+	}
+
+	#[test]
+	fn xor_adjacent_blocks_cbc() {
+		// CBC-AES128.Decrypt of NIST SP 800-38A, F.2.2
+		let iv = 0x000102030405060708090a0b0c0d0e0f_u128.to_be_bytes();
+		let ciphertext = [
+			0x7649abac8119b246cee98e9b12e9197d_u128,
+			0x5086cb9b507219ee95db113a917678b2,
+			0x73bed6b8e3c1743b7116e69e22229516,
+			0x3ff1caa1681fac09120eca307586e1a7,
+		]
+		.map(u128::to_be_bytes);
+		// The outputs of the AES decryption of each ciphertext block
+		let decrypted = [
+			0x6bc0bce12a459991e134741a7f9e1925_u128,
+			0xd86421fb9f1a1eda505ee1375746972c,
+			0x604ed7ddf32efdff7020d0238b7c2a5d,
+			0x8521f2fd3c8eef2cdc3da7e5c44ea206,
+		]
+		.map(u128::to_be_bytes);
+		let plaintext = [
+			0x6bc1bee22e409f96e93d7e117393172a_u128,
+			0xae2d8a571e03ac9c9eb76fac45af8e51,
+			0x30c81c46a35ce411e5fbc1191a0a52ef,
+			0xf69f2445df4f9b17ad2b417be66c3710,
+		]
+		.map(u128::to_be_bytes);
+
+		// The IV followed by the ciphertext, decrypted in-place from the last
+		// block, such that its predecessor is still the ciphertext
+		let mut buf = [0_u8; 80];
+		*buf.sub_array_mut(0) = iv;
+		buf[16..].copy_from_slice(ciphertext.as_flattened());
+		for i in (1..=4).rev() {
+			assert_eq!(buf.sub_array_ref::<16>(i * 16), &ciphertext[i - 1]);
+			*buf.sub_array_mut(i * 16) = decrypted[i - 1];
+			buf[(i - 1) * 16..].sub_array_xor_adjacent_blocks::<16>(2);
+		}
+
+		assert_eq!(buf.sub_array_ref::<16>(0), &iv);
+		assert_eq!(&buf[16..], plaintext.as_flattened());
+	}
+
+	#[test]
+	fn xor_adjacent_blocks_chained() {
+		// The same chaining in one call, with the identity as block cipher,
+		// i.e. the ciphertext blocks are `C[i] = P[i] ^ C[i - 1]`
+		let iv = [0x3C_u8, 0x5A, 0x96, 0xF0];
+		let plain: [[u8; 4]; 3] = [*b"sub-", *b"arra", *b"y!!!"];
+
+		let mut buf = [0_u8; 16];
+		*buf.sub_array_mut(0) = iv;
+		for (i, block) in plain.iter().enumerate() {
+			let prev: [u8; 4] = buf.copy_sub_array(i * 4);
+			*buf.sub_array_mut::<4>(i * 4 + 4) = core::array::from_fn(|j| block[j] ^ prev[j]);
+		}
+
+		buf.sub_array_xor_adjacent_blocks::<4>(4);
+
+		assert_eq!(buf.sub_array_ref::<4>(0), &iv);
+		assert_eq!(&buf[4..], plain.as_flattened());
+	}
+
+	#[test]
+	fn xor_adjacent_blocks_partial() {
+		let mut arr = [1, 2, 4, 8, 16_u32];
+		arr.sub_array_xor_adjacent_blocks::<1>(0);
+		arr.sub_array_xor_adjacent_blocks::<1>(1);
+		assert_eq!(arr, [1, 2, 4, 8, 16]);
+		arr.sub_array_xor_adjacent_blocks::<1>(3);
+		assert_eq!(arr, [1, 3, 6, 8, 16]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 6 at offset 0 exceeds the array of length 5")]
+	fn xor_adjacent_blocks_out_of_bounds() {
+		let mut arr = [0_u8; 5];
+		arr.sub_array_xor_adjacent_blocks::<2>(3);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 4 at offset 0 exceeds the array of length 3")]
+	fn xor_adjacent_blocks_single_out_of_bounds() {
+		let mut arr = [0_u8; 3];
+		arr.sub_array_xor_adjacent_blocks::<4>(1);
+	}
+
+	#[test]
+	fn reversed() {
+		let arr = [1, 2, 3, 4, 5, 6_u8];
+		assert_eq!(arr.sub_array_reversed::<4>(1), [5, 4, 3, 2]);
+		assert_eq!(arr.sub_array_reversed::<0>(6), []);
+		assert_eq!(arr, [1, 2, 3, 4, 5, 6]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn reversed_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_reversed::<4>(0);
+	}
+
+	#[cfg(feature = "bytemuck")]
+	#[test]
+	fn as_bytes() {
+		let arr: [u32; 8] = [0, 1, 0x0102_0304, 0xA0B0_C0D0, 4, 5, 6, 7];
+
+		let bytes: &[u8; 8] = arr.sub_array_as_bytes::<2, 8>(2);
+
+		#[cfg(target_endian = "little")]
+		assert_eq!(bytes, &[0x04, 0x03, 0x02, 0x01, 0xD0, 0xC0, 0xB0, 0xA0]);
+		#[cfg(target_endian = "big")]
+		assert_eq!(bytes, &[0x01, 0x02, 0x03, 0x04, 0xA0, 0xB0, 0xC0, 0xD0]);
+	}
+
+	#[cfg(feature = "subtle")]
+	#[test]
+	fn ct_eq() {
+		let message = [1, 2, 3, 0x5A, 0xA5, 0x0F, 0xF0_u8];
+		let tag = [0x5A, 0xA5, 0x0F, 0xF0_u8];
+
+		assert!(bool::from(message.ct_eq_sub_array(3, &tag)));
+		assert!(!bool::from(message.ct_eq_sub_array(2, &tag)));
+		assert!(!bool::from(
+			message.ct_eq_sub_array(3, &[0x5A, 0xA5, 0x0F, 0xF1])
+		));
+		assert!(bool::from(message.ct_eq_sub_array::<0>(7, &[])));
+	}
+
+	#[cfg(feature = "subtle")]
+	#[test]
+	#[should_panic]
+	fn ct_eq_out_of_bounds() {
+		let message = [1, 2, 3_u8];
+		let _ = message.ct_eq_sub_array(1, &[2, 3, 4]);
+	}
+
+	#[test]
+	fn centered() {
+		let arr = [0, 1, 2, 3, 4, 5, 6_u8];
+		assert_eq!(arr.sub_array_centered_ref::<5>(3), &[1, 2, 3, 4, 5]);
+		assert_eq!(arr.sub_array_centered_ref::<1>(6), &[6]);
+		assert_eq!(arr.sub_array_centered_ref::<2>(1), &[0, 1]);
+		assert_eq!(arr.sub_array_centered_ref::<7>(3), &arr);
+		assert_eq!(arr.sub_array_centered_ref::<0>(7), &[]);
+
+		assert_eq!(arr.try_sub_array_centered_ref::<3>(1), Some(&[0, 1, 2]));
+		assert_eq!(arr.try_sub_array_centered_ref::<3>(0), None);
+		assert_eq!(arr.try_sub_array_centered_ref::<3>(6), None);
+		assert_eq!(arr.try_sub_array_centered_ref::<3>(usize::MAX), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "centered sub-array exceeds the start of the array")]
+	fn centered_off_start() {
+		let arr = [0, 1, 2, 3, 4_u8];
+		arr.sub_array_centered_ref::<5>(1);
+	}
+
+	#[test]
+	#[should_panic]
+	fn centered_off_end() {
+		let arr = [0, 1, 2, 3, 4_u8];
+		arr.sub_array_centered_ref::<3>(4);
+	}
+
+	#[test]
+	fn partial_cmp() {
+		let a = [1, 2, 3, 4, 5_u8];
+		let b: &[u8] = &[9, 1, 2, 4];
+		let c = [1, 3, 0_u8];
+
+		assert_eq!(a.sub_array_partial_cmp::<2, _>(0, b, 1), Ordering::Equal);
+		assert_eq!(a.sub_array_partial_cmp::<0, _>(5, &c, 3), Ordering::Equal);
+		assert_eq!(a.sub_array_partial_cmp::<3, _>(0, &a, 0), Ordering::Equal);
+
+		// a < b < c, thus a < c
+		assert_eq!(a.sub_array_partial_cmp::<3, _>(0, b, 1), Ordering::Less);
+		assert_eq!(b.sub_array_partial_cmp::<3, _>(1, &c, 0), Ordering::Less);
+		assert_eq!(a.sub_array_partial_cmp::<3, _>(0, &c, 0), Ordering::Less);
+		assert_eq!(c.sub_array_partial_cmp::<3, _>(0, &a, 0), Ordering::Greater);
+	}
+
+	#[test]
+	#[should_panic]
+	fn partial_cmp_out_of_bounds() {
+		let a = [1, 2, 3_u8];
+		a.sub_array_partial_cmp::<2, _>(0, &a, 2);
+	}
+
+	#[test]
+	fn split_read_write_disjoint() {
+		let mut arr = [1, 2, 3, 4, 5, 6_u8];
+
+		let (read, write) = arr.split_read_write::<3>(0, 3).unwrap();
+		assert_eq!(read, &[1, 2, 3]);
+		write.copy_from_slice(read);
+		assert_eq!(arr, [1, 2, 3, 1, 2, 3]);
+
+		let (read, write) = arr.split_read_write::<2>(4, 0).unwrap();
+		assert_eq!(read, &[2, 3]);
+		*write = [0, 0];
+		assert_eq!(arr, [0, 0, 3, 1, 2, 3]);
+
+		assert!(arr.split_read_write::<0>(2, 2).is_some());
+		assert!(arr.split_read_write::<0>(6, 0).is_some());
+	}
+
+	#[test]
+	fn split_read_write_overlapping() {
+		let mut arr = [1, 2, 3, 4, 5, 6_u8];
+
+		assert_eq!(arr.split_read_write::<2>(0, 0), None);
+		assert_eq!(arr.split_read_write::<3>(0, 2), None);
+		assert_eq!(arr.split_read_write::<3>(3, 1), None);
+	}
+
+	#[test]
+	fn split_read_write_out_of_bounds() {
+		let slice: &mut [u8] = &mut [1, 2, 3, 4];
+
+		assert_eq!(slice.split_read_write::<2>(0, 3), None);
+		assert_eq!(slice.split_read_write::<2>(3, 0), None);
+		assert_eq!(slice.split_read_write::<2>(usize::MAX, 0), None);
+	}
+
+	/// Naive `O(len * N)` reference of `try_sub_array_windows_max_sum`
+	fn naive_max_sum<const N: usize>(slice: &[i64]) -> Option<(usize, i64)> {
+		let mut best: Option<(usize, i64)> = None;
+		for offset in 0..=slice.len().checked_sub(N)? {
+			let sum = slice.sub_array_ref::<N>(offset).iter().sum();
+			if best.is_none_or(|(_, max)| sum > max) {
+				best = Some((offset, sum));
+			}
+		}
+		best
+	}
+
+	#[test]
+	fn windows_max_sum_naive() {
+		// Simple LCG for deterministic pseudo random values
+		let mut state = 42_u32;
+		let values: Vec<i64> = core::iter::repeat_with(|| {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			i64::from(state >> 16) % 201 - 100
+		})
+		.take(64)
+		.collect();
+
+		for len in 0..values.len() {
+			let slice = &values[..len];
+			assert_eq!(
+				slice.try_sub_array_windows_max_sum::<0>(),
+				naive_max_sum::<0>(slice)
+			);
+			assert_eq!(
+				slice.try_sub_array_windows_max_sum::<1>(),
+				naive_max_sum::<1>(slice)
+			);
+			assert_eq!(
+				slice.try_sub_array_windows_max_sum::<3>(),
+				naive_max_sum::<3>(slice)
+			);
+			assert_eq!(
+				slice.try_sub_array_windows_max_sum::<8>(),
+				naive_max_sum::<8>(slice)
+			);
+			assert_eq!(
+				slice.try_sub_array_windows_max_sum::<17>(),
+				naive_max_sum::<17>(slice)
+			);
+		}
+	}
+
+	#[test]
+	fn windows_max_sum_ties() {
+		let arr = [1, 2, 2, 1, 2, 1_u32];
+		assert_eq!(arr.sub_array_windows_max_sum::<2>(), (1, 4));
+		assert_eq!(arr.sub_array_windows_max_sum::<6>(), (0, 9));
+		assert_eq!(arr.sub_array_windows_max_sum::<0>(), (0, 0));
+	}
+
+	#[test]
+	#[should_panic(expected = "array is shorter than the window")]
+	fn windows_max_sum_too_short() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_windows_max_sum::<4>();
+	}
+
+	/// Element that counts how often it is written by cloning
+	#[derive(Debug, PartialEq)]
+	struct CountWrites<'a> {
+		value: u8,
+		writes: &'a Cell<usize>,
+	}
+
+	impl Clone for CountWrites<'_> {
+		fn clone(&self) -> Self {
+			self.writes.set(self.writes.get() + 1);
+			Self {
+				value: self.value,
+				writes: self.writes,
+			}
+		}
+	}
+
+	#[test]
+	fn copy_if_changed() {
+		let mut arr = [1, 2, 3, 4_u8];
+
+		assert!(!arr.copy_sub_array_if_changed(0, &[1, 2, 3, 4]));
+		assert!(!arr.copy_sub_array_if_changed::<0>(4, &[]));
+		assert_eq!(arr, [1, 2, 3, 4]);
+
+		assert!(arr.copy_sub_array_if_changed(2, &[3, 5]));
+		assert_eq!(arr, [1, 2, 3, 5]);
+
+		assert_eq!(arr.try_copy_sub_array_if_changed(3, &[5, 6]), None);
+		assert_eq!(arr.try_copy_sub_array_if_changed(3, &[5]), Some(false));
+		assert_eq!(arr.try_copy_sub_array_if_changed(3, &[6]), Some(true));
+		assert_eq!(arr, [1, 2, 3, 6]);
+	}
+
+	#[test]
+	fn clone_if_changed_counts_writes() {
+		let writes = Cell::new(0);
+		let elem = |value| {
+			CountWrites {
+				value,
+				writes: &writes,
+			}
+		};
+
+		let mut arr = [elem(1), elem(2), elem(3), elem(4)];
+		let same = [elem(2), elem(3)];
+		let differ = [elem(2), elem(9)];
+
+		assert!(!arr.clone_sub_array_if_changed(1, &same));
+		assert_eq!(writes.get(), 0);
+
+		// A single differing element causes the window to be written once
+		assert!(arr.clone_sub_array_if_changed(1, &differ));
+		assert_eq!(writes.get(), 2);
+		assert_eq!(arr.each_ref().map(|e| e.value), [1, 2, 9, 4]);
+
+		assert!(!arr.clone_sub_array_if_changed(1, &differ));
+		assert_eq!(writes.get(), 2);
+	}
+
+	#[test]
+	fn copy_chunks_if_changed() {
+		let mut arr = [0, 1, 2, 3, 4, 5, 6_u8];
+
+		assert_eq!(arr.copy_chunks_if_changed(1, &[[1, 2], [3, 4], [5, 6]]), 0);
+		assert_eq!(arr.copy_chunks_if_changed(1, &[[1, 2], [0, 4], [0, 0]]), 2);
+		assert_eq!(arr, [0, 1, 2, 0, 4, 0, 0]);
+		assert_eq!(arr.copy_chunks_if_changed::<2>(7, &[]), 0);
+
+		assert_eq!(
+			arr.try_copy_chunks_if_changed(2, &[[9, 9], [9, 9], [9, 9]]),
+			None
+		);
+		assert_eq!(arr, [0, 1, 2, 0, 4, 0, 0]);
+		assert_eq!(
+			arr.try_copy_chunks_if_changed(0, &[[9; 3], [9; 3]]),
+			Some(2)
+		);
+		assert_eq!(arr, [9, 9, 9, 9, 9, 9, 0]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 4 at offset 1 exceeds the array of length 4")]
+	fn copy_chunks_if_changed_out_of_bounds() {
+		let mut arr = [0_u8; 4];
+		arr.copy_chunks_if_changed(1, &[[1, 1], [1, 1]]);
+	}
 
-	const NOT_CLONE_ARRAY: [NotClone; 5] = [
-		NotClone("abc"),
-		NotClone("foo"),
-		NotClone("bar"),
-		NotClone("qux"),
-		NotClone("fox"),
-	];
+	#[test]
+	fn cmp_sub_arrays() {
+		let arr = [1, 2, 3, 1, 2, 4, 1, 2_u8];
+
+		assert_eq!(arr.cmp_sub_arrays::<2>(0, 3), Ordering::Equal);
+		assert_eq!(arr.cmp_sub_arrays::<3>(1, 1), Ordering::Equal);
+		assert_eq!(arr.cmp_sub_arrays::<0>(8, 0), Ordering::Equal);
+		assert_eq!(arr.cmp_sub_arrays::<3>(0, 3), Ordering::Less);
+		assert_eq!(arr.cmp_sub_arrays::<3>(3, 0), Ordering::Greater);
+		assert_eq!(arr.cmp_sub_arrays::<1>(5, 2), Ordering::Greater);
+	}
 
 	#[test]
-	fn not_clone_ref() {
-		let exp_arr = [NotClone("foo"), NotClone("bar"), NotClone("qux")];
-		let arr = NOT_CLONE_ARRAY;
-		assert_eq!(arr.sub_array_ref::<3>(1), &exp_arr);
+	#[should_panic]
+	fn cmp_sub_arrays_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		arr.cmp_sub_arrays::<2>(0, 2);
 	}
 
 	#[test]
-	fn not_clone_mut() {
-		let mut exp_arr = [NotClone("foo"), NotClone("bar"), NotClone("qux")];
-		let mut arr = NOT_CLONE_ARRAY;
-		assert_eq!(arr.sub_array_mut::<3>(1), &mut exp_arr);
+	fn windows_min_max_sum_sorted() {
+		let values = [5, -2, 7, 0, -9, 3, 3, -1, 8, -4_i32];
+
+		let sums = |n: usize| {
+			let mut sums: Vec<i32> = values.windows(n).map(|w| w.iter().sum()).collect();
+			sums.sort();
+			(sums[0], sums[sums.len() - 1])
+		};
+
+		let ((min_offset, min), (max_offset, max)) = values.sub_array_windows_min_max_sum::<3>();
+		assert_eq!((min, max), sums(3));
+		assert_eq!(values[min_offset..][..3].iter().sum::<i32>(), min);
+		assert_eq!(values[max_offset..][..3].iter().sum::<i32>(), max);
+		assert_eq!(((min_offset, min), (max_offset, max)), ((3, -6), (0, 10)));
+
+		let ((_, min), (_, max)) = values.sub_array_windows_min_max_sum::<1>();
+		assert_eq!((min, max), sums(1));
+		let ((_, min), (_, max)) = values.sub_array_windows_min_max_sum::<10>();
+		assert_eq!((min, max), sums(10));
 	}
 
 	#[test]
-	fn some_strings() {
-		let arr: [String; 5] = NOT_CLONE_ARRAY.map(|s| s.0.to_string());
+	fn windows_min_max_sum_edges() {
+		let arr = [2, 2, 2_u8];
+		assert_eq!(arr.sub_array_windows_min_max_sum::<2>(), ((0, 4), (0, 4)));
+		assert_eq!(arr.sub_array_windows_min_max_sum::<0>(), ((0, 0), (0, 0)));
+		assert_eq!(arr.try_sub_array_windows_min_max_sum::<4>(), None);
+		assert_eq!(arr.try_sub_array_windows_max_sum::<3>(), Some((0, 6)));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn find_all() {
+		let arr = [1, 2, 1, 2, 1, 1, 1_u8];
+
+		assert_eq!(arr.find_all_sub_arrays(&[1, 2]), [0, 2]);
+		assert_eq!(arr.find_all_sub_arrays(&[1, 1]), [4, 5]);
+		assert_eq!(arr.find_all_sub_arrays(&[1, 2, 1]), [0, 2]);
+		assert_eq!(arr.find_all_sub_arrays(&[2, 2]), [] as [usize; 0]);
+		assert_eq!(arr.find_all_sub_arrays(&[1; 8]), [] as [usize; 0]);
+	}
+
+	#[test]
+	fn min_max_sum() {
+		let arr = [4, -2, 8, 8, -5, 3_i32];
+
+		assert_eq!(arr.sub_array_min::<4>(1), -5);
+		assert_eq!(arr.sub_array_max::<4>(1), 8);
+		assert_eq!(arr.sub_array_sum::<4>(1), 9);
+		assert_eq!(arr.sub_array_min::<1>(5), 3);
+		assert_eq!(arr.sub_array_sum::<0>(6), 0);
+
+		assert_eq!(arr.try_sub_array_min::<2>(4), Some(-5));
+		assert_eq!(arr.try_sub_array_max::<2>(4), Some(3));
+		assert_eq!(arr.try_sub_array_sum::<2>(4), Some(-2));
+		assert_eq!(arr.try_sub_array_min::<2>(5), None);
+		assert_eq!(arr.try_sub_array_max::<2>(5), None);
+		assert_eq!(arr.try_sub_array_sum::<2>(5), None);
+	}
+
+	#[test]
+	fn min_max_clone() {
+		let arr = [String::from("b"), String::from("a"), String::from("c")];
+		assert_eq!(arr.sub_array_min::<2>(0), "a");
+		assert_eq!(arr.sub_array_max::<2>(1), "c");
+	}
+
+	#[test]
+	#[should_panic]
+	fn min_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_min::<2>(2);
+	}
+
+	#[test]
+	fn select_nth_unstable() {
+		let original = [9, 3, 7, 1, 8, 2, 6, 4, 5, 0_u8];
+
+		for k in 0..8 {
+			let mut arr = original;
+			let (before, nth, after) = arr.sub_array_select_nth_unstable::<8>(1, k);
+			let pivot = *nth;
+			assert_eq!(before.len(), k);
+			assert!(before.iter().all(|&e| e <= pivot));
+			assert!(after.iter().all(|&e| e >= pivot));
+
+			let mut sorted = *original.sub_array_ref::<8>(1);
+			sorted.sort();
+			assert_eq!(pivot, sorted[k]);
+			assert_eq!([arr[0], arr[9]], [9, 0]);
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn select_nth_unstable_out_of_range() {
+		let mut arr = [3, 2, 1_u8];
+		arr.sub_array_select_nth_unstable::<2>(1, 2);
+	}
+
+	#[test]
+	fn gather() {
+		let arr = [5, 6, 7, 8, 9_u8];
+		let indices = [4, 3, 2, 1, 0];
+
+		let reversed = arr.gather_sub_array(&indices);
+		assert_eq!(reversed, indices.map(|i| arr[i]));
+		assert_eq!(reversed, [9, 8, 7, 6, 5]);
+		assert_eq!(arr.gather_sub_array(&[]), [] as [u8; 0]);
+
+		let slice: &[u8] = &arr;
+		assert_eq!(slice.try_gather_sub_array(&[1, 1]), Some([6, 6]));
+		assert_eq!(slice.try_gather_sub_array(&[1, 5]), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn gather_out_of_bounds() {
+		let arr = [5, 6, 7_u8];
+		arr.gather_sub_array(&[0, 3]);
+	}
+
+	#[test]
+	fn group_by_run_length() {
+		// Pairs of `(count, value)`, decoded into the middle of a buffer
+		let encoded = [(3, b'a'), (1, b'b'), (4, b'c'), (2, b'a')];
+		let mut arr = [b'#'; 12];
+		let mut pos = 1;
+		for (count, value) in encoded {
+			arr[pos..(pos + count)].fill(value);
+			pos += count;
+		}
+
+		let runs: Vec<&[u8]> = arr.sub_array_group_by::<10, _>(1, |a, b| a == b).collect();
+		assert_eq!(runs.len(), encoded.as_slice().len());
+		for (run, (count, value)) in runs.iter().zip(encoded) {
+			assert_eq!(run.len(), count);
+			assert!(run.iter().all(|&b| b == value));
+		}
+
+		// Only the sub-array is grouped, the delimiters are excluded
+		let runs: Vec<&[u8]> = arr.sub_array_group_by::<3, _>(2, |a, b| a == b).collect();
+		assert_eq!(runs, [&b"aa"[..], b"b"]);
+	}
+
+	#[test]
+	fn group_by_pred() {
+		let arr = [1, 2, 3, 2, 3, 4, 4_u8];
+		let ascending: Vec<&[u8]> = arr.sub_array_group_by::<7, _>(0, |a, b| a < b).collect();
+		assert_eq!(ascending, [&[1, 2, 3][..], &[2, 3, 4], &[4]]);
+
+		assert_eq!(arr.sub_array_group_by::<0, _>(7, |_, _| true).next(), None);
+		assert!(arr.try_sub_array_group_by::<2, _>(6, |_, _| true).is_none());
 		assert_eq!(
-			arr.sub_array_ref::<2>(2),
-			&[String::from("bar"), String::from("qux")]
+			arr.try_sub_array_group_by::<2, _>(5, |a, b| a == b)
+				.map(|runs| runs.count()),
+			Some(1)
 		);
 	}
 
-	fn test_by_slice(s: &[u8]) -> &[u8; 3] {
-		s.sub_array_ref(4)
+	#[test]
+	#[should_panic]
+	fn group_by_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		let _ = arr.sub_array_group_by::<3, _>(1, |a, b| a == b);
 	}
 
 	#[test]
-	fn slices() {
-		let arr = [1, 2, 3, 4, 5, 6, 7, 8, 9_u8];
+	fn scatter() {
+		let mut arr = [0_u8; 5];
+
+		arr.scatter_sub_array(&[4, 1], [7, 8]);
+		assert_eq!(arr, [0, 8, 0, 0, 7]);
+
+		// The last write to a duplicate index wins
+		arr.scatter_sub_array(&[2, 0, 2], [1, 2, 3]);
+		assert_eq!(arr, [2, 8, 3, 0, 7]);
+
+		arr.scatter_sub_array(&[], []);
+		assert_eq!(arr, [2, 8, 3, 0, 7]);
+	}
+
+	#[test]
+	fn scatter_inverse_of_gather() {
+		let arr = [5, 6, 7, 8_u8];
+		let perm = [2, 0, 3, 1];
+
+		let mut restored = [0; 4];
+		restored.scatter_sub_array(&perm, arr.gather_sub_array(&perm));
+		assert_eq!(restored, arr);
+	}
+
+	#[test]
+	fn try_scatter() {
+		let mut vec = Vec::from([1, 2, 3_u8]);
+		assert_eq!(vec.try_scatter_sub_array(&[0, 3], [9, 9]), None);
+		assert_eq!(vec, [1, 2, 3]);
+		assert_eq!(vec.try_scatter_sub_array(&[2, 0], [9, 8]), Some(()));
+		assert_eq!(vec, [8, 2, 9]);
+	}
+
+	#[test]
+	#[should_panic(expected = "scatter index out of bounds")]
+	fn scatter_out_of_bounds() {
+		let mut arr = [5, 6, 7_u8];
+		arr.scatter_sub_array(&[0, 3], [1, 2]);
+	}
+
+	#[test]
+	fn records() {
+		let buf = [3, 10, 11, 20, 21, 30, 31, 0xFF_u8];
+
+		let records: Vec<&[u8; 2]> = buf.sub_array_records(1, 3).collect();
+		assert_eq!(records, [&[10, 11], &[20, 21], &[30, 31]]);
+		assert_eq!(buf.sub_array_records::<2>(1, 3).len(), 3);
+		assert_eq!(buf.sub_array_records::<4>(8, 0).next(), None);
+
+		let slice: &[u8] = &buf;
+		assert!(slice.try_sub_array_records::<2>(1, 4).is_none());
+		assert!(slice.try_sub_array_records::<2>(2, usize::MAX).is_none());
+		assert!(slice.try_sub_array_records::<1>(9, 0).is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 6 at offset 1 exceeds the array of length 6")]
+	fn records_out_of_bounds() {
+		let buf = [3, 10, 11, 20, 21, 30_u8];
+		let _ = buf.sub_array_records::<2>(1, 3);
+	}
+
+	/// Exercises the method surface generically, on a buffer of `1..=8`
+	fn method_surface<A>(buf: &mut A)
+	where
+		A: SubArray<Item = u8> + AsRef<[u8]> + AsMut<[u8]> + ?Sized,
+	{
+		assert_eq!(buf.sub_array_ref::<2>(1), &[2, 3]);
+		assert_eq!(buf.try_sub_array_ref::<2>(7), None);
+		assert_eq!(buf.copy_sub_array::<2>(6), [7, 8]);
+		assert_eq!(buf.try_copy_sub_array::<1>(8), None);
+		assert_eq!(buf.sub_array_ref_clamped::<4>(6), &[5, 6, 7, 8]);
+		assert_eq!(buf.sub_array_centered_ref::<3>(4), &[4, 5, 6]);
+		assert_eq!(
+			buf.match_indices_sub_array(&[3, 4]).collect::<Vec<_>>(),
+			[2]
+		);
+		assert_eq!(buf.sub_array_each_ref::<2>(6), [&7, &8]);
+		assert_eq!(buf.cmp_sub_arrays::<2>(0, 2), Ordering::Less);
+		assert_eq!(buf.sub_array_min::<3>(2), 3);
+		assert_eq!(buf.sub_array_max::<3>(2), 5);
+		assert_eq!(buf.try_sub_array_sum::<4>(0), Some(10));
+		assert_eq!(buf.sub_array_windows_max_sum::<2>(), (6, 15));
+		assert_eq!(buf.gather_sub_array(&[7, 0]), [8, 1]);
+		assert_eq!(buf.sub_array_chunks::<3>().count(), 2);
+		assert_eq!(buf.sub_array_iter::<4>().nth(1), Some((4, &[5, 6, 7, 8])));
+		assert_eq!(buf.sub_array_records::<2>(2, 3).count(), 3);
+		assert_eq!(buf.sub_array_group_by::<8, _>(0, |a, b| a < b).count(), 1);
+		assert_eq!(buf.split_by_sub_array(&[4]).count(), 2);
+		assert!(buf.validate_sub_array::<8>(0, |_, &b| b > 0).is_ok());
+		assert_eq!(*buf.sub_array_handle::<2>(0), [1, 2]);
+		assert_eq!(
+			buf.ring_window::<3>(7).iter().copied().collect::<Vec<_>>(),
+			[8, 1, 2]
+		);
+
+		*buf.sub_array_mut::<1>(0) = [9];
+		assert_eq!(buf.try_sub_array_mut::<1>(8), None);
+		assert!(!buf.copy_sub_array_if_changed(1, &[2]));
+		assert_eq!(buf.copy_chunks_if_changed(2, &[[3], [0]]), 1);
+		buf.scatter_sub_array(&[3], [4]);
+		let (src, dst) = buf.split_read_write::<1>(0, 7).unwrap();
+		dst.copy_from_slice(src);
+		buf.split_pair_mut::<1, 1>(5, 6).unwrap().swap();
+		buf.sub_array_select_nth_unstable::<2>(0, 0);
+		assert_eq!(buf.as_ref(), [2, 9, 3, 4, 5, 7, 6, 9]);
+	}
+
+	#[test]
+	fn method_surface_indirect() {
+		let mut arr = [1, 2, 3, 4, 5, 6, 7, 8_u8];
+		method_surface(&mut arr);
+
+		let mut arr = [1, 2, 3, 4, 5, 6, 7, 8_u8];
+		method_surface(&mut &mut &mut arr);
+		assert_eq!(arr, [2, 9, 3, 4, 5, 7, 6, 9]);
+
+		let mut arr = [1, 2, 3, 4, 5, 6, 7, 8_u8];
+		method_surface(&mut arr[..]);
+		assert_eq!(arr, [2, 9, 3, 4, 5, 7, 6, 9]);
+
+		#[cfg(feature = "alloc")]
+		method_surface(&mut Vec::from([1, 2, 3, 4, 5, 6, 7, 8_u8]));
+		method_surface(&mut crate::TypedBuf::<(), _>::new([
+			1, 2, 3, 4, 5, 6, 7, 8_u8,
+		]));
+	}
+
+	/// Exercises the read-only method surface generically, on a buffer of
+	/// `1..=8`
+	fn method_surface_ref<B>(buf: &B)
+	where
+		B: SubArrayRef<Item = u8> + AsRef<[u8]> + ?Sized,
+	{
+		assert_eq!(buf.len(), 8);
+		assert_eq!(buf.sub_array_ref::<2>(1), &[2, 3]);
+		assert_eq!(buf.try_sub_array_ref::<2>(7), None);
+		assert_eq!(buf.copy_sub_array::<2>(6), [7, 8]);
+		assert_eq!(buf.try_copy_sub_array::<1>(8), None);
+		assert_eq!(buf.sub_array_ref_clamped::<4>(6), &[5, 6, 7, 8]);
+		assert_eq!(buf.sub_array_centered_ref::<3>(4), &[4, 5, 6]);
+		assert_eq!(
+			buf.match_indices_sub_array(&[3, 4]).collect::<Vec<_>>(),
+			[2]
+		);
+		assert_eq!(buf.sub_array_each_ref::<2>(6), [&7, &8]);
+		assert_eq!(buf.cmp_sub_arrays::<2>(0, 2), Ordering::Less);
+		assert_eq!(buf.sub_array_min::<3>(2), 3);
+		assert_eq!(buf.sub_array_max::<3>(2), 5);
+		assert_eq!(buf.try_sub_array_sum::<4>(0), Some(10));
+		assert_eq!(buf.sub_array_windows_max_sum::<2>(), (6, 15));
+		assert_eq!(buf.gather_sub_array(&[7, 0]), [8, 1]);
+		assert_eq!(buf.sub_array_chunks::<3>().count(), 2);
+		assert_eq!(buf.sub_array_iter::<4>().nth(1), Some((4, &[5, 6, 7, 8])));
+		assert_eq!(buf.sub_array_records::<2>(2, 3).count(), 3);
+		assert_eq!(buf.sub_array_group_by::<8, _>(0, |a, b| a < b).count(), 1);
+		assert_eq!(buf.split_by_sub_array(&[4]).count(), 2);
+		assert!(buf.validate_sub_array::<8>(0, |_, &b| b > 0).is_ok());
+	}
 
+	#[test]
+	fn method_surface_shared() {
+		let arr = [1, 2, 3, 4, 5, 6, 7, 8_u8];
+		method_surface_ref(&arr);
+		method_surface_ref(&&arr);
 		let slice: &[u8] = &arr;
+		method_surface_ref(&slice);
 
-		let arr_ref = test_by_slice(slice);
+		#[cfg(feature = "alloc")]
+		method_surface_ref(&Rc::<[u8]>::from(&arr[..]));
+		#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+		method_surface_ref(&alloc::sync::Arc::<[u8]>::from(&arr[..]));
+	}
 
-		assert_eq!(arr_ref, &[5, 6, 7]);
-		assert_eq!(arr_ref, arr.sub_array_ref(4));
-		assert_eq!(arr_ref, &slice[4..7]);
+	/// Multiplication in GF(2^8) with the AES polynomial, bit by bit
+	fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+		let mut product = 0;
+		while b != 0 {
+			if b & 1 != 0 {
+				product ^= a;
+			}
+			let carry = a & 0x80 != 0;
+			a <<= 1;
+			if carry {
+				a ^= 0x1B;
+			}
+			b >>= 1;
+		}
+		product
+	}
+
+	/// Multiplication in GF(2^8) with the AES polynomial, via the logarithm
+	/// tables of the generator `3`
+	fn gf_mul_reference(a: u8, b: u8) -> u8 {
+		let mut exp = [0_u8; 255];
+		let mut log = [0_u8; 256];
+		let mut x = 1_u8;
+		for (i, e) in exp.iter_mut().enumerate() {
+			*e = x;
+			log[usize::from(x)] = i as u8;
+			x ^= if x & 0x80 != 0 {
+				(x << 1) ^ 0x1B
+			} else {
+				x << 1
+			};
+		}
+
+		if a == 0 || b == 0 {
+			0
+		} else {
+			exp[(usize::from(log[usize::from(a)]) + usize::from(log[usize::from(b)])) % 255]
+		}
+	}
+
+	#[test]
+	fn chunks_product_gf256() {
+		let mut blocks = [0_u8; 16 * 4 + 3];
+		for (i, b) in blocks.iter_mut().enumerate() {
+			*b = (i as u8).wrapping_mul(37).wrapping_add(11);
+		}
+
+		let product = blocks.sub_array_chunks_product([1; 16], |acc, block| {
+			core::array::from_fn(|i| gf_mul(acc[i], block[i]))
+		});
+
+		let mut expected = [1_u8; 16];
+		for block in blocks.chunks_exact(16) {
+			for (e, &b) in expected.iter_mut().zip(block) {
+				*e = gf_mul_reference(*e, b);
+			}
+		}
+		assert_eq!(product, expected);
+		assert_eq!(gf_mul(0x57, 0x83), 0xC1);
+	}
+
+	#[test]
+	fn chunks_product_short() {
+		let arr = [2, 3, 4_u32];
+		assert_eq!(arr.sub_array_chunks_product([5; 4], |_, b| b), [5; 4]);
+		assert_eq!(
+			arr.sub_array_chunks_product([1], |a, b| [a[0] * b[0]]),
+			[24]
+		);
+	}
+
+	/// Sums the `N` items following `offset`, `N` may be zero
+	fn sum_window<A: SubArray<Item = u8> + ?Sized, const N: usize>(
+		arr: &A,
+		offset: usize,
+	) -> Option<u32> {
+		Some(
+			arr.try_sub_array_ref::<N>(offset)?
+				.iter()
+				.map(|&b| u32::from(b))
+				.sum(),
+		)
+	}
+
+	#[test]
+	fn empty_sub_arrays() {
+		let mut arr = [1, 2, 3_u8];
+
+		assert_eq!(arr.sub_array_ref::<0>(0), &[]);
+		assert_eq!(arr.sub_array_ref::<0>(3), &[]);
+		assert_eq!(arr.sub_array_mut::<0>(3), &mut []);
+		assert_eq!(arr.try_sub_array_ref::<0>(3), Some(&[]));
+		assert_eq!(arr.try_sub_array_ref::<0>(4), None);
+		assert_eq!(arr.try_sub_array_mut::<0>(4), None);
+		assert_eq!(arr.try_sub_array_ref::<0>(usize::MAX), None);
+
+		assert_eq!(arr.empty_sub_array_at(3), &[]);
+		assert_eq!(arr[..1].empty_sub_array_at(1), &[]);
+		assert_eq!(arr.try_empty_sub_array_at(0), Some(&[]));
+		assert_eq!(arr.try_empty_sub_array_at(4), None);
+
+		// Generic code behaves the same for `N == 0` as for any other `N`
+		assert_eq!(sum_window::<_, 0>(&arr, 3), Some(0));
+		assert_eq!(sum_window::<_, 0>(&arr, 4), None);
+		assert_eq!(sum_window::<_, 1>(&arr, 2), Some(3));
+		assert_eq!(sum_window::<_, 1>(&arr, 3), None);
+		assert_eq!(sum_window::<[u8], 0>(&arr[3..], 0), Some(0));
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 0 at offset 4 exceeds the array of length 3")]
+	fn empty_sub_array_past_the_end() {
+		let arr = [1, 2, 3_u8];
+		arr.empty_sub_array_at(4);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_length_sub_array_past_the_end() {
+		let arr = [1, 2, 3_u8];
+		arr.sub_array_ref::<0>(4);
+	}
+
+	#[test]
+	fn partition_point_insertion() {
+		// A sorted queue of event times in the middle of the buffer
+		let mut buf = [u32::MAX; 16];
+		*buf.sub_array_mut(2) = [1, 4, 4, 9, 10, 15, 20, 21, 21, 21, 30, 42];
+
+		for value in [0, 1, 4, 5, 21, 22, 42, 43] {
+			let index = buf.sub_array_partition_point::<12, _>(2, |&x| x < value);
+			let expected = buf[2..14].iter().position(|&x| x >= value).unwrap_or(12);
+			assert_eq!(index, expected, "insertion index of {value}");
+		}
+
+		assert_eq!(buf.sub_array_partition_point::<0, _>(16, |_| true), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn partition_point_out_of_bounds() {
+		let buf = [0_u32; 16];
+		buf.sub_array_partition_point::<12, _>(5, |&x| x < 1);
+	}
+
+	#[test]
+	fn regroup() {
+		let buf = [0, 1, 2, 3, 4, 5, 6_u8];
+
+		let pairs: [[u8; 2]; 3] = buf.regroup(1);
+		assert_eq!(pairs, [[1, 2], [3, 4], [5, 6]]);
+		assert_eq!(pairs.as_flattened(), buf.sub_array_ref::<6>(1));
+
+		let triples: [[u8; 3]; 2] = buf.regroup(0);
+		assert_eq!(triples, [[0, 1, 2], [3, 4, 5]]);
+		assert_eq!(buf.try_regroup::<2, 0>(7), Some([]));
+
+		assert_eq!(buf.try_regroup::<2, 3>(2), None);
+		assert_eq!(buf.try_regroup::<3, 1>(5), None);
+		assert_eq!(buf.try_regroup::<1, 1>(usize::MAX), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 6 at offset 1 exceeds the array of length 6")]
+	fn regroup_out_of_bounds() {
+		let buf = [0_u8; 6];
+		let _: [[u8; 2]; 3] = buf.regroup(1);
+	}
+
+	#[test]
+	fn decode_records() {
+		// A 4 byte header, followed by 3 records of an id and a value each
+		let mut table = [0_u8; 4 + 3 * 16];
+		for i in 0..3 {
+			let record = table.sub_array_mut::<16>(4 + i * 16);
+			*record.sub_array_mut(0) = (i as u64 + 1).to_le_bytes();
+			*record.sub_array_mut(8) = (i as u64 * 100).to_le_bytes();
+		}
+
+		let decode = |raw: &[u8; 16]| {
+			(
+				u64::from_le_bytes(*raw.sub_array_ref(0)),
+				u64::from_le_bytes(*raw.sub_array_ref(8)),
+			)
+		};
+		let mut records = table.records(4, 3, decode);
+		assert_eq!(records.len(), 3);
+		assert_eq!(records.next(), Some((1, 0)));
+		assert_eq!(records.next(), Some((2, 100)));
+		assert_eq!(records.next(), Some((3, 200)));
+		assert_eq!(records.next(), None);
+
+		assert!(table.try_records(5, 3, decode).is_none());
+		assert!(table.try_records(4, usize::MAX, decode).is_none());
+		assert!(table.try_records(4, usize::MAX / 8, decode).is_none());
+		assert_eq!(table.records(52, 0, decode).count(), 0);
+
+		// Increment the value of each record in-place, skipping the first
+		let ids: u64 = table
+			.records_mut(20, 2, |raw: &mut [u8; 16]| {
+				let value = u64::from_le_bytes(*raw.sub_array_ref(8));
+				*raw.sub_array_mut(8) = (value + 1).to_le_bytes();
+				u64::from_le_bytes(*raw.sub_array_ref(0))
+			})
+			.sum();
+		assert_eq!(ids, 5);
+		let values: [u64; 3] =
+			core::array::from_fn(|i| u64::from_le_bytes(*table.sub_array_ref(4 + i * 16 + 8)));
+		assert_eq!(values, [0, 101, 201]);
+		assert!(table
+			.try_records_mut(4, usize::MAX, |_: &mut [u8; 16]| ())
+			.is_none());
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 32 at offset 4 exceeds the array of length 20")]
+	fn decode_records_out_of_bounds() {
+		let table = [0_u8; 20];
+		let _ = table.records(4, 2, |raw: &[u8; 16]| raw[0]);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn sort_by_key_stable() {
+		let mut arr: [(&str, u32); 8] = [
+			("head", 0),
+			("d", 2),
+			("a", 1),
+			("e", 2),
+			("b", 1),
+			("f", 0),
+			("c", 1),
+			("tail", 0),
+		];
+
+		arr.sub_array_sort_by_key::<6, _, _>(1, |&(_, key)| key);
+
+		// Equal keys keep their relative order, the rest is untouched
+		let names: [&str; 8] = arr.map(|(name, _)| name);
+		assert_eq!(names, ["head", "f", "a", "b", "c", "d", "e", "tail"]);
+		assert!(arr.sub_array_ref::<6>(1).is_sorted_by_key(|&(_, key)| key));
+	}
+
+	#[test]
+	fn sort_unstable_by_key() {
+		let mut arr = [9, 5, 2, 8, 1, 9_u32];
+
+		arr.sub_array_sort_unstable_by_key::<4, _, _>(1, |&x| core::cmp::Reverse(x));
+		assert_eq!(arr, [9, 8, 5, 2, 1, 9]);
+		arr.sub_array_sort_unstable_by_key::<0, _, _>(6, |&x| x);
+	}
+
+	#[test]
+	#[should_panic]
+	fn sort_by_key_out_of_bounds() {
+		let mut arr = [3, 2, 1_u8];
+		arr.sub_array_sort_unstable_by_key::<3, _, _>(1, |&x| x);
+	}
+
+	#[test]
+	fn flatten_window() {
+		let buf = [0, 1, 2, 3, 4, 5, 6, 7, 8_u8];
+		let rows: [[u8; 2]; 4] = buf.regroup(1);
+
+		let flat: [u8; 6] = rows.flatten_window_copy::<_, 2, 3, 6>(1);
+		assert_eq!(&flat, buf.sub_array_ref::<6>(3));
+		assert_eq!(
+			rows.flatten_window_copy::<_, 2, 4, 8>(0),
+			*buf.sub_array_ref(1)
+		);
+		assert_eq!(rows[..].try_flatten_window_copy::<_, 2, 0, 0>(4), Some([]));
+
+		assert_eq!(rows.try_flatten_window_copy::<_, 2, 2, 4>(3), None);
+		assert_eq!(rows.try_flatten_window_copy::<_, 2, 1, 2>(usize::MAX), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 2 exceeds the array of length 3")]
+	fn flatten_window_out_of_bounds() {
+		let rows = [[1, 2_u8]; 3];
+		rows.flatten_window_copy::<_, 2, 2, 4>(2);
+	}
+
+	#[test]
+	fn fold_and_xor() {
+		let buf: [u8; 32] = core::array::from_fn(|i| (i * 37 + 11) as u8);
+
+		let mut xor = 0;
+		let mut sum = 0_u32;
+		for &b in &buf[3..(3 + 20)] {
+			xor ^= b;
+			sum += u32::from(b);
+		}
+		assert_eq!(buf.xor_sub_array::<20>(3), xor);
+		assert_eq!(
+			buf.fold_sub_array::<20, _>(3, 0, |acc, &b| acc + u32::from(b)),
+			sum
+		);
+		assert_eq!(buf.try_xor_sub_array::<20>(3), Some(xor));
+		assert_eq!(
+			buf.try_fold_sub_array::<20, _>(3, 0, |acc, &b| acc + u32::from(b)),
+			Some(sum)
+		);
+
+		// The empty window
+		assert_eq!(buf.xor_sub_array::<0>(32), 0);
+		assert_eq!(buf.fold_sub_array::<0, _>(32, 7, |_, _| unreachable!()), 7);
+
+		assert_eq!(buf.try_xor_sub_array::<20>(13), None);
+		assert_eq!(
+			buf.try_fold_sub_array::<1, _>(usize::MAX, 0, |acc, _| acc),
+			None
+		);
+	}
+
+	#[test]
+	fn ipv4_header_checksum() {
+		// An IPv4 header, whose checksum field at bytes 10 and 11 is excluded
+		let header: [u8; 20] = [
+			0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xB8, 0x61, 0xC0, 0xA8,
+			0x00, 0x01, 0xC0, 0xA8, 0x00, 0xC7,
+		];
+		let words: [[u8; 2]; 10] = header.regroup(0);
+		let ones_complement = |acc: u32, word: &[u8; 2]| {
+			let acc = acc + u32::from(u16::from_be_bytes(*word));
+			(acc & 0xFFFF) + (acc >> 16)
+		};
+
+		let sum = words.fold_sub_array::<5, _>(0, 0, ones_complement);
+		let sum = words.fold_sub_array::<4, _>(6, sum, ones_complement);
+		assert_eq!(!(sum as u16), u16::from_be_bytes(*header.sub_array_ref(10)));
+	}
+
+	#[test]
+	#[should_panic]
+	fn xor_out_of_bounds() {
+		let buf = [0_u8; 4];
+		buf.xor_sub_array::<4>(1);
+	}
+
+	#[test]
+	fn chunks_collect_map() {
+		let arr: [u8; 10] = core::array::from_fn(|i| i as u8);
+		let first = |chunk: &[u8; 3]| u32::from(chunk[0]);
+
+		// Exactly the size needed, the remainder is not a whole chunk
+		let mut out = [u32::MAX; 3];
+		assert_eq!(arr.sub_array_chunks_collect_map(&mut out, first), 3);
+		assert_eq!(out, [0, 3, 6]);
+
+		// Stops early at the end of the output
+		let mut out = [u32::MAX; 2];
+		let mut calls = 0;
+		let count = arr.sub_array_chunks_collect_map(&mut out, |chunk: &[u8; 3]| {
+			calls += 1;
+			first(chunk)
+		});
+		assert_eq!((count, calls), (2, 2));
+		assert_eq!(out, [0, 3]);
+
+		// Leaves the rest of an oversized output untouched
+		let mut out = [u32::MAX; 5];
+		assert_eq!(arr.sub_array_chunks_collect_map(&mut out, first), 3);
+		assert_eq!(out, [0, 3, 6, u32::MAX, u32::MAX]);
+		assert_eq!(arr.sub_array_chunks_collect_map(&mut [], first), 0);
+	}
+
+	#[cfg(feature = "release-unchecked")]
+	#[allow(unsafe_code)]
+	#[test]
+	fn unchecked() {
+		let mut arr = [1, 2, 3, 4, 5_u8];
+
+		// SAFETY: all of these are in bounds
+		unsafe {
+			assert_eq!(arr.sub_array_ref_unchecked::<2>(3), &[4, 5]);
+			assert_eq!(arr[1..].sub_array_ref_unchecked::<0>(4), &[]);
+			*arr.sub_array_mut_unchecked::<3>(0) = [7; 3];
+		}
+		assert_eq!(arr, [7, 7, 7, 4, 5]);
+	}
+
+	#[cfg(all(feature = "release-unchecked", debug_assertions))]
+	#[allow(unsafe_code)]
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 4 exceeds the array of length 5")]
+	fn unchecked_out_of_bounds_in_debug() {
+		let arr = [1, 2, 3, 4, 5_u8];
+		// SAFETY: not sound, but debug builds are guaranteed to panic before
+		unsafe {
+			arr.sub_array_ref_unchecked::<2>(4);
+		}
+	}
+
+	#[cfg(all(feature = "release-unchecked", debug_assertions))]
+	#[allow(unsafe_code)]
+	#[test]
+	// The offset is not spelled out, since it depends on the pointer width
+	#[should_panic(expected = "exceeds the array of length 5")]
+	fn unchecked_mut_overflow_in_debug() {
+		let mut arr = [1, 2, 3, 4, 5_u8];
+		// SAFETY: not sound, but debug builds are guaranteed to panic before
+		unsafe {
+			arr.sub_array_mut_unchecked::<2>(usize::MAX);
+		}
+	}
+
+	#[test]
+	fn max_valid_offset() {
+		let arr = [1, 2, 3, 4, 5_u8];
+
+		assert_eq!(arr.max_valid_offset::<0>(), Some(5));
+		assert_eq!(arr.max_valid_offset::<2>(), Some(3));
+		assert_eq!(arr.max_valid_offset::<5>(), Some(0));
+		assert_eq!(arr.max_valid_offset::<6>(), None);
+		assert_eq!(arr[..0].max_valid_offset::<1>(), None);
+
+		// The largest offset is always in bounds, one past it never is
+		let offset = arr.max_valid_offset::<2>().unwrap();
+		assert_eq!(arr.try_sub_array_ref::<2>(offset), Some(&[4, 5]));
+		assert_eq!(arr.try_sub_array_ref::<2>(offset + 1), None);
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn into_iter() {
+		let arr = [1, 2, 3, 4, 5_u8];
+
+		let window: Vec<u8> = arr.sub_array_into_iter::<3>(1).collect();
+		assert_eq!(window, [2, 3, 4]);
+		let reversed: Vec<u8> = arr[..].sub_array_into_iter::<2>(3).rev().collect();
+		assert_eq!(reversed, [5, 4]);
+		assert_eq!(arr.sub_array_into_iter::<0>(5).len(), 0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn into_iter_out_of_bounds() {
+		let arr = [1, 2, 3_u8];
+		let _ = arr.sub_array_into_iter::<2>(2);
+	}
+
+	/// Plans the extraction via `len` alone, without further bounds
+	fn tail<A: SubArray<Item = u8> + ?Sized, const N: usize>(
+		buf: &A,
+		offset: usize,
+	) -> Option<[u8; N]> {
+		if buf.len() >= offset + N {
+			Some(buf.copy_sub_array(offset))
+		} else {
+			None
+		}
+	}
+
+	#[test]
+	fn len() {
+		let mut arr = [1, 2, 3, 4, 5_u8];
+		assert_eq!(SubArray::len(&arr), 5);
+		assert_eq!(tail::<_, 2>(&arr, 3), Some([4, 5]));
+		assert_eq!(tail::<_, 2>(&arr, 4), None);
+
+		let slice: &[u8] = &arr[1..];
+		assert_eq!(SubArray::len(slice), 4);
+		assert_eq!(tail::<_, 4>(slice, 0), Some([2, 3, 4, 5]));
+		assert!(SubArray::is_empty(&arr[5..]));
+
+		let by_ref = &mut arr;
+		assert_eq!(tail::<_, 1>(&by_ref, 4), Some([5]));
+		assert_eq!(SubArray::len(&by_ref), 5);
+		assert_eq!(by_ref.max_valid_offset::<2>(), Some(3));
+
+		let mut empty: [u8; 0] = [];
+		assert!(SubArray::is_empty(&&mut empty));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn len_alloc() {
+		let vec = alloc::vec![1, 2, 3_u8];
+		assert_eq!(tail::<_, 3>(&vec, 0), Some([1, 2, 3]));
+		assert_eq!(tail::<_, 3>(&vec, 1), None);
+
+		let boxed: alloc::boxed::Box<[u8]> = vec.into_boxed_slice();
+		assert_eq!(SubArray::len(&boxed), 3);
+		assert_eq!(tail::<_, 2>(&boxed, 1), Some([2, 3]));
+
+		let boxed_vec = alloc::boxed::Box::new(Vec::<u8>::new());
+		assert!(SubArray::is_empty(&boxed_vec));
 	}
 }
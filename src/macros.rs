@@ -0,0 +1,120 @@
+//! Macros splitting arrays into several sub-arrays
+
+/// Splits a mutable buffer into consecutive mutable sub-arrays of the given
+/// lengths.
+///
+/// This expands to a chain of [`split_at_mut`](slice::split_at_mut) calls
+/// starting at offset `0`, thus it is entirely safe, and evaluates to a tuple
+/// with one `&mut [T; LEN]` for each of the given lengths. It is similar to
+/// `mut_array_refs!` of the `arrayref` crate, which eases migrating from it.
+/// However, the buffer may be longer than the sum of the lengths, any
+/// trailing elements are not part of the result.
+///
+/// The lengths must be integer literals, otherwise this fails to compile.
+///
+/// # Panics
+/// Panics if the sum of the lengths exceeds the length of the buffer.
+///
+/// # Example
+/// ```
+/// use sub_array::sub_array_mut_refs;
+///
+/// let mut buf = [0_u8; 10];
+///
+/// let (a, b, c) = sub_array_mut_refs!(buf, 2, 4, 4);
+/// *a = [1; 2];
+/// *b = [2; 4];
+/// c[0] = 3;
+///
+/// assert_eq!(buf, [1, 1, 2, 2, 2, 2, 3, 0, 0, 0]);
+/// ```
+///
+/// Lengths that are not literals are rejected:
+/// ```compile_fail
+/// use sub_array::sub_array_mut_refs;
+///
+/// const LEN: usize = 2;
+/// let mut buf = [0_u8; 10];
+///
+/// let (a, b) = sub_array_mut_refs!(buf, LEN, 4);
+/// ```
+#[macro_export]
+macro_rules! sub_array_mut_refs {
+	($buf:expr, $($len:literal),+ $(,)?) => {{
+		let rest: &mut [_] = &mut $buf[..];
+		$crate::__sub_array_mut_refs!(rest; []; $($len),+)
+	}};
+	($buf:expr $(, $len:expr)* $(,)?) => {
+		compile_error!("the lengths of `sub_array_mut_refs!` must be integer literals")
+	};
+}
+
+/// Implementation detail of [`sub_array_mut_refs!`], splits off one sub-array
+/// after the other from `$rest`, accumulating them in `[$done]`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sub_array_mut_refs {
+	($rest:ident; [$($done:ident),*]; $len:literal $(, $tail:literal)*) => {{
+		let (head, rest) = $rest.split_at_mut($len);
+		let head: &mut [_; $len] = head.try_into().unwrap();
+		$crate::__sub_array_mut_refs!(rest; [$($done,)* head]; $($tail),*)
+	}};
+	($rest:ident; [$($done:ident),*]; ) => {{
+		let _ = $rest;
+		($($done,)*)
+	}};
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn distinct_values() {
+		let mut buf = [0_u8; 10];
+
+		let (a, b, c): (&mut [u8; 2], &mut [u8; 4], &mut [u8; 4]) =
+			sub_array_mut_refs!(buf, 2, 4, 4);
+		*a = [1, 2];
+		*b = [3, 4, 5, 6];
+		*c = [7, 8, 9, 10];
+		a[0] = 0;
+
+		assert_eq!(buf, [0, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+	}
+
+	#[test]
+	fn single_and_empty() {
+		let mut buf = [0_u8; 4];
+		let buf_ref = &mut buf;
+
+		let (a,) = sub_array_mut_refs!(buf_ref, 3);
+		*a = [1, 2, 3];
+		let (a, b, c) = sub_array_mut_refs!(buf_ref, 1, 0, 3,);
+		a[0] = 4;
+		assert_eq!(b, &[]);
+		c[2] = 5;
+
+		assert_eq!(buf, [4, 2, 3, 5]);
+	}
+
+	#[test]
+	fn on_slices() {
+		let mut buf = [0_u8; 5];
+		let slice: &mut [u8] = &mut buf;
+
+		let (a, b) = sub_array_mut_refs!(slice, 2, 2);
+		core::mem::swap(&mut a[0], &mut b[1]);
+		a[0] = 1;
+		b[1] = 2;
+
+		assert_eq!(buf, [1, 0, 0, 2, 0]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn too_short() {
+		let mut buf = [0_u8; 5];
+		let _ = sub_array_mut_refs!(buf, 2, 4);
+	}
+}
@@ -0,0 +1,113 @@
+//! Helpers for square matrices made of nested arrays
+
+
+/// Square matrix, i.e. an array of arrays of the same length
+///
+/// This is implemented for `[[T; N]; N]`, where the outer array contains the
+/// rows of the matrix. Unlike the [`SubArray`](crate::SubArray) methods,
+/// which extract contiguous elements, these helpers gather elements from
+/// different rows.
+pub trait SquareMatrix<const N: usize> {
+	/// The value type of this matrix.
+	///
+	/// This is the `T` in `[[T; N]; N]`.
+	type Item;
+
+	/// Get a copy of the main diagonal of this matrix, i.e. the elements from
+	/// the top left to the bottom right.
+	///
+	/// Since the matrix is square, each row `i` has an element at column `i`,
+	/// thus this never panics.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SquareMatrix;
+	///
+	/// let mat = [
+	///     [1, 2, 3], //
+	///     [4, 5, 6],
+	///     [7, 8, 9],
+	/// ];
+	///
+	/// assert_eq!(mat.diagonal(), [1, 5, 9]);
+	/// ```
+	fn diagonal(&self) -> [Self::Item; N]
+	where
+		Self::Item: Copy;
+
+	/// Get a copy of the anti-diagonal of this matrix, i.e. the elements from
+	/// the top right to the bottom left.
+	///
+	/// Since the matrix is square, each row `i` has an element at column
+	/// `N - 1 - i`, thus this never panics.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SquareMatrix;
+	///
+	/// let mat = [
+	///     [1, 2, 3], //
+	///     [4, 5, 6],
+	///     [7, 8, 9],
+	/// ];
+	///
+	/// assert_eq!(mat.anti_diagonal(), [3, 5, 7]);
+	/// ```
+	fn anti_diagonal(&self) -> [Self::Item; N]
+	where
+		Self::Item: Copy;
+}
+
+/// Implementation on nested arrays
+impl<T, const N: usize> SquareMatrix<N> for [[T; N]; N] {
+	type Item = T;
+
+	fn diagonal(&self) -> [Self::Item; N]
+	where
+		Self::Item: Copy,
+	{
+		core::array::from_fn(|i| self[i][i])
+	}
+
+	fn anti_diagonal(&self) -> [Self::Item; N]
+	where
+		Self::Item: Copy,
+	{
+		core::array::from_fn(|i| self[i][N - 1 - i])
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	const MATRIX: [[u8; 3]; 3] = [
+		[1, 2, 3], //
+		[4, 5, 6],
+		[7, 8, 9],
+	];
+
+	#[test]
+	fn diagonal() {
+		assert_eq!(MATRIX.diagonal(), [1, 5, 9]);
+	}
+
+	#[test]
+	fn anti_diagonal() {
+		assert_eq!(MATRIX.anti_diagonal(), [3, 5, 7]);
+	}
+
+	#[test]
+	fn trivial() {
+		let empty: [[u8; 0]; 0] = [];
+		assert_eq!(empty.diagonal(), []);
+		assert_eq!(empty.anti_diagonal(), []);
+
+		let single = [[42_u8]];
+		assert_eq!(single.diagonal(), [42]);
+		assert_eq!(single.anti_diagonal(), [42]);
+	}
+}
@@ -0,0 +1,305 @@
+//! Helpers for network addresses in byte arrays
+
+use core::net::Ipv4Addr;
+use core::net::Ipv6Addr;
+use core::net::SocketAddrV4;
+
+use crate::SubArray;
+use crate::SubArrayRef;
+
+
+/// Byte array that contains network addresses
+///
+/// This trait is implemented for every [`SubArray`] of `u8`, thus it is
+/// sufficient to import it to use its methods, there is no need to implement
+/// it manually.
+///
+/// All addresses are in network byte order, i.e. their octets are stored in
+/// the order of [`Ipv4Addr::octets`] and [`Ipv6Addr::octets`], and ports are
+/// big-endian.
+pub trait NetSubArray: SubArray<Item = u8> {
+	/// Reads the IPv4 address made of the 4 bytes starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + 4` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use std::net::Ipv4Addr;
+	///
+	/// use sub_array::NetSubArray;
+	///
+	/// let arr: [u8; 6] = [0xFF, 192, 168, 0, 1, 0xFF];
+	///
+	/// assert_eq!(arr.read_ipv4(1), Ipv4Addr::new(192, 168, 0, 1));
+	/// ```
+	fn read_ipv4(&self, offset: usize) -> Ipv4Addr {
+		Ipv4Addr::from(self.copy_sub_array::<4>(offset))
+	}
+
+	/// Reads the IPv4 address made of the 4 bytes starting at `offset`, if it
+	/// is in bounds.
+	///
+	/// Returns `None` if `offset + 4` exceeds the length of this array.
+	fn try_read_ipv4(&self, offset: usize) -> Option<Ipv4Addr> {
+		self.try_copy_sub_array::<4>(offset).map(Ipv4Addr::from)
+	}
+
+	/// Writes the octets of `addr` into the 4 bytes starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + 4` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use std::net::Ipv4Addr;
+	///
+	/// use sub_array::NetSubArray;
+	///
+	/// let mut arr = [0_u8; 5];
+	/// arr.write_ipv4(1, Ipv4Addr::new(10, 0, 0, 1));
+	///
+	/// assert_eq!(arr, [0, 10, 0, 0, 1]);
+	/// ```
+	fn write_ipv4(&mut self, offset: usize, addr: Ipv4Addr) {
+		*self.sub_array_mut(offset) = addr.octets();
+	}
+
+	/// Writes the octets of `addr` into the 4 bytes starting at `offset`, if
+	/// they are in bounds.
+	///
+	/// Returns `None`, without writing anything, if `offset + 4` exceeds the
+	/// length of this array.
+	fn try_write_ipv4(&mut self, offset: usize, addr: Ipv4Addr) -> Option<()> {
+		*self.try_sub_array_mut(offset)? = addr.octets();
+		Some(())
+	}
+
+	/// Reads the IPv6 address made of the 16 bytes starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + 16` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use std::net::Ipv6Addr;
+	///
+	/// use sub_array::NetSubArray;
+	///
+	/// let mut arr = [0_u8; 20];
+	/// arr[19] = 1;
+	///
+	/// assert_eq!(arr.read_ipv6(4), Ipv6Addr::LOCALHOST);
+	/// ```
+	fn read_ipv6(&self, offset: usize) -> Ipv6Addr {
+		Ipv6Addr::from(self.copy_sub_array::<16>(offset))
+	}
+
+	/// Reads the IPv6 address made of the 16 bytes starting at `offset`, if
+	/// it is in bounds.
+	///
+	/// Returns `None` if `offset + 16` exceeds the length of this array.
+	fn try_read_ipv6(&self, offset: usize) -> Option<Ipv6Addr> {
+		self.try_copy_sub_array::<16>(offset).map(Ipv6Addr::from)
+	}
+
+	/// Writes the octets of `addr` into the 16 bytes starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + 16` exceeds the length of this array.
+	fn write_ipv6(&mut self, offset: usize, addr: Ipv6Addr) {
+		*self.sub_array_mut(offset) = addr.octets();
+	}
+
+	/// Writes the octets of `addr` into the 16 bytes starting at `offset`, if
+	/// they are in bounds.
+	///
+	/// Returns `None`, without writing anything, if `offset + 16` exceeds the
+	/// length of this array.
+	fn try_write_ipv6(&mut self, offset: usize, addr: Ipv6Addr) -> Option<()> {
+		*self.try_sub_array_mut(offset)? = addr.octets();
+		Some(())
+	}
+
+	/// Reads the MAC address made of the 6 bytes starting at `offset`.
+	///
+	/// The bytes are returned in the order they are stored, which is the
+	/// order in which they are usually written, e.g. `00:1A:2B:...`.
+	///
+	/// # Panics
+	/// Panics if `offset + 6` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::NetSubArray;
+	///
+	/// let frame: [u8; 8] = [0xFF, 0xFF, 0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+	///
+	/// assert_eq!(frame.read_mac(2), [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+	/// ```
+	fn read_mac(&self, offset: usize) -> [u8; 6] {
+		self.copy_sub_array(offset)
+	}
+
+	/// Reads the MAC address made of the 6 bytes starting at `offset`, if it
+	/// is in bounds.
+	///
+	/// Returns `None` if `offset + 6` exceeds the length of this array.
+	fn try_read_mac(&self, offset: usize) -> Option<[u8; 6]> {
+		self.try_copy_sub_array(offset)
+	}
+
+	/// Reads the IPv4 socket address made of the 4 byte address followed by
+	/// the 2 byte big-endian port starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + 6` exceeds the length of this array.
+	///
+	/// # Example
+	/// ```
+	/// use std::net::Ipv4Addr;
+	/// use std::net::SocketAddrV4;
+	///
+	/// use sub_array::NetSubArray;
+	///
+	/// let arr: [u8; 6] = [127, 0, 0, 1, 0x1F, 0x90];
+	///
+	/// assert_eq!(
+	///     arr.read_socketaddr_v4(0),
+	///     SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)
+	/// );
+	/// ```
+	fn read_socketaddr_v4(&self, offset: usize) -> SocketAddrV4 {
+		socketaddr_v4(self.copy_sub_array(offset))
+	}
+
+	/// Reads the IPv4 socket address made of the 4 byte address followed by
+	/// the 2 byte big-endian port starting at `offset`, if it is in bounds.
+	///
+	/// Returns `None` if `offset + 6` exceeds the length of this array.
+	fn try_read_socketaddr_v4(&self, offset: usize) -> Option<SocketAddrV4> {
+		self.try_copy_sub_array(offset).map(socketaddr_v4)
+	}
+}
+
+impl<A> NetSubArray for A where A: SubArray<Item = u8> + ?Sized {}
+
+/// Decodes an IPv4 address followed by a big-endian port
+fn socketaddr_v4(bytes: [u8; 6]) -> SocketAddrV4 {
+	let [a, b, c, d, port @ ..] = bytes;
+	SocketAddrV4::new(Ipv4Addr::new(a, b, c, d), u16::from_be_bytes(port))
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn ipv4() {
+		let arr = [1, 10, 0, 0, 42, 2_u8];
+		assert_eq!(arr.read_ipv4(1), Ipv4Addr::new(10, 0, 0, 42));
+		assert_eq!(arr.read_ipv4(2).octets(), [0, 0, 42, 2]);
+		assert_eq!(arr.try_read_ipv4(0), Some(Ipv4Addr::new(1, 10, 0, 0)));
+		assert_eq!(arr.try_read_ipv4(3), None);
+	}
+
+	#[test]
+	fn ipv6() {
+		let addr = Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0xFF00, 0x42, 0x8329);
+		let mut arr = [0_u8; 18];
+		arr[1..17].copy_from_slice(&addr.octets());
+
+		let slice: &[u8] = &arr;
+		assert_eq!(slice.read_ipv6(1), addr);
+		assert_eq!(slice.try_read_ipv6(1), Some(addr));
+		assert_eq!(slice.try_read_ipv6(3), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn ipv4_out_of_bounds() {
+		let arr = [0_u8; 4];
+		arr.read_ipv4(1);
+	}
+
+	/// A fake IPv4 header with some trailing space
+	fn packet() -> [u8; 40] {
+		let mut packet = [0_u8; 40];
+		packet[0] = 0x45;
+		packet[8] = 64;
+		packet
+	}
+
+	#[test]
+	fn round_trip() {
+		let src = Ipv4Addr::new(192, 0, 2, 1);
+		let dst = Ipv4Addr::new(198, 51, 100, 7);
+		let six = Ipv6Addr::new(0x2001, 0xDB8, 1, 2, 3, 4, 5, 6);
+
+		let mut packet = packet();
+		packet.write_ipv4(12, src);
+		packet.write_ipv4(16, dst);
+		packet.write_ipv6(21, six);
+
+		assert_eq!(packet.sub_array_ref::<4>(12), &src.octets());
+		assert_eq!(packet.sub_array_ref::<4>(16), &dst.octets());
+		assert_eq!(packet.sub_array_ref::<16>(21), &six.octets());
+		assert_eq!(packet[..2], [0x45, 0]);
+		assert_eq!(packet[37..], [0; 3]);
+
+		assert_eq!(packet.read_ipv4(12), src);
+		assert_eq!(packet.read_ipv4(16), dst);
+		assert_eq!(packet.read_ipv6(21), six);
+	}
+
+	#[test]
+	fn try_write() {
+		let mut packet = packet();
+
+		assert_eq!(packet.try_write_ipv4(37, Ipv4Addr::BROADCAST), None);
+		assert_eq!(packet.try_write_ipv6(25, Ipv6Addr::LOCALHOST), None);
+		assert_eq!(packet, self::packet());
+
+		assert_eq!(packet.try_write_ipv4(36, Ipv4Addr::BROADCAST), Some(()));
+		assert_eq!(packet.try_write_ipv6(20, Ipv6Addr::LOCALHOST), Some(()));
+		assert_eq!(packet.try_read_ipv4(36), Some(Ipv4Addr::BROADCAST));
+		assert_eq!(packet.try_read_ipv6(20), Some(Ipv6Addr::LOCALHOST));
+	}
+
+	#[test]
+	fn mac() {
+		let frame = [0xFF, 0x02, 0x42, 0xAC, 0x11, 0x00, 0x02, 0xFF_u8];
+		assert_eq!(frame.read_mac(1), [0x02, 0x42, 0xAC, 0x11, 0x00, 0x02]);
+		assert_eq!(
+			frame.try_read_mac(2),
+			Some([0x42, 0xAC, 0x11, 0x00, 0x02, 0xFF])
+		);
+		assert_eq!(frame.try_read_mac(3), None);
+	}
+
+	#[test]
+	fn socketaddr() {
+		let addr = SocketAddrV4::new(Ipv4Addr::new(10, 1, 2, 3), 0xABCD);
+
+		let mut packet = packet();
+		packet.write_ipv4(30, *addr.ip());
+		*packet.sub_array_mut(34) = addr.port().to_be_bytes();
+
+		assert_eq!(packet.read_socketaddr_v4(30), addr);
+		assert_eq!(
+			packet.try_read_socketaddr_v4(34),
+			Some(SocketAddrV4::new(Ipv4Addr::new(0xAB, 0xCD, 0, 0), 0))
+		);
+		assert_eq!(packet.try_read_socketaddr_v4(35), None);
+	}
+
+	#[test]
+	#[should_panic]
+	fn write_out_of_bounds() {
+		let mut arr = [0_u8; 15];
+		arr.write_ipv6(0, Ipv6Addr::UNSPECIFIED);
+	}
+}
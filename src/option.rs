@@ -0,0 +1,224 @@
+//! Sub-arrays of optional, e.g. lazily initialized, arrays
+
+use core::fmt;
+
+use crate::SubArray;
+
+
+/// Error getting a sub-array of an optional array
+///
+/// Returned by [`checked_sub_array_of_option`] and
+/// [`checked_sub_array_of_option_mut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionSubArrayError {
+	/// The array is absent, i.e. the option is `None`
+	Absent,
+	/// The array is present, but too short for the sub-array
+	OutOfBounds,
+}
+
+impl fmt::Display for OptionSubArrayError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Absent => write!(f, "array is absent"),
+			Self::OutOfBounds => write!(f, "sub-array out of bounds"),
+		}
+	}
+}
+
+impl core::error::Error for OptionSubArrayError {}
+
+
+/// Get a reference to the sub-array of length `N` starting at `offset` of the
+/// array in `opt`, if there is one and the sub-array is in bounds.
+///
+/// Use [`checked_sub_array_of_option`] to tell these two cases apart.
+///
+/// # Example
+/// ```
+/// use sub_array::sub_array_of_option;
+///
+/// let mut buffer: Option<[u8; 4]> = None;
+/// assert_eq!(sub_array_of_option::<2, _>(&buffer, 1), None);
+///
+/// buffer = Some([1, 2, 3, 4]);
+/// assert_eq!(sub_array_of_option(&buffer, 1), Some(&[2, 3]));
+/// assert_eq!(sub_array_of_option::<2, _>(&buffer, 3), None);
+/// ```
+pub fn sub_array_of_option<const N: usize, A>(
+	opt: &Option<A>,
+	offset: usize,
+) -> Option<&[A::Item; N]>
+where
+	A: SubArray,
+{
+	opt.as_ref()?.try_sub_array_ref(offset)
+}
+
+/// Get a mutable reference to the sub-array of length `N` starting at
+/// `offset` of the array in `opt`, if there is one and the sub-array is in
+/// bounds.
+///
+/// Use [`checked_sub_array_of_option_mut`] to tell these two cases apart.
+pub fn sub_array_of_option_mut<const N: usize, A>(
+	opt: &mut Option<A>,
+	offset: usize,
+) -> Option<&mut [A::Item; N]>
+where
+	A: SubArray,
+{
+	opt.as_mut()?.try_sub_array_mut(offset)
+}
+
+/// Get a reference to the sub-array of length `N` starting at `offset` of the
+/// array in `opt`.
+///
+/// # Errors
+/// Returns [`OptionSubArrayError::Absent`] if `opt` is `None`, and
+/// [`OptionSubArrayError::OutOfBounds`] if `offset + N` exceeds the length of
+/// the array.
+///
+/// # Example
+/// ```
+/// use sub_array::checked_sub_array_of_option;
+/// use sub_array::OptionSubArrayError;
+///
+/// let buffer: Option<[u8; 4]> = Some([1, 2, 3, 4]);
+///
+/// assert_eq!(checked_sub_array_of_option(&buffer, 2), Ok(&[3, 4]));
+/// assert_eq!(
+///     checked_sub_array_of_option::<2, _>(&buffer, 3),
+///     Err(OptionSubArrayError::OutOfBounds)
+/// );
+/// ```
+pub fn checked_sub_array_of_option<const N: usize, A>(
+	opt: &Option<A>,
+	offset: usize,
+) -> Result<&[A::Item; N], OptionSubArrayError>
+where
+	A: SubArray,
+{
+	opt.as_ref()
+		.ok_or(OptionSubArrayError::Absent)?
+		.try_sub_array_ref(offset)
+		.ok_or(OptionSubArrayError::OutOfBounds)
+}
+
+/// Get a mutable reference to the sub-array of length `N` starting at
+/// `offset` of the array in `opt`.
+///
+/// # Errors
+/// Returns [`OptionSubArrayError::Absent`] if `opt` is `None`, and
+/// [`OptionSubArrayError::OutOfBounds`] if `offset + N` exceeds the length of
+/// the array.
+pub fn checked_sub_array_of_option_mut<const N: usize, A>(
+	opt: &mut Option<A>,
+	offset: usize,
+) -> Result<&mut [A::Item; N], OptionSubArrayError>
+where
+	A: SubArray,
+{
+	opt.as_mut()
+		.ok_or(OptionSubArrayError::Absent)?
+		.try_sub_array_mut(offset)
+		.ok_or(OptionSubArrayError::OutOfBounds)
+}
+
+/// Get a mutable reference to the sub-array of length `N` starting at
+/// `offset` of the array in `opt`, inserting `init` first if there is none.
+///
+/// An array that is already present is never replaced.
+///
+/// # Panics
+/// Panics if `offset + N` exceeds the length of the array. In this case,
+/// `init` is still inserted if `opt` was `None`.
+///
+/// # Example
+/// ```
+/// use sub_array::get_or_insert_sub_array_mut;
+///
+/// let mut buffer: Option<[u8; 4]> = None;
+///
+/// *get_or_insert_sub_array_mut(&mut buffer, 1, [0; 4]) = [1, 2];
+/// assert_eq!(buffer, Some([0, 1, 2, 0]));
+/// ```
+pub fn get_or_insert_sub_array_mut<const N: usize, A>(
+	opt: &mut Option<A>,
+	offset: usize,
+	init: A,
+) -> &mut [A::Item; N]
+where
+	A: SubArray,
+{
+	opt.get_or_insert(init).sub_array_mut(offset)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn absent() {
+		let mut opt: Option<[u8; 4]> = None;
+
+		assert_eq!(sub_array_of_option::<2, _>(&opt, 0), None);
+		assert_eq!(sub_array_of_option_mut::<2, _>(&mut opt, 0), None);
+		assert_eq!(
+			checked_sub_array_of_option::<2, _>(&opt, 0),
+			Err(OptionSubArrayError::Absent)
+		);
+		assert_eq!(
+			checked_sub_array_of_option_mut::<2, _>(&mut opt, 5),
+			Err(OptionSubArrayError::Absent)
+		);
+	}
+
+	#[test]
+	fn present() {
+		let mut opt = Some([1, 2, 3, 4_u8]);
+
+		assert_eq!(sub_array_of_option(&opt, 2), Some(&[3, 4]));
+		assert_eq!(checked_sub_array_of_option(&opt, 0), Ok(&[1]));
+		*sub_array_of_option_mut(&mut opt, 0).unwrap() = [5, 6];
+		*checked_sub_array_of_option_mut(&mut opt, 3).unwrap() = [7];
+		assert_eq!(opt, Some([5, 6, 3, 7]));
+	}
+
+	#[test]
+	fn present_out_of_bounds() {
+		let mut opt = Some([1, 2, 3, 4_u8]);
+
+		assert_eq!(sub_array_of_option::<2, _>(&opt, 3), None);
+		assert_eq!(sub_array_of_option_mut::<5, _>(&mut opt, 0), None);
+		assert_eq!(
+			checked_sub_array_of_option::<2, _>(&opt, 3),
+			Err(OptionSubArrayError::OutOfBounds)
+		);
+		assert_eq!(
+			checked_sub_array_of_option_mut::<1, _>(&mut opt, 4),
+			Err(OptionSubArrayError::OutOfBounds)
+		);
+	}
+
+	#[test]
+	fn lazy_init() {
+		let mut opt: Option<[u8; 4]> = None;
+
+		*get_or_insert_sub_array_mut(&mut opt, 0, [9; 4]) = [1, 2];
+		assert_eq!(opt, Some([1, 2, 9, 9]));
+
+		// Already initialized, thus the new `init` is ignored
+		*get_or_insert_sub_array_mut(&mut opt, 2, [0; 4]) = [3];
+		assert_eq!(opt, Some([1, 2, 3, 9]));
+	}
+
+	#[test]
+	#[should_panic]
+	fn lazy_init_out_of_bounds() {
+		let mut opt: Option<[u8; 4]> = None;
+		get_or_insert_sub_array_mut::<2, _>(&mut opt, 3, [0; 4]);
+	}
+}
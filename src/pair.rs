@@ -0,0 +1,97 @@
+//! Pairs of non-overlapping mutable sub-arrays
+
+/// Two non-overlapping mutable sub-arrays of the same array
+///
+/// This keeps both references together, such that they can be passed around
+/// and stored as a single value.
+///
+/// Returned by [`SubArray::split_pair_mut`](crate::SubArray::split_pair_mut).
+#[derive(Debug)]
+pub struct SubArrayRefPair<'a, T, const N: usize, const M: usize> {
+	a: &'a mut [T; N],
+	b: &'a mut [T; M],
+}
+
+impl<'a, T, const N: usize, const M: usize> SubArrayRefPair<'a, T, N, M> {
+	pub(crate) fn new(a: &'a mut [T; N], b: &'a mut [T; M]) -> Self {
+		Self {
+			a,
+			b,
+		}
+	}
+
+	/// Get the first sub-array.
+	pub fn a(&mut self) -> &mut [T; N] {
+		self.a
+	}
+
+	/// Get the second sub-array.
+	pub fn b(&mut self) -> &mut [T; M] {
+		self.b
+	}
+
+	/// Get both sub-arrays at the same time.
+	pub fn both(&mut self) -> (&mut [T; N], &mut [T; M]) {
+		(self.a, self.b)
+	}
+
+	/// Unwraps both sub-arrays.
+	pub fn into_inner(self) -> (&'a mut [T; N], &'a mut [T; M]) {
+		(self.a, self.b)
+	}
+}
+
+impl<T, const N: usize> SubArrayRefPair<'_, T, N, N> {
+	/// Exchanges the contents of both sub-arrays.
+	pub fn swap(&mut self) {
+		self.a.swap_with_slice(self.b);
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use crate::SubArray;
+
+
+	#[test]
+	fn swap() {
+		let mut arr = [1, 2, 3, 4, 5, 6_u8];
+
+		let mut pair = arr.split_pair_mut::<2, 2>(4, 0).unwrap();
+		assert_eq!(pair.a(), &[5, 6]);
+		assert_eq!(pair.b(), &[1, 2]);
+		pair.swap();
+		assert_eq!(pair.a(), &[1, 2]);
+		assert_eq!(pair.b(), &[5, 6]);
+
+		assert_eq!(arr, [5, 6, 3, 4, 1, 2]);
+	}
+
+	#[test]
+	fn different_lengths() {
+		let mut arr = [0_u8; 6];
+
+		let mut pair = arr.split_pair_mut::<1, 3>(0, 3).unwrap();
+		pair.a()[0] = 1;
+		let (a, b) = pair.both();
+		b.fill(a[0] + 1);
+		let (a, _) = pair.into_inner();
+		a[0] = 3;
+
+		assert_eq!(arr, [3, 0, 0, 2, 2, 2]);
+	}
+
+	#[test]
+	fn overlapping_or_out_of_bounds() {
+		let mut arr = [0_u8; 6];
+
+		assert!(arr.split_pair_mut::<2, 3>(0, 1).is_none());
+		assert!(arr.split_pair_mut::<3, 2>(1, 0).is_none());
+		assert!(arr.split_pair_mut::<2, 2>(0, 5).is_none());
+		assert!(arr.split_pair_mut::<2, 2>(usize::MAX, 0).is_none());
+		assert!(arr.split_pair_mut::<2, 4>(0, 2).is_some());
+		assert!(arr.split_pair_mut::<0, 0>(3, 3).is_some());
+	}
+}
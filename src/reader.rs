@@ -0,0 +1,299 @@
+//! Sequential reading of fixed-size arrays from a byte buffer
+
+use core::fmt;
+
+use crate::ByteSource;
+use crate::SubArray;
+
+
+/// Error reading past the end of an [`ArrayReader`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Underflow {
+	/// The number of bytes that were requested
+	pub requested: usize,
+	/// The number of bytes that were remaining
+	pub remaining: usize,
+}
+
+impl fmt::Display for Underflow {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"requested {} bytes, but only {} are remaining",
+			self.requested, self.remaining
+		)
+	}
+}
+
+impl core::error::Error for Underflow {}
+
+
+/// Cursor reading consecutive fixed-size arrays from a byte buffer
+///
+/// Each `read_*` method consumes the bytes it returns, advancing the
+/// [`position`](Self::position), while the `peek_*` methods look ahead without
+/// consuming anything, see [`advance`](Self::advance).
+///
+/// All returned references borrow from the underlying buffer, not from the
+/// reader, thus they stay valid while the reader is used further.
+///
+/// # Example
+/// ```
+/// use sub_array::ArrayReader;
+///
+/// let mut reader = ArrayReader::new(&[1, 0x12, 0x34, 9, 9]);
+///
+/// let tag = reader.peek_u8()?;
+/// assert_eq!(tag, 1);
+/// assert_eq!(reader.position(), 0);
+///
+/// reader.advance(1)?;
+/// assert_eq!(reader.read_u16_be()?, 0x1234);
+/// assert_eq!(reader.read_array::<2>()?, &[9, 9]);
+/// assert!(reader.read_u8().is_err());
+/// # Ok::<(), sub_array::Underflow>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArrayReader<'a> {
+	bytes: &'a [u8],
+	position: usize,
+}
+
+impl<'a> ArrayReader<'a> {
+	/// Creates a reader starting at the beginning of `bytes`.
+	pub fn new(bytes: &'a [u8]) -> Self {
+		Self {
+			bytes,
+			position: 0,
+		}
+	}
+
+	/// The offset of the next byte to read.
+	pub fn position(&self) -> usize {
+		self.position
+	}
+
+	/// The bytes that have not been read yet.
+	pub fn remaining(&self) -> &'a [u8] {
+		&self.bytes[self.position..]
+	}
+
+	/// Get the remaining bytes as an array, if exactly `N` bytes remain.
+	///
+	/// This does not consume anything.
+	pub fn remaining_array<const N: usize>(&self) -> Option<&'a [u8; N]> {
+		self.remaining().try_into().ok()
+	}
+
+	/// Get the next `N` bytes, without consuming them.
+	///
+	/// # Errors
+	/// Returns [`Underflow`] if less than `N` bytes remain.
+	pub fn peek_array<const N: usize>(&self) -> Result<&'a [u8; N], Underflow> {
+		let remaining = self.remaining();
+		remaining.try_sub_array_ref(0).ok_or(Underflow {
+			requested: N,
+			remaining: remaining.len(),
+		})
+	}
+
+	/// Get the next byte, without consuming it.
+	///
+	/// # Errors
+	/// Returns [`Underflow`] if no bytes remain.
+	pub fn peek_u8(&self) -> Result<u8, Underflow> {
+		self.peek_array::<1>().map(|&[b]| b)
+	}
+
+	/// Get the next two bytes as a big-endian `u16`, without consuming them.
+	///
+	/// # Errors
+	/// Returns [`Underflow`] if less than two bytes remain.
+	pub fn peek_u16_be(&self) -> Result<u16, Underflow> {
+		self.peek_array().copied().map(u16::from_be_bytes)
+	}
+
+	/// Consumes the next `n` bytes without looking at them, e.g. after they
+	/// were inspected via one of the `peek_*` methods.
+	///
+	/// # Errors
+	/// Returns [`Underflow`], without consuming anything, if less than `n`
+	/// bytes remain.
+	pub fn advance(&mut self, n: usize) -> Result<(), Underflow> {
+		let remaining = self.remaining().len();
+		if n > remaining {
+			return Err(Underflow {
+				requested: n,
+				remaining,
+			});
+		}
+		self.position += n;
+		Ok(())
+	}
+
+	/// Consumes and returns the next `N` bytes.
+	///
+	/// # Errors
+	/// Returns [`Underflow`], without consuming anything, if less than `N`
+	/// bytes remain.
+	pub fn read_array<const N: usize>(&mut self) -> Result<&'a [u8; N], Underflow> {
+		let array = self.peek_array()?;
+		self.position += N;
+		Ok(array)
+	}
+
+	/// Consumes and returns the next byte.
+	///
+	/// # Errors
+	/// Returns [`Underflow`] if no bytes remain.
+	pub fn read_u8(&mut self) -> Result<u8, Underflow> {
+		self.read_array::<1>().map(|&[b]| b)
+	}
+
+	/// Consumes the next two bytes and returns them as a big-endian `u16`.
+	///
+	/// # Errors
+	/// Returns [`Underflow`], without consuming anything, if less than two
+	/// bytes remain.
+	pub fn read_u16_be(&mut self) -> Result<u16, Underflow> {
+		self.read_array().copied().map(u16::from_be_bytes)
+	}
+}
+
+impl ByteSource for ArrayReader<'_> {
+	type Error = Underflow;
+
+	fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+		let src = self.remaining().get(..buf.len()).ok_or(Underflow {
+			requested: buf.len(),
+			remaining: self.remaining().len(),
+		})?;
+		buf.copy_from_slice(src);
+		self.position += buf.len();
+		Ok(())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[derive(Debug, PartialEq)]
+	enum Record<'a> {
+		Byte(u8),
+		Word(u16),
+		Bytes(&'a [u8; 3]),
+	}
+
+	/// Parses one record, dispatching on its tag
+	fn parse<'a>(reader: &mut ArrayReader<'a>) -> Result<Record<'a>, Underflow> {
+		let start = reader.position();
+		let record = match reader.peek_u8()? {
+			1 => {
+				let [_, b] = *reader.peek_array()?;
+				assert_eq!(reader.position(), start);
+				reader.advance(2)?;
+				Record::Byte(b)
+			},
+			2 => {
+				reader.advance(1)?;
+				Record::Word(reader.read_u16_be()?)
+			},
+			_ => {
+				reader.advance(1)?;
+				Record::Bytes(reader.read_array()?)
+			},
+		};
+		Ok(record)
+	}
+
+	#[test]
+	fn tag_dispatch() {
+		let data = [1, 42, 2, 0x12, 0x34, 3, 7, 8, 9, 2, 0xFF];
+		let mut reader = ArrayReader::new(&data);
+
+		assert_eq!(parse(&mut reader), Ok(Record::Byte(42)));
+		assert_eq!(reader.position(), 2);
+		assert_eq!(parse(&mut reader), Ok(Record::Word(0x1234)));
+		assert_eq!(reader.position(), 5);
+		assert_eq!(parse(&mut reader), Ok(Record::Bytes(&[7, 8, 9])));
+		assert_eq!(reader.position(), 9);
+
+		// Only the tag was consumed, the truncated word was not
+		assert_eq!(
+			parse(&mut reader),
+			Err(Underflow {
+				requested: 2,
+				remaining: 1
+			})
+		);
+		assert_eq!(reader.position(), 10);
+		assert_eq!(reader.remaining(), [0xFF]);
+	}
+
+	#[test]
+	fn peek_does_not_consume() {
+		let mut reader = ArrayReader::new(&[0xAB, 0xCD, 0xEF]);
+
+		assert_eq!(reader.peek_u8(), Ok(0xAB));
+		assert_eq!(reader.peek_u16_be(), Ok(0xABCD));
+		assert_eq!(reader.peek_array(), Ok(&[0xAB, 0xCD, 0xEF]));
+		assert!(reader.peek_array::<4>().is_err());
+		assert_eq!(reader.position(), 0);
+
+		assert_eq!(
+			reader.advance(4),
+			Err(Underflow {
+				requested: 4,
+				remaining: 3
+			})
+		);
+		assert_eq!(reader.position(), 0);
+		assert_eq!(reader.advance(3), Ok(()));
+		assert_eq!(
+			reader.peek_u8(),
+			Err(Underflow {
+				requested: 1,
+				remaining: 0
+			})
+		);
+	}
+
+	#[test]
+	fn references_outlive_reads() {
+		let data = [1, 2, 3, 4, 5, 6];
+
+		// All references borrow `data`, not the reader, which is gone already
+		let (peeked, read, next) = {
+			let mut reader = ArrayReader::new(&data);
+			let peeked: &[u8; 2] = reader.peek_array().unwrap();
+			let read: &[u8; 2] = reader.read_array().unwrap();
+			let next: &[u8; 2] = reader.read_array().unwrap();
+			reader.advance(1).unwrap();
+			(peeked, read, next)
+		};
+		assert_eq!((peeked, read, next), (&[1, 2], &[1, 2], &[3, 4]));
+	}
+
+	#[test]
+	fn remaining_array() {
+		let mut reader = ArrayReader::new(&[1, 2, 3]);
+
+		assert_eq!(reader.remaining_array::<2>(), None);
+		assert_eq!(reader.remaining_array::<3>(), Some(&[1, 2, 3]));
+		reader.read_u8().unwrap();
+		assert_eq!(reader.remaining_array::<2>(), Some(&[2, 3]));
+		assert_eq!(reader.position(), 1);
+	}
+
+	#[test]
+	fn byte_source() {
+		let mut reader = ArrayReader::new(&[1, 2, 3]);
+		assert_eq!(crate::read_sub_array::<2, _>(&mut reader), Ok([1, 2]));
+		assert!(crate::read_sub_array::<2, _>(&mut reader).is_err());
+		assert_eq!(reader.position(), 2);
+	}
+}
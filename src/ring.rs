@@ -0,0 +1,203 @@
+//! Fixed-size views that wrap around the end of an array
+
+use core::iter::FusedIterator;
+use core::ops::Index;
+
+
+/// A view of `N` consecutive elements of an array, wrapping around its end
+///
+/// Unlike the sub-arrays returned by [`SubArray`](crate::SubArray), this view
+/// does not require its elements to be contiguous. Instead, the element at
+/// index `i` is computed on access as the element at `(start + i) % len` of
+/// the original array, thus nothing is copied. If `N` exceeds the length of
+/// the original array, the view wraps around several times.
+///
+/// Returned by [`SubArrayRef::ring_window`](crate::SubArrayRef::ring_window).
+#[derive(Debug)]
+pub struct RingWindow<'a, T, const N: usize> {
+	slice: &'a [T],
+	/// The start of the view, always less than `slice.len()`
+	start: usize,
+}
+
+impl<'a, T, const N: usize> RingWindow<'a, T, N> {
+	#[track_caller]
+	pub(crate) fn new(slice: &'a [T], start: usize) -> Self {
+		assert!(!slice.is_empty(), "ring window over an empty array");
+		Self {
+			slice,
+			start: start % slice.len(),
+		}
+	}
+
+	/// Get a reference to the element at `index` of this view, or `None` if
+	/// `index` is not less than `N`.
+	pub fn get(&self, index: usize) -> Option<&'a T> {
+		if index < N {
+			let len = self.slice.len();
+			Some(&self.slice[(self.start + index % len) % len])
+		} else {
+			None
+		}
+	}
+
+	/// Get an iterator over the `N` elements of this view.
+	pub fn iter(&self) -> RingWindowIter<'a, T, N> {
+		RingWindowIter {
+			window: *self,
+			front: 0,
+			back: N,
+		}
+	}
+}
+
+impl<T, const N: usize> Clone for RingWindow<'_, T, N> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T, const N: usize> Copy for RingWindow<'_, T, N> {}
+
+impl<T, const N: usize> Index<usize> for RingWindow<'_, T, N> {
+	type Output = T;
+
+	#[track_caller]
+	fn index(&self, index: usize) -> &Self::Output {
+		self.get(index)
+			.unwrap_or_else(|| panic!("index {index} out of bounds of ring window of length {N}"))
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for RingWindow<'a, T, N> {
+	type IntoIter = RingWindowIter<'a, T, N>;
+	type Item = &'a T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<'a, T, const N: usize> IntoIterator for &RingWindow<'a, T, N> {
+	type IntoIter = RingWindowIter<'a, T, N>;
+	type Item = &'a T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+
+/// Iterator over the elements of a [`RingWindow`]
+#[derive(Debug, Clone)]
+pub struct RingWindowIter<'a, T, const N: usize> {
+	window: RingWindow<'a, T, N>,
+	/// The next index to yield from the front
+	front: usize,
+	/// One past the next index to yield from the back
+	back: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for RingWindowIter<'a, T, N> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.front += 1;
+			self.window.get(self.front - 1)
+		} else {
+			None
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.back - self.front;
+		(len, Some(len))
+	}
+}
+
+impl<T, const N: usize> DoubleEndedIterator for RingWindowIter<'_, T, N> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front < self.back {
+			self.back -= 1;
+			self.window.get(self.back)
+		} else {
+			None
+		}
+	}
+}
+
+impl<T, const N: usize> ExactSizeIterator for RingWindowIter<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for RingWindowIter<'_, T, N> {}
+
+
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::vec::Vec;
+
+	use crate::SubArrayRef;
+
+
+	#[test]
+	fn straddling() {
+		let arr = [0, 1, 2, 3, 4_u8];
+		let window = arr.ring_window::<4>(3);
+
+		assert_eq!(window.iter().copied().collect::<Vec<_>>(), [3, 4, 0, 1]);
+		assert_eq!(
+			window.iter().rev().copied().collect::<Vec<_>>(),
+			[1, 0, 4, 3]
+		);
+		assert_eq!(window.iter().len(), 4);
+		assert_eq!(window.get(1), Some(&4));
+		assert_eq!(window.get(2), Some(&0));
+		assert_eq!(window.get(4), None);
+		assert_eq!(window[3], 1);
+	}
+
+	#[test]
+	fn start_wraps() {
+		let arr = [0, 1, 2_u8];
+		let found: Vec<_> = arr.ring_window::<2>(7).into_iter().copied().collect();
+		assert_eq!(found, [1, 2]);
+		let found: Vec<_> = arr
+			.ring_window::<2>(usize::MAX)
+			.into_iter()
+			.copied()
+			.collect();
+		assert_eq!(found, [0, 1]);
+	}
+
+	#[test]
+	fn longer_than_array() {
+		let arr = [0, 1, 2_u8];
+		let window = arr.ring_window::<7>(1);
+		let found: Vec<_> = (&window).into_iter().copied().collect();
+		assert_eq!(found, [1, 2, 0, 1, 2, 0, 1]);
+		assert_eq!(window[6], 1);
+	}
+
+	#[test]
+	fn empty_window() {
+		let arr = [0_u8];
+		assert_eq!(arr.ring_window::<0>(5).iter().next(), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "ring window over an empty array")]
+	fn empty_array() {
+		let arr: [u8; 0] = [];
+		arr.ring_window::<1>(0);
+	}
+
+	#[test]
+	#[should_panic(expected = "index 2 out of bounds of ring window of length 2")]
+	fn index_out_of_bounds() {
+		let arr = [0, 1, 2_u8];
+		let _ = arr.ring_window::<2>(0)[2];
+	}
+}
@@ -0,0 +1,240 @@
+//! Read-only sub-arrays, also of shared containers
+
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::SubArray;
+
+
+/// The read-only accessors, which [`SubArrayRef`](crate::SubArrayRef) builds
+/// upon
+///
+/// These are the same as the ones of [`SubArray`], which all its
+/// implementations forward to. This trait can not be named outside of this
+/// crate, thus these accessors are only ever called on concrete types via
+/// [`SubArray`], and importing both [`SubArray`] and `SubArrayRef` does not
+/// make them ambiguous.
+pub trait SubArrayRead {
+	/// The value type of this array.
+	type Item;
+
+	/// The number of elements in this array.
+	fn len(&self) -> usize;
+
+	/// Returns `true` if this array has no elements.
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Get a reference to a sub-array of length `N` starting at `offset`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of this array.
+	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N];
+
+	/// Get a reference to a sub-array of length `N` starting at `offset`, if
+	/// it is in bounds.
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]>;
+}
+
+/// Implementation on everything that implements [`SubArray`]
+impl<A> SubArrayRead for A
+where
+	A: SubArray + ?Sized,
+{
+	type Item = A::Item;
+
+	fn len(&self) -> usize {
+		SubArray::len(self)
+	}
+
+	#[track_caller]
+	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+		SubArray::sub_array_ref(self, offset)
+	}
+
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		SubArray::try_sub_array_ref(self, offset)
+	}
+}
+
+/// Implements `SubArrayRead` for the given shared containers by forwarding to
+/// the [`SubArray`] of their target
+///
+/// A single implementation on all `&A` would conflict with the one on
+/// everything that implements [`SubArray`], since other crates may implement
+/// [`SubArray`] on shared references to their own types, thus the ones of
+/// this crate are listed one by one.
+macro_rules! impl_shared {
+	($(
+		$(#[$attr:meta])*
+		impl [$($generics:tt)*] for $container:ty;
+	)*) => {$(
+		$(#[$attr])*
+		impl<$($generics)*> SubArrayRead for $container {
+			type Item = <<Self as core::ops::Deref>::Target as SubArrayRead>::Item;
+
+			fn len(&self) -> usize {
+				SubArrayRead::len(&**self)
+			}
+
+			#[track_caller]
+			fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+				SubArrayRead::sub_array_ref(&**self, offset)
+			}
+
+			fn try_sub_array_ref<const N: usize>(
+				&self,
+				offset: usize,
+			) -> Option<&[Self::Item; N]> {
+				SubArrayRead::try_sub_array_ref(&**self, offset)
+			}
+		}
+	)*};
+}
+
+impl_shared! {
+	/// Implementation on shared references to arrays
+	impl[T, const M: usize] for &[T; M];
+	/// Implementation on shared references to slices
+	impl[T] for &[T];
+	/// Implementation on shared references to vectors
+	///
+	/// Requires the `alloc` feature.
+	#[cfg(feature = "alloc")]
+	impl[T] for &Vec<T>;
+	/// Implementation on reference-counted pointers
+	///
+	/// Requires the `alloc` feature.
+	#[cfg(feature = "alloc")]
+	impl[A: SubArrayRead + ?Sized] for Rc<A>;
+	/// Implementation on atomically reference-counted pointers
+	///
+	/// Requires the `alloc` feature.
+	#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+	impl[A: SubArrayRead + ?Sized] for Arc<A>;
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	#[cfg(feature = "alloc")]
+	use alloc::rc::Rc;
+	#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+	use alloc::sync::Arc;
+	#[cfg(feature = "alloc")]
+	use alloc::vec;
+
+	use crate::SubArray;
+	use crate::SubArrayRef;
+
+
+	/// Only needs to read, thus it is bound on the read-only trait
+	fn header<B>(buf: &B) -> Option<(u8, &[u8; 2])>
+	where
+		B: SubArrayRef<Item = u8> + ?Sized,
+	{
+		Some((buf.try_sub_array_ref::<1>(0)?[0], buf.try_sub_array_ref(1)?))
+	}
+
+	/// Uses some of the provided methods through the read-only trait
+	fn summary<B>(buf: &B) -> (u8, [u8; 2], Option<usize>)
+	where
+		B: SubArrayRef<Item = u8> + ?Sized,
+	{
+		(
+			buf.sub_array_max::<3>(0),
+			buf.copy_sub_array(1),
+			buf.max_valid_offset::<2>(),
+		)
+	}
+
+	#[test]
+	fn shared_references() {
+		let arr = [1, 2, 3_u8];
+		let slice: &[u8] = &arr;
+
+		assert_eq!(header(&&arr), Some((1, &[2, 3])));
+		assert_eq!(header(&slice), Some((1, &[2, 3])));
+		assert_eq!(header(&&slice[1..]), None);
+
+		assert_eq!(summary(&&arr), (3, [2, 3], Some(1)));
+		assert_eq!(summary(&slice), (3, [2, 3], Some(1)));
+		assert_eq!(slice.cmp_sub_arrays::<1>(0, 2), core::cmp::Ordering::Less);
+		let shared: &[u8; 3] = &arr;
+		assert_eq!(shared.sub_array_chunks::<2>().remainder(), &[3]);
+	}
+
+	#[test]
+	fn both_traits_in_scope() {
+		// Neither call is ambiguous, though both traits are imported
+		let mut arr = [1, 2, 3_u8];
+		*arr.sub_array_mut::<1>(0) = [4];
+		assert_eq!(arr.sub_array_ref::<2>(0), &[4, 2]);
+		assert_eq!(arr.copy_sub_array::<2>(1), [2, 3]);
+
+		let slice: &[u8] = &arr;
+		assert_eq!(slice.sub_array_ref::<2>(1), &[2, 3]);
+		assert_eq!(slice.len(), 3);
+	}
+
+	#[test]
+	fn owned_and_mutable() {
+		// Everything that implements `SubArray` is read-only as well
+		let mut arr = [4, 5, 6_u8];
+		assert_eq!(header(&arr), Some((4, &[5, 6])));
+		assert_eq!(header(&arr[..]), Some((4, &[5, 6])));
+		assert_eq!(summary(&&mut arr), (6, [5, 6], Some(1)));
+	}
+
+	#[cfg(feature = "alloc")]
+	#[test]
+	fn reference_counted() {
+		let rc: Rc<[u8]> = Rc::new([1, 2, 3]);
+		let rc_array = Rc::new([4, 5, 6_u8]);
+
+		assert_eq!(header(&rc), Some((1, &[2, 3])));
+		assert_eq!(header(&rc_array), Some((4, &[5, 6])));
+		assert_eq!(summary(&rc), (3, [2, 3], Some(1)));
+		assert_eq!(rc.sub_array_ref::<1>(2), &[3]);
+		assert_eq!(rc.try_sub_array_ref::<2>(2), None);
+		assert_eq!(rc.sub_array_sum::<3>(0), 6);
+		assert_eq!(rc.sub_array_chunks::<2>().next(), Some(&[1, 2]));
+
+		let vec = vec![7, 8, 9_u8];
+		assert_eq!(header(&&vec), Some((7, &[8, 9])));
+	}
+
+	#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+	#[test]
+	fn atomically_reference_counted() {
+		let arc: Arc<[u8]> = Arc::new([1, 2, 3]);
+		let clone = Arc::clone(&arc);
+
+		assert_eq!(header(&arc), Some((1, &[2, 3])));
+		assert_eq!(summary(&clone), (3, [2, 3], Some(1)));
+		assert_eq!(clone.sub_array_ref::<2>(1), &[2, 3]);
+		assert_eq!(arc.sub_array_min::<2>(1), 2);
+		assert_eq!(
+			arc.sub_array_iter::<2>()
+				.map(|(offset, _)| offset)
+				.sum::<usize>(),
+			0
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 2 exceeds the array of length 3")]
+	fn out_of_bounds() {
+		fn tail<B: SubArrayRef<Item = u8> + ?Sized>(buf: &B) -> &[u8; 2] {
+			buf.sub_array_ref(2)
+		}
+
+		tail(&&[1, 2, 3_u8]);
+	}
+}
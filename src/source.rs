@@ -0,0 +1,103 @@
+//! Reading fixed-size arrays from streaming sources
+
+/// Minimal source of bytes, like a `no_std` version of `std::io::Read`
+///
+/// Implement this for a streaming reader to fill fixed-size arrays from it
+/// via [`read_sub_array`].
+pub trait ByteSource {
+	/// The error returned if reading fails.
+	type Error;
+
+	/// Reads exactly enough bytes to fill `buf`.
+	///
+	/// # Errors
+	/// Returns an error if reading fails, including if the source ends before
+	/// `buf` is filled. The contents of `buf` are unspecified in this case.
+	fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Reads exactly `N` bytes from `src` into a new array.
+///
+/// # Errors
+/// Returns the error of [`ByteSource::read_exact`], if reading fails.
+///
+/// # Example
+/// ```
+/// use sub_array::read_sub_array;
+/// use sub_array::ByteSource;
+///
+/// struct Zeros;
+///
+/// impl ByteSource for Zeros {
+///     type Error = core::convert::Infallible;
+///
+///     fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+///         buf.fill(0);
+///         Ok(())
+///     }
+/// }
+///
+/// assert_eq!(read_sub_array::<4, _>(&mut Zeros), Ok([0; 4]));
+/// ```
+pub fn read_sub_array<const N: usize, R>(src: &mut R) -> Result<[u8; N], R::Error>
+where
+	R: ByteSource + ?Sized,
+{
+	let mut buf = [0; N];
+	src.read_exact(&mut buf)?;
+	Ok(buf)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	/// In-memory cursor over a byte slice
+	struct Cursor<'a> {
+		data: &'a [u8],
+		position: usize,
+	}
+
+	#[derive(Debug, PartialEq)]
+	struct UnexpectedEof;
+
+	impl ByteSource for Cursor<'_> {
+		type Error = UnexpectedEof;
+
+		fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), Self::Error> {
+			let end = self.position + buf.len();
+			let src = self.data.get(self.position..end).ok_or(UnexpectedEof)?;
+			buf.copy_from_slice(src);
+			self.position = end;
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn cursor() {
+		let mut cursor = Cursor {
+			data: &[1, 2, 3, 4, 5, 6],
+			position: 0,
+		};
+
+		assert_eq!(read_sub_array::<2, _>(&mut cursor), Ok([1, 2]));
+		assert_eq!(read_sub_array::<0, _>(&mut cursor), Ok([]));
+		assert_eq!(read_sub_array::<3, _>(&mut cursor), Ok([3, 4, 5]));
+		assert_eq!(read_sub_array::<2, _>(&mut cursor), Err(UnexpectedEof));
+		assert_eq!(read_sub_array::<1, _>(&mut cursor), Ok([6]));
+	}
+
+	#[test]
+	fn dyn_source() {
+		let mut cursor = Cursor {
+			data: &[1, 2, 3],
+			position: 1,
+		};
+		let src: &mut dyn ByteSource<Error = UnexpectedEof> = &mut cursor;
+
+		assert_eq!(read_sub_array::<2, _>(src), Ok([2, 3]));
+	}
+}
@@ -0,0 +1,430 @@
+//! Reusable extraction parameters
+
+use crate::SubArray;
+use crate::SubArrayError;
+
+
+/// The position of a sub-array of length `N`, reusable across arrays
+///
+/// Carrying the length `N` in the type ensures that the same `offset` is
+/// always used with the same length. Apply it to an array via
+/// [`SubArrayRef::apply_ref`](crate::SubArrayRef::apply_ref) or
+/// [`SubArray::apply_mut`](crate::SubArray::apply_mut), which still check the
+/// bounds against that specific array.
+///
+/// # Example
+/// ```
+/// use sub_array::SubArrayRef;
+/// use sub_array::SubArraySpec;
+///
+/// const CHECKSUM: SubArraySpec<2> = SubArraySpec::new(3);
+///
+/// let packet: [u8; 5] = [1, 2, 3, 4, 5];
+/// let other: [u8; 6] = [6, 5, 4, 3, 2, 1];
+///
+/// assert_eq!(packet.apply_ref(CHECKSUM), &[4, 5]);
+/// assert_eq!(other.apply_ref(CHECKSUM), &[3, 2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubArraySpec<const N: usize> {
+	/// The offset at which the sub-array starts
+	pub offset: usize,
+}
+
+impl<const N: usize> SubArraySpec<N> {
+	/// The length of the sub-array
+	pub const LEN: usize = N;
+
+	/// Creates a new spec for the sub-array of length `N` starting at
+	/// `offset`.
+	pub const fn new(offset: usize) -> Self {
+		Self {
+			offset,
+		}
+	}
+
+	/// The offset one past the end of the sub-array, i.e. `offset + N`.
+	///
+	/// # Panics
+	/// Panics if `offset + N` overflows `usize`.
+	pub const fn end(self) -> usize {
+		self.offset + N
+	}
+
+	/// Creates a spec for the sub-array of length `M` that directly follows
+	/// this one, i.e. that starts at [`end`](Self::end).
+	///
+	/// # Panics
+	/// Panics if `offset + N` overflows `usize`.
+	pub const fn next<const M: usize>(self) -> SubArraySpec<M> {
+		SubArraySpec::new(self.end())
+	}
+}
+
+
+/// The position of a sub-array of length `N`, which is known to be in bounds
+///
+/// Unlike a [`SubArraySpec`], a token cannot be created from an arbitrary
+/// offset, it is only returned by
+/// [`SubArrayRef::locate_sub_array`](crate::SubArrayRef::locate_sub_array) after
+/// checking the bounds once. It records the length of the array it was
+/// located in, which thus covers `offset + N`.
+///
+/// Apply it via [`SubArrayRef::apply_window`](crate::SubArrayRef::apply_window) or
+/// [`SubArray::apply_window_mut`](crate::SubArray::apply_window_mut).
+///
+/// A token cannot be forged:
+/// ```compile_fail,E0451
+/// use sub_array::WindowToken;
+///
+/// let token = WindowToken::<2> {
+///     offset: 8,
+///     source_len: 10,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowToken<const N: usize> {
+	/// The offset at which the sub-array starts
+	offset: usize,
+	/// The length of the array it was located in, at least `offset + N`
+	source_len: usize,
+}
+
+impl<const N: usize> WindowToken<N> {
+	/// The length of the sub-array
+	pub const LEN: usize = N;
+
+	/// Checks that the sub-array of length `N` at `offset` is in bounds of
+	/// an array of length `len`.
+	pub(crate) const fn locate(offset: usize, len: usize) -> Option<Self> {
+		match offset.checked_add(N) {
+			Some(end) if end <= len => {
+				Some(Self {
+					offset,
+					source_len: len,
+				})
+			},
+			_ => None,
+		}
+	}
+
+	/// The offset at which the sub-array starts.
+	pub const fn offset(self) -> usize {
+		self.offset
+	}
+
+	/// The length of the array this token was located in.
+	pub const fn source_len(self) -> usize {
+		self.source_len
+	}
+
+	/// The offset one past the end of the sub-array, i.e. `offset + N`.
+	pub const fn end(self) -> usize {
+		// Cannot overflow, since it was checked to be at most `source_len`
+		self.offset + N
+	}
+
+	/// Forgets that this position was checked, e.g. to apply it to other
+	/// arrays.
+	pub const fn to_spec(self) -> SubArraySpec<N> {
+		SubArraySpec::new(self.offset)
+	}
+}
+
+
+/// Checked layout of consecutive sub-arrays in a buffer of a fixed size
+///
+/// Instead of typing the offset of each [`SubArraySpec`], they are derived
+/// from the lengths of the preceding ones: the first field is created via
+/// [`first`](Self::first), each further field via [`SubArraySpec::next`] of
+/// its predecessor. Finally, [`finish`](Self::finish) checks that the last
+/// field ends exactly at the end of the buffer. All of this works in const
+/// context, thus a mistake in the lengths fails to compile.
+///
+/// # Example
+/// ```
+/// use sub_array::Layout;
+/// use sub_array::SubArray;
+/// use sub_array::SubArraySpec;
+///
+/// const LAYOUT: Layout = Layout::new::<10>();
+/// const FOO: SubArraySpec<2> = LAYOUT.first();
+/// const BAR: SubArraySpec<4> = FOO.next();
+/// const BAZ: SubArraySpec<4> = BAR.next();
+/// const _: () = LAYOUT.finish(BAZ);
+///
+/// let mut arr = [0_u8; 10];
+/// *arr.apply_mut(BAR) = 0x1234_u32.to_be_bytes();
+/// assert_eq!(arr, [0, 0, 0, 0, 0x12, 0x34, 0, 0, 0, 0]);
+/// ```
+///
+/// A layout that does not add up to the size of the buffer is rejected:
+/// ```compile_fail
+/// use sub_array::Layout;
+/// use sub_array::SubArraySpec;
+///
+/// const LAYOUT: Layout = Layout::new::<10>();
+/// const FOO: SubArraySpec<2> = LAYOUT.first();
+/// const BAR: SubArraySpec<4> = FOO.next();
+/// const _: () = LAYOUT.finish(BAR);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Layout {
+	size: usize,
+}
+
+impl Layout {
+	/// Creates a layout of a buffer of `SIZE` elements.
+	pub const fn new<const SIZE: usize>() -> Self {
+		Self {
+			size: SIZE,
+		}
+	}
+
+	/// The size of the buffer described by this layout.
+	pub const fn size(self) -> usize {
+		self.size
+	}
+
+	/// Creates a spec for the first field of this layout, i.e. the sub-array
+	/// of length `N` at offset `0`.
+	///
+	/// # Panics
+	/// Panics if `N` exceeds the size of this layout.
+	pub const fn first<const N: usize>(self) -> SubArraySpec<N> {
+		self.field(0)
+	}
+
+	/// Creates a spec for the field of length `N` starting at `offset`,
+	/// checking that it is part of this layout.
+	///
+	/// This allows to describe fields, which do not directly follow each
+	/// other, e.g. to skip reserved bytes.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the size of this layout, which fails to
+	/// compile if used in a const item:
+	/// ```compile_fail,E0080
+	/// use sub_array::Layout;
+	/// use sub_array::SubArraySpec;
+	///
+	/// const LAYOUT: Layout = Layout::new::<10>();
+	/// const CRC: SubArraySpec<4> = LAYOUT.field(8);
+	/// ```
+	pub const fn field<const N: usize>(self, offset: usize) -> SubArraySpec<N> {
+		assert!(
+			offset <= self.size && N <= self.size - offset,
+			"field exceeds the size of the layout"
+		);
+		SubArraySpec::new(offset)
+	}
+
+	/// Checks that `last`, the last field of this layout, ends exactly at the
+	/// end of the buffer.
+	///
+	/// Use it in a const item, e.g. `const _: () = LAYOUT.finish(LAST);`, to
+	/// turn a mismatch into a compile error.
+	///
+	/// # Panics
+	/// Panics if `last` does not end at the size of this layout.
+	pub const fn finish<const N: usize>(self, last: SubArraySpec<N>) {
+		assert!(
+			last.end() == self.size,
+			"the fields do not add up to the size of the layout"
+		);
+	}
+
+	/// Checks once that `buf` covers this layout, such that all of its fields
+	/// can be extracted from the returned [`ValidatedLayout`] without
+	/// handling the out of bounds case for each of them.
+	///
+	/// The fields created via [`first`](Self::first) and
+	/// [`field`](Self::field) are checked against the size of this layout,
+	/// and [`finish`](Self::finish) checks that the ones following them via
+	/// [`SubArraySpec::next`] end within it, thus these extractions can not
+	/// fail.
+	///
+	/// The buffer may be longer than this layout, e.g. if the layout is only
+	/// the header of a packet, the remaining elements are ignored.
+	///
+	/// # Errors
+	/// Returns a [`SubArrayError`] if `buf` is shorter than the size of this
+	/// layout, as if the whole layout was a sub-array at offset `0`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::Layout;
+	/// use sub_array::SubArraySpec;
+	///
+	/// const HEADER: Layout = Layout::new::<4>();
+	/// const KIND: SubArraySpec<1> = HEADER.first();
+	/// const LEN: SubArraySpec<3> = KIND.next();
+	/// const _: () = HEADER.finish(LEN);
+	///
+	/// let packet: &[u8] = &[7, 0, 0, 2, 0xAA, 0xBB];
+	///
+	/// let header = HEADER.validate(packet).unwrap();
+	/// assert_eq!(header.apply(KIND), &[7]);
+	/// assert_eq!(header.field::<2>(2), &[0, 2]);
+	/// assert!(HEADER.validate(&packet[..3]).is_err());
+	/// ```
+	pub fn validate<T>(self, buf: &[T]) -> Result<ValidatedLayout<'_, T>, SubArrayError> {
+		let covered = buf.get(..self.size).ok_or(SubArrayError {
+			offset: 0,
+			len: self.size,
+			array_len: buf.len(),
+		})?;
+		Ok(ValidatedLayout {
+			buf: covered,
+		})
+	}
+}
+
+/// A buffer, which is known to cover a [`Layout`]
+///
+/// Returned by [`Layout::validate`]. Its fields are extracted via
+/// [`apply`](Self::apply), given a spec created by the layout, which is
+/// already checked to be part of it, or via [`field`](Self::field), given an
+/// arbitrary offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidatedLayout<'a, T> {
+	/// The part of the buffer covered by the layout
+	buf: &'a [T],
+}
+
+impl<'a, T> ValidatedLayout<'a, T> {
+	/// Get the field of length `N` starting at `offset`.
+	///
+	/// Unlike the specs passed to [`apply`](Self::apply), `offset` is not
+	/// checked in advance, prefer [`Layout::field`] for fixed offsets.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the size of the layout.
+	#[track_caller]
+	pub fn field<const N: usize>(&self, offset: usize) -> &'a [T; N] {
+		self.buf.sub_array_ref(offset)
+	}
+
+	/// Get the field given by `spec`.
+	///
+	/// This can not fail for a spec created by the validated layout, see
+	/// [`Layout::validate`].
+	///
+	/// # Panics
+	/// Panics if `spec` exceeds the size of the layout, i.e. if it was not
+	/// created by the layout.
+	#[track_caller]
+	pub fn apply<const N: usize>(&self, spec: SubArraySpec<N>) -> &'a [T; N] {
+		self.buf.sub_array_ref(spec.offset)
+	}
+
+	/// Get the part of the buffer covered by the layout.
+	pub fn as_slice(&self) -> &'a [T] {
+		self.buf
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::SubArrayRef;
+
+
+	const LAYOUT: Layout = Layout::new::<10>();
+	const FOO: SubArraySpec<2> = LAYOUT.first();
+	const BAR: SubArraySpec<4> = FOO.next();
+	const BAZ: SubArraySpec<4> = BAR.next();
+	const _: () = LAYOUT.finish(BAZ);
+
+	#[test]
+	fn derived_offsets() {
+		assert_eq!(LAYOUT.size(), 10);
+		assert_eq!(FOO.offset, 0);
+		assert_eq!(BAR.offset, 2);
+		assert_eq!(BAZ.offset, 6);
+		assert_eq!(BAZ.end(), 10);
+	}
+
+	#[test]
+	fn readme_example() {
+		let mut arr = [0_u8; 10];
+		*arr.apply_mut(FOO) = 42_u16.to_be_bytes();
+		*arr.apply_mut(BAR) = 0x1234_u32.to_be_bytes();
+		*arr.apply_mut(BAZ) = 0x5678_u32.to_be_bytes();
+
+		assert_eq!(arr, [0, 42, 0, 0, 0x12, 0x34, 0, 0, 0x56, 0x78]);
+		assert_eq!(arr.apply_ref(BAR), &[0, 0, 0x12, 0x34]);
+	}
+
+	#[test]
+	#[should_panic(expected = "the fields do not add up to the size of the layout")]
+	fn finish_mismatch() {
+		LAYOUT.finish(BAR);
+	}
+
+	#[test]
+	#[should_panic(expected = "field exceeds the size of the layout")]
+	fn first_too_long() {
+		LAYOUT.first::<11>();
+	}
+
+	#[test]
+	fn validate() {
+		const HEADER: Layout = Layout::new::<8>();
+		const VERSION: SubArraySpec<1> = HEADER.first();
+		const FLAGS: SubArraySpec<3> = VERSION.next();
+		const LEN: SubArraySpec<4> = FLAGS.next();
+		const _: () = HEADER.finish(LEN);
+
+		let packet = [2, 0, 1, 0, 0, 0, 0, 3, b'a', b'b', b'c'];
+
+		let header = HEADER.validate(&packet).unwrap();
+		assert_eq!(header.as_slice().len(), 8);
+		assert_eq!(header.apply(VERSION), &[2]);
+		assert_eq!(header.apply(FLAGS), &[0, 1, 0]);
+		assert_eq!(u32::from_be_bytes(*header.apply(LEN)), 3);
+		assert_eq!(header.field::<2>(6), &[0, 3]);
+
+		assert_eq!(
+			HEADER.validate(&packet[..8]).map(|h| h.apply(LEN)),
+			Ok(&[0, 0, 0, 3])
+		);
+		assert_eq!(
+			HEADER.validate(&packet[..7]),
+			Err(SubArrayError {
+				offset: 0,
+				len: 8,
+				array_len: 7,
+			})
+		);
+	}
+
+	#[test]
+	fn skipped_fields() {
+		const HEADER: Layout = Layout::new::<8>();
+		const KIND: SubArraySpec<1> = HEADER.first();
+		// Bytes 1 to 3 are reserved
+		const LEN: SubArraySpec<4> = HEADER.field(4);
+		const _: () = HEADER.finish(LEN);
+
+		let header = HEADER.validate(&[9, 0xFF, 0xFF, 0xFF, 0, 0, 1, 0]).unwrap();
+		assert_eq!(header.apply(KIND), &[9]);
+		assert_eq!(u32::from_be_bytes(*header.apply(LEN)), 256);
+	}
+
+	#[test]
+	#[should_panic(expected = "field exceeds the size of the layout")]
+	fn layout_field_outside_layout() {
+		LAYOUT.field::<4>(8);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 4 at offset 8 exceeds the array of length 10")]
+	fn validated_field_outside_layout() {
+		// The buffer is long enough, but the field is not part of the layout
+		let packet = [0_u8; 16];
+		LAYOUT.validate(&packet).unwrap().field::<4>(8);
+	}
+}
@@ -0,0 +1,160 @@
+//! Splitting arrays into two arrays
+
+
+/// Array of a fixed length `M`, which can be split into two arrays
+///
+/// This is implemented for `[T; M]`. Unlike [`slice::split_at`], both halves
+/// keep a fixed-size type. Their lengths `K` and `R` are separate parameters,
+/// since `M - K` is not expressible on stable Rust, but they must add up to
+/// `M`, otherwise this fails to compile. Usually, they are inferred from the
+/// types of the halves.
+pub trait SplitArray<const M: usize> {
+	/// The value type of this array.
+	///
+	/// This is the `T` in `[T; M]`.
+	type Item;
+
+	/// Splits this array into its first `K` and its remaining `R` elements.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SplitArray;
+	///
+	/// let arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let (head, tail): (&[u8; 2], &[u8; 3]) = arr.split_at_fixed();
+	/// assert_eq!(head, &[1, 2]);
+	/// assert_eq!(tail, &[3, 4, 5]);
+	/// ```
+	///
+	/// Lengths that do not add up to `M` fail to compile:
+	/// ```compile_fail
+	/// use sub_array::SplitArray;
+	///
+	/// let arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let (head, tail) = arr.split_at_fixed::<6, 0>();
+	/// ```
+	fn split_at_fixed<const K: usize, const R: usize>(
+		&self,
+	) -> (&[Self::Item; K], &[Self::Item; R]);
+
+	/// Splits this array into its first `K` and its remaining `R` elements,
+	/// mutably.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SplitArray;
+	///
+	/// let mut arr: [u8; 5] = [1, 2, 3, 4, 5];
+	///
+	/// let (head, tail) = arr.split_at_fixed_mut::<2, 3>();
+	/// head.copy_from_slice(&tail[1..]);
+	/// assert_eq!(arr, [4, 5, 3, 4, 5]);
+	/// ```
+	fn split_at_fixed_mut<const K: usize, const R: usize>(
+		&mut self,
+	) -> (&mut [Self::Item; K], &mut [Self::Item; R]);
+
+	/// Get the remaining `R` elements after the first `K` ones, e.g. the
+	/// payload after a header of length `K`.
+	///
+	/// Unlike with a slice, the length of the tail is still known, thus tails
+	/// can be chained. Also see [`split_at_fixed`](Self::split_at_fixed).
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::SplitArray;
+	///
+	/// let packet: [u8; 6] = [0xCA, 0xFE, 1, 2, 3, 4];
+	///
+	/// let payload: &[u8; 4] = packet.tail_after::<2, _>();
+	/// assert_eq!(payload, &[1, 2, 3, 4]);
+	/// ```
+	///
+	/// Lengths that do not add up to `M` fail to compile:
+	/// ```compile_fail
+	/// use sub_array::SplitArray;
+	///
+	/// let packet: [u8; 6] = [0xCA, 0xFE, 1, 2, 3, 4];
+	///
+	/// let payload: &[u8; 3] = packet.tail_after::<2, _>();
+	/// ```
+	fn tail_after<const K: usize, const R: usize>(&self) -> &[Self::Item; R] {
+		self.split_at_fixed::<K, R>().1
+	}
+
+	/// Get the remaining `R` mutable elements after the first `K` ones.
+	///
+	/// This is the mutable version of [`tail_after`](Self::tail_after).
+	fn tail_after_mut<const K: usize, const R: usize>(&mut self) -> &mut [Self::Item; R] {
+		self.split_at_fixed_mut::<K, R>().1
+	}
+}
+
+impl<T, const M: usize> SplitArray<M> for [T; M] {
+	type Item = T;
+
+	fn split_at_fixed<const K: usize, const R: usize>(&self) -> (&[T; K], &[T; R]) {
+		const {
+			assert!(
+				K + R == M,
+				"the halves must add up to the length of the array"
+			)
+		};
+		let (head, tail) = self.split_first_chunk::<K>().unwrap();
+		(head, tail.try_into().unwrap())
+	}
+
+	fn split_at_fixed_mut<const K: usize, const R: usize>(&mut self) -> (&mut [T; K], &mut [T; R]) {
+		const {
+			assert!(
+				K + R == M,
+				"the halves must add up to the length of the array"
+			)
+		};
+		let (head, tail) = self.split_first_chunk_mut::<K>().unwrap();
+		(head, tail.try_into().unwrap())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn split_at_fixed() {
+		let mut arr = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9_u8];
+
+		let (head, tail): (&[u8; 3], &[u8; 7]) = arr.split_at_fixed();
+		assert_eq!(head, &[0, 1, 2]);
+		assert_eq!(tail, &[3, 4, 5, 6, 7, 8, 9]);
+
+		assert_eq!(arr.split_at_fixed::<0, 10>(), (&[], &arr));
+		assert_eq!(arr.split_at_fixed::<10, 0>(), (&arr, &[]));
+
+		let (head, tail) = arr.split_at_fixed_mut::<3, 7>();
+		head.swap_with_slice(&mut tail[..3]);
+		assert_eq!(arr, [3, 4, 5, 0, 1, 2, 6, 7, 8, 9]);
+	}
+
+	#[test]
+	fn chained_tails() {
+		let mut packet: [u8; 16] = core::array::from_fn(|i| i as u8);
+
+		// Strip a 2 byte and then a 4 byte header
+		let inner: &[u8; 14] = packet.tail_after::<2, _>();
+		let payload: &[u8; 10] = inner.tail_after::<4, _>();
+		assert_eq!(payload, &[6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+		let payload: &mut [u8; 10] = packet.tail_after_mut::<2, 14>().tail_after_mut::<4, _>();
+		payload.fill(0xFF);
+		assert_eq!(
+			packet.split_at_fixed::<6, 10>(),
+			(&[0, 1, 2, 3, 4, 5], &[0xFF; 10])
+		);
+	}
+}
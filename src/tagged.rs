@@ -0,0 +1,41 @@
+//! Tagged payloads whose size depends on a tag byte
+
+use core::fmt;
+
+
+/// Payload of one of two sizes, selected by a tag byte
+///
+/// Returned by [`ByteSubArray::read_tagged`](crate::ByteSubArray::read_tagged).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tagged<'a, const A: usize, const B: usize> {
+	/// The payload of the first tag
+	A(&'a [u8; A]),
+	/// The payload of the second tag
+	B(&'a [u8; B]),
+}
+
+
+/// Error decoding a [`Tagged`] payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagError {
+	/// The tag byte, or the payload selected by it, is out of bounds
+	OutOfBounds,
+	/// The tag byte is none of the expected tags
+	UnknownTag {
+		/// The offending tag byte
+		tag: u8,
+	},
+}
+
+impl fmt::Display for TagError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::OutOfBounds => write!(f, "tagged payload out of bounds"),
+			Self::UnknownTag {
+				tag,
+			} => write!(f, "unknown tag {tag:#04x}"),
+		}
+	}
+}
+
+impl core::error::Error for TagError {}
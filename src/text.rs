@@ -0,0 +1,154 @@
+//! Formatting text into fixed-size byte windows
+
+use core::fmt;
+
+
+/// A byte window that formatted text can be written into
+///
+/// This implements [`fmt::Write`], thus [`write!`] can be used to append
+/// UTF-8 text to the window, starting at its beginning. Each string is either
+/// appended as a whole or, if it does not fit into the remaining space of the
+/// window, not at all, in which case [`fmt::Error`] is returned. Thus, the
+/// written part is always valid UTF-8, see [`as_str`](Self::as_str). However,
+/// a single `write!` may append multiple strings, thus if it fails, a prefix
+/// of its output may have been written anyway.
+///
+/// The bytes of the window after the written part are left untouched.
+///
+/// Returned by [`ByteSubArray::text_window`](crate::ByteSubArray::text_window).
+///
+/// # Example
+/// ```
+/// use core::fmt::Write;
+///
+/// use sub_array::TextWindow;
+///
+/// let mut buf = [0_u8; 8];
+/// let mut text = TextWindow::new(&mut buf);
+///
+/// write!(text, "{}-{}", 4, 2).unwrap();
+/// assert_eq!(text.as_str(), "4-2");
+/// assert!(write!(text, "{}", 123_456).is_err());
+/// assert_eq!(text.as_str(), "4-2");
+/// ```
+#[derive(Debug)]
+pub struct TextWindow<'a> {
+	window: &'a mut [u8],
+	/// The number of bytes written so far
+	len: usize,
+}
+
+impl<'a> TextWindow<'a> {
+	/// Creates an empty text window that writes into `window`.
+	pub fn new(window: &'a mut [u8]) -> Self {
+		Self {
+			window,
+			len: 0,
+		}
+	}
+
+	/// Get the text written so far.
+	pub fn as_str(&self) -> &str {
+		core::str::from_utf8(&self.window[..self.len]).expect("only whole strings are written")
+	}
+
+	/// Get the number of bytes written so far.
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Whether nothing was written so far.
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Get the total number of bytes of this window.
+	pub fn capacity(&self) -> usize {
+		self.window.len()
+	}
+
+	/// Get the number of bytes that can still be written.
+	pub fn remaining(&self) -> usize {
+		self.window.len() - self.len
+	}
+
+	/// Unwraps the underlying window.
+	pub fn into_inner(self) -> &'a mut [u8] {
+		self.window
+	}
+}
+
+impl fmt::Write for TextWindow<'_> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+		self.window
+			.get_mut(self.len..end)
+			.ok_or(fmt::Error)?
+			.copy_from_slice(s.as_bytes());
+		self.len = end;
+		Ok(())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use core::fmt::Write;
+
+	use super::*;
+	use crate::ByteSubArray;
+
+
+	#[test]
+	fn write_formatted() {
+		let mut buf = [b'#'; 18];
+
+		let mut text = buf.text_window::<16>(1);
+		assert!(text.is_empty());
+		write!(text, "id={:04x} n={}", 0xBEEF_u16, -7).unwrap();
+		assert_eq!(text.as_str(), "id=beef n=-7");
+		assert_eq!(text.len(), 12);
+		assert_eq!(text.remaining(), 4);
+		assert_eq!(text.capacity(), 16);
+
+		assert_eq!(&buf[..14], b"#id=beef n=-7#");
+		assert_eq!(buf[17], b'#');
+	}
+
+	#[test]
+	fn write_overflow() {
+		let mut buf = [0_u8; 16];
+		let mut text = buf.text_window::<16>(0);
+
+		write!(text, "{:010}", 123_456_789).unwrap();
+		assert_eq!(text.write_str("abcdefg"), Err(fmt::Error));
+		assert_eq!(text.as_str(), "0123456789");
+
+		// Exactly filling the window is fine
+		text.write_str("abcdef").unwrap();
+		assert_eq!(text.as_str(), "0123456789abcdef");
+		assert_eq!(text.remaining(), 0);
+		assert_eq!(text.write_str(""), Ok(()));
+		assert_eq!(text.write_char('x'), Err(fmt::Error));
+	}
+
+	#[test]
+	fn write_multi_byte() {
+		let mut buf = [0_u8; 3];
+		let mut text = TextWindow::new(&mut buf);
+
+		text.write_char('ä').unwrap();
+		// Does not fit, and is not truncated in the middle of the character
+		assert!(text.write_char('€').is_err());
+		assert_eq!(text.as_str(), "ä");
+		assert_eq!(text.into_inner(), &[0xC3, 0xA4, 0]);
+	}
+
+	#[test]
+	fn try_window() {
+		let mut buf = [0_u8; 4];
+		assert!(buf.try_text_window::<4>(1).is_none());
+		assert_eq!(buf.try_text_window::<3>(1).map(|t| t.capacity()), Some(3));
+	}
+}
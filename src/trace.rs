@@ -0,0 +1,126 @@
+//! Tracing which parts of a buffer are read
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::SubArray;
+
+
+/// A buffer that records which of its elements were extracted
+///
+/// Every sub-array extracted via [`sub_array_ref`](Self::sub_array_ref) is
+/// marked in a coverage map, see [`coverage`](Self::coverage). This helps to
+/// find unparsed regions of e.g. a file format, while debugging a parser.
+///
+/// Requires the `trace` feature.
+///
+/// # Example
+/// ```
+/// use sub_array::TrackingBuffer;
+///
+/// let data: [u8; 6] = [1, 2, 3, 4, 5, 6];
+/// let mut buf = TrackingBuffer::new(&data);
+///
+/// assert_eq!(buf.sub_array_ref::<2>(1), &[2, 3]);
+/// assert_eq!(buf.sub_array_ref::<1>(4), &[5]);
+///
+/// assert_eq!(buf.coverage(), [false, true, true, false, true, false]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TrackingBuffer<'a, T = u8> {
+	data: &'a [T],
+	coverage: Vec<bool>,
+}
+
+impl<'a, T> TrackingBuffer<'a, T> {
+	/// Wraps `data`, with none of its elements covered yet.
+	pub fn new(data: &'a [T]) -> Self {
+		Self {
+			data,
+			coverage: vec![false; data.len()],
+		}
+	}
+
+	/// Get a reference to the sub-array of length `N` starting at `offset`,
+	/// marking its elements as covered.
+	///
+	/// # Panics
+	/// Panics if `offset + N` exceeds the length of the buffer.
+	#[track_caller]
+	pub fn sub_array_ref<const N: usize>(&mut self, offset: usize) -> &'a [T; N] {
+		let sub = self.data.sub_array_ref(offset);
+		self.coverage[offset..(offset + N)].fill(true);
+		sub
+	}
+
+	/// Get a reference to the sub-array of length `N` starting at `offset`,
+	/// if it is in bounds, marking its elements as covered.
+	///
+	/// Returns `None`, without marking anything, if `offset + N` exceeds the
+	/// length of the buffer.
+	pub fn try_sub_array_ref<const N: usize>(&mut self, offset: usize) -> Option<&'a [T; N]> {
+		let sub = self.data.try_sub_array_ref(offset)?;
+		self.coverage[offset..(offset + N)].fill(true);
+		Some(sub)
+	}
+
+	/// The coverage map, with one entry per element of the buffer, which is
+	/// `true` if the element was part of any extracted sub-array.
+	pub fn coverage(&self) -> &[bool] {
+		&self.coverage
+	}
+
+	/// Unwraps the underlying buffer.
+	pub fn into_inner(self) -> &'a [T] {
+		self.data
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn coverage() {
+		let data = [0_u8; 8];
+		let mut buf = TrackingBuffer::new(&data);
+		assert_eq!(buf.coverage(), [false; 8]);
+
+		buf.sub_array_ref::<3>(0);
+		buf.sub_array_ref::<2>(5);
+		assert_eq!(
+			buf.coverage(),
+			[true, true, true, false, false, true, true, false]
+		);
+
+		// Overlapping and empty extractions
+		buf.sub_array_ref::<2>(1);
+		buf.sub_array_ref::<0>(4);
+		assert_eq!(
+			buf.coverage(),
+			[true, true, true, false, false, true, true, false]
+		);
+	}
+
+	#[test]
+	fn try_out_of_bounds() {
+		let data = [1, 2, 3_u16];
+		let mut buf = TrackingBuffer::new(&data);
+
+		assert_eq!(buf.try_sub_array_ref::<2>(2), None);
+		assert_eq!(buf.coverage(), [false; 3]);
+		assert_eq!(buf.try_sub_array_ref::<1>(2), Some(&[3]));
+		assert_eq!(buf.coverage(), [false, false, true]);
+		assert_eq!(buf.into_inner(), &[1, 2, 3]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array of length 2 at offset 3 exceeds the array of length 4")]
+	fn out_of_bounds() {
+		let data = [0_u8; 4];
+		TrackingBuffer::new(&data).sub_array_ref::<2>(3);
+	}
+}
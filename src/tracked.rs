@@ -0,0 +1,93 @@
+//! Sub-arrays that remember where they came from
+
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+
+/// A mutable sub-array that remembers its offset in the original array
+///
+/// This dereferences to the plain `[T; N]` sub-array, thus it can be used just
+/// like the `&mut [T; N]` returned by
+/// [`sub_array_mut`](crate::SubArray::sub_array_mut), but additionally it
+/// knows at which offset it was taken, see [`origin_offset`](Self::origin_offset).
+///
+/// Returned by [`SubArray::sub_array_tracked_mut`](crate::SubArray::sub_array_tracked_mut).
+#[derive(Debug)]
+pub struct TrackedSubArray<'a, T, const N: usize> {
+	array: &'a mut [T; N],
+	offset: usize,
+}
+
+impl<'a, T, const N: usize> TrackedSubArray<'a, T, N> {
+	pub(crate) fn new(array: &'a mut [T; N], offset: usize) -> Self {
+		Self {
+			array,
+			offset,
+		}
+	}
+
+	/// The offset in the original array at which this sub-array starts.
+	pub fn origin_offset(&self) -> usize {
+		self.offset
+	}
+
+	/// Unwraps the plain sub-array reference, forgetting its origin.
+	pub fn into_inner(self) -> &'a mut [T; N] {
+		self.array
+	}
+}
+
+impl<T, const N: usize> Deref for TrackedSubArray<'_, T, N> {
+	type Target = [T; N];
+
+	fn deref(&self) -> &Self::Target {
+		self.array
+	}
+}
+
+impl<T, const N: usize> DerefMut for TrackedSubArray<'_, T, N> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.array
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use crate::SubArray;
+
+
+	#[test]
+	fn deref_and_offset() {
+		let mut arr = [1, 2, 3, 4, 5_u8];
+
+		let mut sub = arr.sub_array_tracked_mut::<2>(3);
+		assert_eq!(sub.origin_offset(), 3);
+		assert_eq!(*sub, [4, 5]);
+		assert_eq!(sub.len(), 2);
+
+		sub[0] = 42;
+		*sub = [sub[0], 43];
+		assert_eq!(sub.origin_offset(), 3);
+
+		assert_eq!(arr, [1, 2, 3, 42, 43]);
+	}
+
+	#[test]
+	fn into_inner() {
+		let mut arr = [1, 2, 3_u8];
+
+		let inner = arr.sub_array_tracked_mut::<1>(1).into_inner();
+		*inner = [7];
+
+		assert_eq!(arr, [1, 7, 3]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_bounds() {
+		let mut arr = [1, 2, 3_u8];
+		arr.sub_array_tracked_mut::<2>(2);
+	}
+}
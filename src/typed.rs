@@ -0,0 +1,197 @@
+//! Buffers tagged with their logical format at the type level
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::ops::DerefMut;
+
+use crate::SubArray;
+
+
+/// A buffer tagged with the marker type `Fmt` of its logical format
+///
+/// This is a zero-cost wrapper around a buffer `B`, e.g. an array, a `Vec`,
+/// or a reference to a slice, which implements [`SubArray`] if `B` does, and
+/// otherwise dereferences to `B`. Thus, all the extraction methods can be used
+/// on it, but functions can require a buffer of a specific format, which
+/// prevents applying the offsets of one format to a buffer of another.
+///
+/// Any type can be used as the format tag, an uninhabited `enum` is
+/// sufficient and makes it clear that it is only a marker.
+///
+/// # Example
+/// ```
+/// use sub_array::SubArray;
+/// use sub_array::TypedBuf;
+///
+/// enum Ipv4 {}
+/// enum Udp {}
+///
+/// fn ttl(packet: &TypedBuf<Ipv4, [u8; 20]>) -> u8 {
+///     packet.sub_array_ref::<1>(8)[0]
+/// }
+///
+/// let ip: TypedBuf<Ipv4, _> = TypedBuf::new([64_u8; 20]);
+/// assert_eq!(ttl(&ip), 64);
+/// ```
+///
+/// A buffer of another format is rejected:
+/// ```compile_fail
+/// # use sub_array::SubArray;
+/// # use sub_array::TypedBuf;
+/// # enum Ipv4 {}
+/// # enum Udp {}
+/// # fn ttl(packet: &TypedBuf<Ipv4, [u8; 20]>) -> u8 {
+/// #     packet.sub_array_ref::<1>(8)[0]
+/// # }
+/// let udp: TypedBuf<Udp, _> = TypedBuf::new([64_u8; 20]);
+/// ttl(&udp);
+/// ```
+pub struct TypedBuf<Fmt, B: ?Sized> {
+	/// Neither owns a `Fmt`, nor restricts the auto traits by it
+	fmt: PhantomData<fn() -> Fmt>,
+	buf: B,
+}
+
+impl<Fmt, B> TypedBuf<Fmt, B> {
+	/// Tags `buf` as being of the format `Fmt`.
+	pub const fn new(buf: B) -> Self {
+		Self {
+			fmt: PhantomData,
+			buf,
+		}
+	}
+
+	/// Unwraps the untagged buffer.
+	pub fn into_inner(self) -> B {
+		self.buf
+	}
+}
+
+impl<Fmt, B: Clone> Clone for TypedBuf<Fmt, B> {
+	fn clone(&self) -> Self {
+		Self::new(self.buf.clone())
+	}
+}
+
+impl<Fmt, B: Copy> Copy for TypedBuf<Fmt, B> {}
+
+impl<Fmt, B: fmt::Debug + ?Sized> fmt::Debug for TypedBuf<Fmt, B> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("TypedBuf").field(&&self.buf).finish()
+	}
+}
+
+impl<Fmt, B: ?Sized> Deref for TypedBuf<Fmt, B> {
+	type Target = B;
+
+	fn deref(&self) -> &Self::Target {
+		&self.buf
+	}
+}
+
+impl<Fmt, B: ?Sized> DerefMut for TypedBuf<Fmt, B> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.buf
+	}
+}
+
+impl<Fmt, B, T> AsRef<[T]> for TypedBuf<Fmt, B>
+where
+	B: AsRef<[T]> + ?Sized,
+{
+	fn as_ref(&self) -> &[T] {
+		self.buf.as_ref()
+	}
+}
+
+impl<Fmt, B, T> AsMut<[T]> for TypedBuf<Fmt, B>
+where
+	B: AsMut<[T]> + ?Sized,
+{
+	fn as_mut(&mut self) -> &mut [T] {
+		self.buf.as_mut()
+	}
+}
+
+/// Implementation on tagged buffers
+impl<Fmt, B> SubArray for TypedBuf<Fmt, B>
+where
+	B: SubArray + ?Sized,
+{
+	type Item = B::Item;
+
+	fn len(&self) -> usize {
+		self.buf.len()
+	}
+
+	#[track_caller]
+	fn sub_array_ref<const N: usize>(&self, offset: usize) -> &[Self::Item; N] {
+		self.buf.sub_array_ref(offset)
+	}
+
+	#[track_caller]
+	fn sub_array_mut<const N: usize>(&mut self, offset: usize) -> &mut [Self::Item; N] {
+		self.buf.sub_array_mut(offset)
+	}
+
+	fn try_sub_array_ref<const N: usize>(&self, offset: usize) -> Option<&[Self::Item; N]> {
+		self.buf.try_sub_array_ref(offset)
+	}
+
+	fn try_sub_array_mut<const N: usize>(&mut self, offset: usize) -> Option<&mut [Self::Item; N]> {
+		self.buf.try_sub_array_mut(offset)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::SubArrayRef;
+
+
+	enum FormatA {}
+	enum FormatB {}
+
+	/// The same field is at a different offset in each format
+	fn version_a(buf: &TypedBuf<FormatA, [u8; 8]>) -> u16 {
+		u16::from_be_bytes(*buf.sub_array_ref(2))
+	}
+
+	fn version_b<B: SubArray<Item = u8>>(buf: &TypedBuf<FormatB, B>) -> u16 {
+		u16::from_be_bytes(*buf.sub_array_ref(0))
+	}
+
+	#[test]
+	fn separate_formats() {
+		let a: TypedBuf<FormatA, _> = TypedBuf::new([0, 0, 1, 2, 0, 0, 0, 0_u8]);
+		let b: TypedBuf<FormatB, _> = TypedBuf::new([3, 4, 0, 0_u8]);
+
+		assert_eq!(version_a(&a), 0x0102);
+		assert_eq!(version_b(&b), 0x0304);
+		assert_eq!(b.into_inner(), [3, 4, 0, 0]);
+	}
+
+	#[test]
+	fn delegation() {
+		let mut arr = [1, 2, 3, 4_u8];
+		let mut buf: TypedBuf<FormatA, &mut [u8]> = TypedBuf::new(&mut arr);
+
+		*buf.sub_array_mut::<2>(1) = [5, 6];
+		assert_eq!(buf.try_sub_array_ref::<2>(3), None);
+		assert_eq!(buf.try_sub_array_mut::<1>(3), Some(&mut [4]));
+		assert_eq!(buf.copy_sub_array::<2>(0), [1, 5]);
+		assert_eq!(buf.len(), 4);
+		assert_eq!(arr, [1, 5, 6, 4]);
+
+		// A shared slice is no `SubArray` itself, thus the tagged buffer
+		// dereferences to it, including the inherent slice methods
+		let shared: TypedBuf<FormatB, &[u8]> = TypedBuf::new(&arr);
+		assert_eq!(shared.sub_array_ref::<2>(2), &[6, 4]);
+		assert_eq!(shared.first(), Some(&1));
+		assert_eq!(shared.iter().rev().nth(1), Some(&6));
+		assert_eq!(version_b(&TypedBuf::new(arr)), 0x0105);
+	}
+}
@@ -0,0 +1,154 @@
+//! Validating all elements of a sub-array before extracting it
+
+use core::fmt;
+
+
+/// Error validating the elements of a sub-array
+///
+/// Returned by [`SubArrayRef::validate_sub_array`](crate::SubArrayRef::validate_sub_array),
+/// where `E` is a reference to the offending element, and by
+/// [`SubArrayRef::validate_sub_array_with`](crate::SubArrayRef::validate_sub_array_with),
+/// where `E` is the error returned by the validator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidateError<E> {
+	/// The sub-array is out of bounds, nothing was validated
+	OutOfBounds,
+	/// An element of the sub-array is invalid
+	Invalid {
+		/// The index of the first invalid element within the sub-array
+		index: usize,
+		/// The offset of the first invalid element within the whole array
+		offset: usize,
+		/// Why the element is invalid
+		error: E,
+	},
+}
+
+impl<E: fmt::Display> fmt::Display for ValidateError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::OutOfBounds => write!(f, "validated sub-array out of bounds"),
+			Self::Invalid {
+				offset,
+				error,
+				..
+			} => write!(f, "invalid element at offset {offset}: {error}"),
+		}
+	}
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for ValidateError<E> {}
+
+
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::string::ToString;
+	use core::cell::Cell;
+
+	use super::*;
+	use crate::SubArrayRef;
+
+
+	#[test]
+	fn all_valid() {
+		let arr = *b"\xFFabc\xFF";
+		assert_eq!(
+			arr.validate_sub_array::<3>(1, |_, b| b.is_ascii_lowercase()),
+			Ok(b"abc")
+		);
+		assert_eq!(arr.validate_sub_array::<0>(5, |_, _| false), Ok(&[]));
+	}
+
+	#[test]
+	fn invalid_first() {
+		let arr = *b"xAbc";
+		assert_eq!(
+			arr.validate_sub_array::<3>(1, |_, b| b.is_ascii_lowercase()),
+			Err(ValidateError::Invalid {
+				index: 0,
+				offset: 1,
+				error: &b'A',
+			})
+		);
+	}
+
+	#[test]
+	fn invalid_last() {
+		let arr = [0, 1, 2, 3, 7_u8];
+		let checked = Cell::new(0);
+
+		let result = arr.validate_sub_array::<4>(1, |i, &d| {
+			checked.set(checked.get() + 1);
+			assert_eq!(arr[1 + i], d);
+			d < 4
+		});
+		assert_eq!(
+			result,
+			Err(ValidateError::Invalid {
+				index: 3,
+				offset: 4,
+				error: &7,
+			})
+		);
+		assert_eq!(checked.get(), 4);
+		assert_eq!(
+			result.unwrap_err().to_string(),
+			"invalid element at offset 4: 7"
+		);
+	}
+
+	#[test]
+	fn out_of_bounds_first() {
+		let slice: &[u8] = &[1, 2, 3];
+		let called = Cell::new(false);
+
+		let result = slice.validate_sub_array::<3>(1, |_, _| {
+			called.set(true);
+			true
+		});
+		assert_eq!(result, Err(ValidateError::OutOfBounds));
+		assert!(!called.get());
+	}
+
+	/// Error of a validator for in-range enum discriminants
+	#[derive(Debug, PartialEq)]
+	struct BadDiscriminant(u8);
+
+	impl fmt::Display for BadDiscriminant {
+		fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+			write!(f, "bad discriminant {}", self.0)
+		}
+	}
+
+	#[test]
+	fn custom_error() {
+		let arr = [0, 1, 2, 5, 1_u8];
+		let check = |_, &d: &u8| {
+			if d < 3 {
+				Ok(())
+			} else {
+				Err(BadDiscriminant(d))
+			}
+		};
+
+		assert_eq!(
+			arr.validate_sub_array_with::<3, _>(0, check),
+			Ok(&[0, 1, 2])
+		);
+		assert_eq!(
+			arr.validate_sub_array_with::<3, _>(1, check),
+			Err(ValidateError::Invalid {
+				index: 2,
+				offset: 3,
+				error: BadDiscriminant(5),
+			})
+		);
+		assert_eq!(
+			arr.validate_sub_array_with::<3, _>(3, check),
+			Err(ValidateError::OutOfBounds)
+		);
+	}
+}
@@ -0,0 +1,291 @@
+//! Appending sub-arrays to a `Vec`
+
+use alloc::vec::Vec;
+#[cfg(feature = "uninit")]
+use core::mem::MaybeUninit;
+
+use crate::SubArray;
+
+
+/// Extension methods to append fixed-size arrays to a [`Vec`]
+///
+/// Requires the `alloc` feature.
+pub trait VecSubArray<T> {
+	/// Appends a clone of `values` to the end of this vec, returning the newly
+	/// appended sub-array.
+	///
+	/// This is like [`Vec::extend_from_slice`], but keeps the fixed-size type.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::VecSubArray;
+	///
+	/// let mut vec = vec![1_u8];
+	///
+	/// let sub: &mut [u8; 2] = vec.push_sub_array(&[2, 3]);
+	/// sub[1] = 42;
+	/// assert_eq!(vec, [1, 2, 42]);
+	/// ```
+	fn push_sub_array<const N: usize>(&mut self, values: &[T; N]) -> &mut [T; N]
+	where
+		T: Clone;
+
+	/// Appends clones of all the arrays in `values` to the end of this vec,
+	/// returning the newly appended sub-arrays.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::VecSubArray;
+	///
+	/// let mut vec = vec![1_u8];
+	///
+	/// vec.push_sub_arrays(&[[2, 3], [4, 5]]);
+	/// assert_eq!(vec, [1, 2, 3, 4, 5]);
+	/// ```
+	fn push_sub_arrays<const N: usize, const K: usize>(
+		&mut self,
+		values: &[[T; N]; K],
+	) -> &mut [[T; N]; K]
+	where
+		T: Clone;
+
+	/// Get a mutable reference to the sub-array of length `N` starting at
+	/// `offset`, growing this vec as needed.
+	///
+	/// If `offset + N` exceeds the length of this vec, it is first resized to
+	/// exactly that length, filling all new elements with [`Default`] values.
+	/// This includes any gap between the old length and `offset`. The vec is
+	/// never shrunk.
+	///
+	/// # Panics
+	/// Panics if `offset + N` overflows `usize`.
+	///
+	/// # Example
+	/// ```
+	/// use sub_array::VecSubArray;
+	///
+	/// let mut vec = vec![1_u8, 2];
+	///
+	/// *vec.sub_array_mut_grow(4) = [5, 6];
+	/// assert_eq!(vec, [1, 2, 0, 0, 5, 6]);
+	///
+	/// *vec.sub_array_mut_grow(0) = [3];
+	/// assert_eq!(vec, [3, 2, 0, 0, 5, 6]);
+	/// ```
+	fn sub_array_mut_grow<const N: usize>(&mut self, offset: usize) -> &mut [T; N]
+	where
+		T: Default + Clone;
+
+	/// Appends `N` elements, which are initialized in-place by `f`, returning
+	/// the newly appended sub-array.
+	///
+	/// The closure gets the uninitialized spare capacity of this vec, and must
+	/// return a reference to the very same memory once it initialized all of
+	/// it, which is what e.g. [`MaybeUninit::write`] or `getrandom::fill_uninit`
+	/// provide. Only then, the length of this vec is increased.
+	///
+	/// If `f` panics, the length of this vec stays unchanged, any elements
+	/// initialized so far are leaked, which is why this requires `T: Copy`.
+	///
+	/// Requires the `uninit` feature, which contains `unsafe` code.
+	///
+	/// # Panics
+	/// Panics if the reference returned by `f` does not point to the memory
+	/// passed to it.
+	///
+	/// # Example
+	/// ```
+	/// use std::mem::MaybeUninit;
+	///
+	/// use sub_array::VecSubArray;
+	///
+	/// let mut vec = vec![1_u8];
+	///
+	/// vec.append_with_sub_array(|uninit: &mut [MaybeUninit<u8>; 3]| {
+	///     // Initialize the memory, and hand it back as initialized
+	///     uninit.write_copy_of_slice(&[2, 3, 4]).try_into().unwrap()
+	/// });
+	/// assert_eq!(vec, [1, 2, 3, 4]);
+	/// ```
+	#[cfg(feature = "uninit")]
+	fn append_with_sub_array<const N: usize, F>(&mut self, f: F) -> &mut [T; N]
+	where
+		T: Copy,
+		F: for<'a> FnOnce(&'a mut [MaybeUninit<T>; N]) -> &'a mut [T; N];
+}
+
+/// Implementation on vecs
+impl<T> VecSubArray<T> for Vec<T> {
+	fn push_sub_array<const N: usize>(&mut self, values: &[T; N]) -> &mut [T; N]
+	where
+		T: Clone,
+	{
+		let len = self.len();
+		self.extend_from_slice(values);
+		(&mut self[len..]).try_into().unwrap()
+	}
+
+	fn push_sub_arrays<const N: usize, const K: usize>(
+		&mut self,
+		values: &[[T; N]; K],
+	) -> &mut [[T; N]; K]
+	where
+		T: Clone,
+	{
+		let len = self.len();
+		self.extend_from_slice(values.as_flattened());
+		let (chunks, rest) = self[len..].as_chunks_mut();
+		debug_assert!(rest.is_empty());
+		chunks.try_into().unwrap()
+	}
+
+	fn sub_array_mut_grow<const N: usize>(&mut self, offset: usize) -> &mut [T; N]
+	where
+		T: Default + Clone,
+	{
+		let end = offset
+			.checked_add(N)
+			.expect("sub-array end overflows usize");
+		if self.len() < end {
+			self.resize(end, T::default());
+		}
+		self.as_mut_slice().sub_array_mut(offset)
+	}
+
+	#[cfg(feature = "uninit")]
+	fn append_with_sub_array<const N: usize, F>(&mut self, f: F) -> &mut [T; N]
+	where
+		T: Copy,
+		F: for<'a> FnOnce(&'a mut [MaybeUninit<T>; N]) -> &'a mut [T; N],
+	{
+		self.reserve(N);
+		let len = self.len();
+
+		let spare: &mut [MaybeUninit<T>; N] = self
+			.spare_capacity_mut()
+			.first_chunk_mut()
+			.expect("reserved capacity is missing");
+		let spare_ptr = spare.as_ptr().cast::<T>();
+
+		let init = f(spare);
+		assert!(
+			core::ptr::eq(init.as_ptr(), spare_ptr),
+			"the closure must return the memory it was given"
+		);
+
+		// SAFETY: `init` is a valid `&mut [T; N]` pointing to the first `N`
+		// elements of the spare capacity, thus these elements are initialized.
+		// Duplicating zero-sized elements this way is fine, since they are
+		// `Copy`.
+		#[allow(unsafe_code)]
+		unsafe {
+			self.set_len(len + N);
+		}
+
+		(&mut self[len..]).try_into().unwrap()
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+
+	#[test]
+	fn push() {
+		let mut vec = Vec::new();
+
+		vec.push(1_u8);
+		assert_eq!(vec.push_sub_array(&[2, 3]), &[2, 3]);
+		vec.push(4);
+		assert_eq!(vec.push_sub_array(&[]), &[]);
+		vec.push_sub_array(&[5]);
+
+		assert_eq!(vec, [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn push_many() {
+		let mut vec = Vec::from([0_u8]);
+
+		let subs = vec.push_sub_arrays(&[[1, 2], [3, 4], [5, 6]]);
+		subs[1] = [0, 0];
+		vec.push(7);
+		vec.push_sub_arrays::<3, 0>(&[]);
+
+		assert_eq!(vec, [0, 1, 2, 0, 0, 5, 6, 7]);
+	}
+
+	#[test]
+	fn grow() {
+		let mut vec = Vec::from([1_u8, 2]);
+
+		assert_eq!(vec.sub_array_mut_grow::<2>(5), &[0, 0]);
+		assert_eq!(vec, [1, 2, 0, 0, 0, 0, 0]);
+
+		*vec.sub_array_mut_grow(6) = [7, 8];
+		assert_eq!(vec, [1, 2, 0, 0, 0, 0, 7, 8]);
+
+		*vec.sub_array_mut_grow(1) = [3, 4];
+		vec.sub_array_mut_grow::<0>(8);
+		assert_eq!(vec, [1, 3, 4, 0, 0, 0, 7, 8]);
+	}
+
+	#[test]
+	#[should_panic(expected = "sub-array end overflows usize")]
+	fn grow_overflow() {
+		let mut vec = Vec::<u8>::new();
+		vec.sub_array_mut_grow::<2>(usize::MAX);
+	}
+
+	#[cfg(feature = "uninit")]
+	#[test]
+	fn append_with() {
+		let mut vec = Vec::from([1_u8]);
+
+		let sub = vec.append_with_sub_array(|uninit: &mut [MaybeUninit<u8>; 2]| {
+			uninit.write_copy_of_slice(&[2, 3]).try_into().unwrap()
+		});
+		assert_eq!(sub, &[2, 3]);
+		vec.push(4);
+		vec.append_with_sub_array(|uninit: &mut [MaybeUninit<u8>; 1]| {
+			uninit.write_copy_of_slice(&[5]).try_into().unwrap()
+		});
+
+		assert_eq!(vec, [1, 2, 3, 4, 5]);
+	}
+
+	#[cfg(feature = "uninit")]
+	#[test]
+	fn append_with_panic() {
+		extern crate std;
+
+		let mut vec = Vec::from([1_u8, 2]);
+
+		let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+			vec.append_with_sub_array(|uninit: &mut [MaybeUninit<u8>; 4]| {
+				uninit[0].write(42);
+				panic!("producer failed")
+			});
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(vec, [1, 2]);
+		vec.push(3);
+		assert_eq!(vec, [1, 2, 3]);
+	}
+
+	#[cfg(feature = "uninit")]
+	#[test]
+	#[should_panic(expected = "the closure must return the memory it was given")]
+	fn append_with_foreign_memory() {
+		extern crate std;
+
+		let mut vec = Vec::<u8>::new();
+		vec.append_with_sub_array(|_: &mut [MaybeUninit<u8>; 2]| {
+			std::boxed::Box::leak(std::boxed::Box::new([1, 2]))
+		});
+	}
+}
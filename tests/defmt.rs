@@ -0,0 +1,38 @@
+//! Logging of failed extractions via `defmt`, on the host
+//!
+//! This is an integration test, since the logger requires `unsafe` code,
+//! which the crate itself forbids.
+
+#![cfg(feature = "defmt")]
+
+use sub_array::SubArrayRef;
+
+
+/// Discards all log messages, there is no host to send them to
+#[defmt::global_logger]
+struct NullLogger;
+
+unsafe impl defmt::Logger for NullLogger {
+	fn acquire() {}
+
+	unsafe fn flush() {}
+
+	unsafe fn release() {}
+
+	unsafe fn write(_bytes: &[u8]) {}
+}
+
+defmt::timestamp!("");
+
+#[test]
+fn logged() {
+	let arr = [1, 2, 3, 4_u8];
+	let slice: &[u8] = &arr[1..];
+
+	assert_eq!(arr.sub_array_ref_logged::<2>(2), Some(&[3, 4]));
+	assert_eq!(slice.sub_array_ref_logged::<0>(3), Some(&[]));
+
+	assert_eq!(arr.sub_array_ref_logged::<2>(3), None);
+	assert_eq!(slice.sub_array_ref_logged::<4>(0), None);
+	assert_eq!(slice.sub_array_ref_logged::<1>(usize::MAX), None);
+}